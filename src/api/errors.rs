@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+/// Errors returned by the public API of the crate.
+pub enum Error {
+    /// Thrown when opening a directory that does not hold a Holium project.
+    #[error("no Holium project found in directory: {0}")]
+    NotAProject(PathBuf),
+    /// Thrown when initializing a project in a directory that already holds one.
+    #[error("a Holium project already exists in directory: {0}")]
+    AlreadyInitialized(PathBuf),
+    /// Thrown when a key was expected to be free in the project.
+    #[error("object already exists with key: {0}")]
+    AlreadyExists(String),
+    /// Thrown when an object was expected to be found in the project with a given key.
+    #[error("missing object for key: {0}")]
+    NotFound(String),
+    /// Thrown when the definition of an object is not valid: node name, typed name, JSON schema,
+    /// selector or bytecode.
+    #[error("invalid object: {0}")]
+    InvalidObject(String),
+    /// Thrown when data can not be converted from or to its HoliumCBOR representation.
+    #[error("invalid data: {0}")]
+    InvalidData(String),
+    /// Thrown when the pipeline of the project is not valid.
+    #[error("invalid pipeline: {0}")]
+    InvalidPipeline(String),
+    /// Thrown when the execution of the pipeline fails.
+    #[error("failed to run the pipeline: {0}")]
+    RunFailed(String),
+    /// Thrown when the project fails to be exported to, or imported from, its interplanetary area.
+    #[error("failed to move the project through its interplanetary area: {0}")]
+    InterplanetaryFailed(String),
+    /// Thrown when an operation on the storage of the project fails.
+    #[error("failed to operate on project storage: {0}")]
+    StorageFailed(String),
+}
+
+/// Convert internal results into results of the public API, keeping the whole chain of context of
+/// internal errors in the message of the given variant.
+pub(crate) trait IntoApiResult<T> {
+    fn or_api_err(self, variant: fn(String) -> Error) -> Result<T, Error>;
+}
+
+impl<T, E: Into<anyhow::Error>> IntoApiResult<T> for Result<T, E> {
+    fn or_api_err(self, variant: fn(String) -> Error) -> Result<T, Error> {
+        self.map_err(|err| variant(format!("{:#}", err.into())))
+    }
+}
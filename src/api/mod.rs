@@ -0,0 +1,305 @@
+//! Public API to create, run and share Holium projects from Rust code, without going through the
+//! command line interface.
+//!
+//! Pipeline nodes are addressed by their typed name (eg: `source:my-source`), as with the CLI.
+
+mod errors;
+
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::fs::helpers::clear_ip_area::clear_ip_area;
+use crate::utils::interplanetary::multiformats::DEFAULT_MULTIBASE;
+use crate::utils::local::context::helpers::{
+    build_connection_id, parse_node_typed_name, validate_node_name,
+    validate_pipeline_node_existence, PortationDirectionType,
+};
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::dag::models::PipelineDag;
+use crate::utils::local::dag::provenance::store_run;
+use crate::utils::local::export::export_project;
+use crate::utils::local::helpers::bytecode::{
+    validate_wasm_function_export, validate_wasm_magic_number,
+};
+use crate::utils::local::helpers::jsonschema::validate_pipeline_node_json_schema;
+use crate::utils::local::helpers::selector::{
+    parse_selector_argument, validate_selector, validate_selectors_pairing,
+};
+use crate::utils::local::import::import_project;
+use crate::utils::repo::constants::HOLIUM_DIR;
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::helpers::create_holium_dir;
+use crate::utils::repo::ports::formats::json::{holium_cbor_to_json, json_to_holium_cbor};
+use crate::utils::repo::ports::helpers::get_node_json_schema;
+use crate::utils::run::runtime::Runtime;
+
+pub use errors::Error;
+use errors::IntoApiResult;
+
+pub use crate::utils::local::models::connection::Connection;
+pub use crate::utils::local::models::shaper::Shaper;
+pub use crate::utils::local::models::source::Source;
+pub use crate::utils::local::models::transformation::Transformation;
+
+/// Result type of the public API.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Handle on a Holium project stored on the file system.
+pub struct Project {
+    local_context: LocalContext,
+    repo_context: RepositoryContext,
+}
+
+impl Project {
+    /// Initialize a new project in a directory. The local area of the project is ignored by git if
+    /// the directory is tracked by it.
+    pub fn init<P: AsRef<Path>>(root_path: P) -> Result<Self> {
+        let root_path = root_path.as_ref();
+        if root_path.join(HOLIUM_DIR).exists() {
+            return Err(Error::AlreadyInitialized(root_path.to_path_buf()));
+        }
+        create_holium_dir(root_path, root_path.join(".git").exists())
+            .or_api_err(Error::StorageFailed)?;
+        Self::open(root_path)
+    }
+
+    /// Open the project stored in a directory.
+    pub fn open<P: AsRef<Path>>(root_path: P) -> Result<Self> {
+        let root_path = root_path.as_ref().to_path_buf();
+        if !root_path.join(HOLIUM_DIR).is_dir() {
+            return Err(Error::NotAProject(root_path));
+        }
+        Ok(Project {
+            local_context: LocalContext::from_root_path(&root_path)
+                .or_api_err(Error::StorageFailed)?,
+            repo_context: RepositoryContext::from_root_path(&root_path)
+                .or_api_err(Error::StorageFailed)?,
+        })
+    }
+
+    /// Path of the root directory of the project.
+    pub fn root_path(&self) -> &Path {
+        &self.local_context.root_path
+    }
+
+    /// Create a source node.
+    pub fn create_source(&self, name: &str, json_schema: &str) -> Result<()> {
+        validate_node_name(name).or_api_err(Error::InvalidObject)?;
+        validate_pipeline_node_json_schema(json_schema).or_api_err(Error::InvalidObject)?;
+        let object = Source {
+            name: name.to_string(),
+            json_schema: json_schema.to_string(),
+        };
+        insert_new_object(&self.local_context.sources, name, &object)
+    }
+
+    /// Create a shaper node.
+    pub fn create_shaper(&self, name: &str, json_schema: &str) -> Result<()> {
+        validate_node_name(name).or_api_err(Error::InvalidObject)?;
+        validate_pipeline_node_json_schema(json_schema).or_api_err(Error::InvalidObject)?;
+        let object = Shaper {
+            name: name.to_string(),
+            json_schema: json_schema.to_string(),
+        };
+        insert_new_object(&self.local_context.shapers, name, &object)
+    }
+
+    /// Create a transformation node from the bytecode of a Wasm module and the name of the
+    /// function to call in it.
+    pub fn create_transformation(
+        &self,
+        name: &str,
+        bytecode: &[u8],
+        handle: &str,
+        json_schema_in: &str,
+        json_schema_out: &str,
+    ) -> Result<()> {
+        validate_node_name(name).or_api_err(Error::InvalidObject)?;
+        validate_wasm_magic_number(bytecode).or_api_err(Error::InvalidObject)?;
+        validate_wasm_function_export(bytecode, handle).or_api_err(Error::InvalidObject)?;
+        validate_pipeline_node_json_schema(json_schema_in).or_api_err(Error::InvalidObject)?;
+        validate_pipeline_node_json_schema(json_schema_out).or_api_err(Error::InvalidObject)?;
+        let object = Transformation {
+            name: name.to_string(),
+            bytecode: bytecode.to_vec(),
+            handle: handle.to_string(),
+            json_schema_in: json_schema_in.to_string(),
+            json_schema_out: json_schema_out.to_string(),
+        };
+        insert_new_object(&self.local_context.transformations, name, &object)
+    }
+
+    /// Create a connection between two nodes and return its identifier. Selectors are given as
+    /// JSON literals or in the path syntax.
+    pub fn create_connection(
+        &self,
+        tail_typed_name: &str,
+        tail_selector: &str,
+        head_typed_name: &str,
+        head_selector: &str,
+    ) -> Result<String> {
+        let tail_typed_name = self.existing_node_typed_name(tail_typed_name)?;
+        let head_typed_name = self.existing_node_typed_name(head_typed_name)?;
+        let tail_selector =
+            parse_selector_argument(tail_selector).or_api_err(Error::InvalidObject)?;
+        let head_selector =
+            parse_selector_argument(head_selector).or_api_err(Error::InvalidObject)?;
+        validate_selector(&tail_selector).or_api_err(Error::InvalidObject)?;
+        validate_selector(&head_selector).or_api_err(Error::InvalidObject)?;
+        validate_selectors_pairing(&tail_selector, &head_selector)
+            .or_api_err(Error::InvalidObject)?;
+        let id = build_connection_id(&tail_typed_name, &head_typed_name);
+        let object = Connection {
+            id: id.clone(),
+            tail_selector,
+            head_selector,
+        };
+        insert_new_object(&self.local_context.connections, &id, &object)?;
+        Ok(id)
+    }
+
+    /// Read a source node.
+    pub fn source(&self, name: &str) -> Result<Source> {
+        let mut object: Source = read_object(&self.local_context.sources, name)?;
+        object.name = name.to_string();
+        Ok(object)
+    }
+
+    /// Read a shaper node.
+    pub fn shaper(&self, name: &str) -> Result<Shaper> {
+        let mut object: Shaper = read_object(&self.local_context.shapers, name)?;
+        object.name = name.to_string();
+        Ok(object)
+    }
+
+    /// Read a transformation node.
+    pub fn transformation(&self, name: &str) -> Result<Transformation> {
+        let mut object: Transformation = read_object(&self.local_context.transformations, name)?;
+        object.name = name.to_string();
+        Ok(object)
+    }
+
+    /// Read a connection from its identifier.
+    pub fn connection(&self, id: &str) -> Result<Connection> {
+        let mut object: Connection = read_object(&self.local_context.connections, id)?;
+        object.id = id.to_string();
+        Ok(object)
+    }
+
+    /// Set the data stored for a node, as described by the JSON schema of the data leaving it.
+    pub fn set_data(&self, node_typed_name: &str, value: &Value) -> Result<()> {
+        let node_typed_name = self.existing_node_typed_name(node_typed_name)?;
+        let json_schema = get_node_json_schema(
+            &self.local_context,
+            &node_typed_name,
+            &PortationDirectionType::fromHolium,
+        )
+        .or_api_err(Error::InvalidObject)?;
+        let holium_cbor =
+            json_to_holium_cbor(&json_schema, value).or_api_err(Error::InvalidData)?;
+        self.local_context
+            .data
+            .insert(&node_typed_name, holium_cbor)
+            .or_api_err(Error::StorageFailed)?;
+        Ok(())
+    }
+
+    /// Get the data stored for a node, as described by the JSON schema of the data leaving it.
+    pub fn data(&self, node_typed_name: &str) -> Result<Value> {
+        let node_typed_name = self.existing_node_typed_name(node_typed_name)?;
+        let holium_cbor = self
+            .local_context
+            .data
+            .get(&node_typed_name)
+            .or_api_err(Error::StorageFailed)?
+            .ok_or_else(|| Error::NotFound(node_typed_name.clone()))?;
+        let json_schema = get_node_json_schema(
+            &self.local_context,
+            &node_typed_name,
+            &PortationDirectionType::fromHolium,
+        )
+        .or_api_err(Error::InvalidObject)?;
+        holium_cbor_to_json(&json_schema, &holium_cbor).or_api_err(Error::InvalidData)
+    }
+
+    /// Run the pipeline of the project and store the provenance record of the run. Returns the
+    /// typed names of nodes whose output has been exported through a portation, along with the path
+    /// of the written files.
+    pub fn run(&self) -> Result<Vec<(String, String)>> {
+        PipelineDag::from_local_context(&self.local_context)
+            .and_then(|dag| dag.is_valid_pipeline())
+            .or_api_err(Error::InvalidPipeline)?;
+        let mut runtime = Runtime::new().or_api_err(Error::RunFailed)?;
+        let mut run = PipelineDag::run(&mut runtime, &self.local_context, &self.repo_context)
+            .or_api_err(Error::RunFailed)?;
+        store_run(&self.local_context, &mut run).or_api_err(Error::StorageFailed)?;
+        Ok(run.node_exports())
+    }
+
+    /// Export the project to its interplanetary area and return the cid of the pipeline.
+    pub fn export(&self) -> Result<String> {
+        PipelineDag::from_local_context(&self.local_context)
+            .and_then(|dag| dag.is_valid_pipeline())
+            .or_api_err(Error::InvalidPipeline)?;
+        let ip_context = InterplanetaryContext::from_root_path(&self.local_context.root_path)
+            .or_api_err(Error::StorageFailed)?;
+        clear_ip_area(&ip_context).or_api_err(Error::InterplanetaryFailed)?;
+        let pipeline_cid = export_project(&self.local_context, &ip_context)
+            .or_api_err(Error::InterplanetaryFailed)?;
+        pipeline_cid
+            .to_string_of_base(DEFAULT_MULTIBASE)
+            .or_api_err(Error::InterplanetaryFailed)
+    }
+
+    /// Replace the local project with the content of its interplanetary area, and return a handle
+    /// on the updated project.
+    pub fn import(self) -> Result<Self> {
+        let root_path = self.local_context.root_path.clone();
+        let ip_context =
+            InterplanetaryContext::from_root_path(&root_path).or_api_err(Error::StorageFailed)?;
+        // import into a temporary local area and check the imported pipeline
+        let (tmp_local_context, tmp_dir) =
+            LocalContext::new_tmp().or_api_err(Error::StorageFailed)?;
+        import_project(&ip_context, &tmp_local_context).or_api_err(Error::InterplanetaryFailed)?;
+        PipelineDag::from_local_context(&tmp_local_context)
+            .and_then(|dag| dag.is_valid_pipeline())
+            .or_api_err(Error::InvalidPipeline)?;
+        // move the imported content to the project local area
+        tmp_local_context
+            .mv_local_area(&self.local_context)
+            .or_api_err(Error::StorageFailed)?;
+        tmp_dir.close().or_api_err(Error::StorageFailed)?;
+        // the local context must be rebuilt after the move
+        drop(self);
+        Self::open(root_path)
+    }
+
+    /// Validate a typed name and the existence of the node it refers to.
+    fn existing_node_typed_name(&self, node_typed_name: &str) -> Result<String> {
+        let (node_type, node_name) =
+            parse_node_typed_name(node_typed_name).or_api_err(Error::InvalidObject)?;
+        validate_pipeline_node_existence(&self.local_context, &node_type, &node_name)
+            .map_err(|_| Error::NotFound(node_typed_name.to_string()))
+    }
+}
+
+/// Store a new object in a tree of the local area.
+fn insert_new_object<T: Serialize>(tree: &sled::Tree, key: &str, object: &T) -> Result<()> {
+    let encoded = bincode::serialize(object).or_api_err(Error::StorageFailed)?;
+    tree.compare_and_swap(key, None as Option<&[u8]>, Some(encoded))
+        .or_api_err(Error::StorageFailed)?
+        .map_err(|_| Error::AlreadyExists(key.to_string()))
+}
+
+/// Read an object from a tree of the local area.
+fn read_object<T: DeserializeOwned>(tree: &sled::Tree, key: &str) -> Result<T> {
+    let encoded = tree
+        .get(key)
+        .or_api_err(Error::StorageFailed)?
+        .ok_or_else(|| Error::NotFound(key.to_string()))?;
+    bincode::deserialize(&encoded[..]).or_api_err(Error::StorageFailed)
+}
@@ -0,0 +1,23 @@
+use crate::utils::local::helpers::prints::output::{output_format_arg, parse_output_format};
+use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+use crate::utils::repo::context::RepositoryContext;
+use anyhow::Result;
+use clap::{App, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("read")
+        .about("Read project settings")
+        .arg(output_format_arg())
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create repository context
+    let repo_context = RepositoryContext::new()?;
+    // get argument values
+    let output_format = parse_output_format(matches)?;
+    // print
+    repo_context.config.object_print(&output_format)?;
+    Ok(())
+}
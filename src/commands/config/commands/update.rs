@@ -0,0 +1,73 @@
+use anyhow::Result;
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use crate::utils::interplanetary::block_store::BlockStoreKind;
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::multiformats::HashFunction;
+use crate::utils::local::helpers::prints::commands_outputs::print_update_success;
+use crate::utils::repo::constants::CONFIG_FILE;
+use crate::utils::repo::context::RepositoryContext;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("the interplanetary area is not empty, use `project migrate --block-store` to move its blocks to another block store")]
+    NonEmptyInterplanetaryArea,
+}
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("update")
+        .about("Update project settings")
+        .args(&[
+            Arg::with_name("hash-function")
+                .help("Hash function used for newly written interplanetary blocks")
+                .long_help(
+                    "Hash function used for newly written interplanetary blocks. Blocks already \
+                written remain readable. Use `project migrate` to rewrite them.",
+                )
+                .display_order(1)
+                .takes_value(true)
+                .possible_values(&HashFunction::variants())
+                .case_insensitive(true)
+                .value_name("HASH_FUNCTION")
+                .long("hash-function"),
+            Arg::with_name("block-store")
+                .help("Backend storing the blocks of the interplanetary area")
+                .long_help(
+                    "Backend storing the blocks of the interplanetary area. It can only be changed \
+                    while the interplanetary area is empty. Use `project migrate --block-store` to \
+                    move existing blocks to another backend.",
+                )
+                .display_order(2)
+                .takes_value(true)
+                .possible_values(&BlockStoreKind::variants())
+                .case_insensitive(true)
+                .value_name("BLOCK_STORE")
+                .long("block-store"),
+        ])
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create repository context
+    let mut repo_context = RepositoryContext::new()?;
+    // parse hash function, if any
+    if let Some(s) = matches.value_of("hash-function") {
+        repo_context.config.hash_function = s.parse::<HashFunction>()?;
+    }
+    // parse block store, if any
+    if let Some(s) = matches.value_of("block-store") {
+        let block_store = s.parse::<BlockStoreKind>()?;
+        // blocks would be left behind in the previous block store
+        if block_store != repo_context.config.block_store
+            && !InterplanetaryContext::new()?.block_store.list()?.is_empty()
+        {
+            return Err(Error::NonEmptyInterplanetaryArea.into());
+        }
+        repo_context.config.block_store = block_store;
+    }
+    // store settings
+    repo_context.config.save()?;
+    print_update_success(CONFIG_FILE);
+    Ok(())
+}
@@ -0,0 +1,24 @@
+//! Manipulate project settings.
+
+mod commands;
+
+use anyhow::Result;
+use clap::{App, AppSettings, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("config")
+        .about("Manipulate project settings")
+        .setting(AppSettings::ArgRequiredElseHelp)
+        .subcommand(commands::read::cmd())
+        .subcommand(commands::update::cmd())
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    match matches.subcommand() {
+        ("read", Some(matches)) => commands::read::handle_cmd(matches),
+        ("update", Some(matches)) => commands::update::handle_cmd(matches),
+        _ => unreachable!(), // If all subcommands are defined above, anything else should be unreachable!()
+    }
+}
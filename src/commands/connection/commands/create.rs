@@ -9,9 +9,12 @@ use crate::utils::local::context::helpers::build_connection_id;
 use crate::utils::local::context::helpers::{validate_pipeline_node_existence, NodeType};
 use crate::utils::local::context::LocalContext;
 
+use crate::utils::local::helpers::arguments::read_literal_arguments;
 use crate::utils::local::helpers::prints::commands_outputs::print_create_success;
 use crate::utils::local::helpers::prints::errors::Error::StructureCreationError;
-use crate::utils::local::helpers::selector::validate_selector;
+use crate::utils::local::helpers::selector::{
+    parse_selector_argument, validate_selector, validate_selectors_pairing,
+};
 use crate::utils::local::models::connection::Connection;
 
 /// command
@@ -36,11 +39,11 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .value_name("NAME")
                 .long("tail-name"),
             Arg::with_name("tail-selector")
-                .help("Selector at the tail of the connection")
+                .help("Selector at the tail of the connection, as a JSON literal or a path (e.g. 0/2..5), inline, as @FILE or as - to read standard input")
                 .display_order(3)
                 .required(true)
                 .takes_value(true)
-                .value_name("JSON-SCHEMA")
+                .value_name("SELECTOR")
                 .long("tail-selector"),
             Arg::with_name("head-type")
                 .help("Type of the node at the head of the connection")
@@ -59,11 +62,11 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .value_name("NAME")
                 .long("head-name"),
             Arg::with_name("head-selector")
-                .help("Selector at the head of the connection")
+                .help("Selector at the head of the connection, as a JSON literal or a path (e.g. 0/2..5), inline, as @FILE or as - to read standard input")
                 .display_order(6)
                 .required(true)
                 .takes_value(true)
-                .value_name("JSON-SCHEMA")
+                .value_name("SELECTOR")
                 .long("head-selector"),
         ])
 }
@@ -79,18 +82,18 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let tail_name = matches
         .value_of("tail-name")
         .context(MissingRequiredArgument("tail-name".to_string()))?;
-    let tail_selector = matches
-        .value_of("tail-selector")
-        .context(MissingRequiredArgument("tail-selector".to_string()))?;
+    let [tail_selector, head_selector] =
+        read_literal_arguments(matches, ["tail-selector", "head-selector"])?;
+    let tail_selector =
+        tail_selector.context(MissingRequiredArgument("tail-selector".to_string()))?;
     let head_type_str = matches
         .value_of("head-type")
         .context(MissingRequiredArgument("head-type".to_string()))?;
     let head_name = matches
         .value_of("head-name")
         .context(MissingRequiredArgument("head-name".to_string()))?;
-    let head_selector = matches
-        .value_of("head-selector")
-        .context(MissingRequiredArgument("head-selector".to_string()))?;
+    let head_selector =
+        head_selector.context(MissingRequiredArgument("head-selector".to_string()))?;
     // validate the existence of tail and head nodes
     let tail_type = tail_type_str
         .parse::<NodeType>()
@@ -110,14 +113,17 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     {
         return Err(ObjectAlreadyExistsForGivenKey(id).into());
     }
-    // validate selectors
-    validate_selector(tail_selector)?;
-    validate_selector(head_selector)?;
+    // parse and validate selectors
+    let tail_selector = parse_selector_argument(&tail_selector)?;
+    let head_selector = parse_selector_argument(&head_selector)?;
+    validate_selector(&tail_selector)?;
+    validate_selector(&head_selector)?;
+    validate_selectors_pairing(&tail_selector, &head_selector)?;
     // create new object
     let object = Connection {
         id: id.clone(),
-        tail_selector: tail_selector.to_string(),
-        head_selector: head_selector.to_string(),
+        tail_selector,
+        head_selector,
     };
     // store new object
     let encoded: Vec<u8> = bincode::serialize(&object).context(BinCodeSerializeFailed)?;
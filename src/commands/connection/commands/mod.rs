@@ -1,5 +1,6 @@
 pub mod create;
 pub mod delete;
 pub mod list;
+pub mod preview;
 pub mod read;
 pub mod update;
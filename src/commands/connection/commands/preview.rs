@@ -0,0 +1,86 @@
+//! Preview the data selected and written by a connection
+
+use crate::utils::cbor::as_holium_cbor::AsHoliumCbor;
+use crate::utils::cbor::write_holium_cbor::WriteHoliumCbor;
+use crate::utils::errors::Error::{
+    DbOperationFailed, MissingRequiredArgument, NoObjectForGivenKey,
+};
+use crate::utils::local::context::helpers::{parse_connection_id, PortationDirectionType};
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::dag::models::connection_details;
+use crate::utils::local::helpers::prints::commands_outputs::print_selection_preview;
+use crate::utils::local::helpers::selector::selected_data_sets_to_json;
+use crate::utils::local::models::data::HoliumCbor;
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::ports::formats::json::{cbor_to_json, holium_cbor_to_json};
+use crate::utils::repo::ports::helpers::get_node_json_schema;
+use anyhow::{Context, Result};
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to select data at the tail of connection: {0}")]
+    TailSelectionFailed(String),
+}
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("preview")
+        .about("Preview the data selected at the tail of a connection and written at its head")
+        .args(&[Arg::with_name("id")
+            .help("ID of the connection")
+            .required(true)
+            .value_name("ID")])
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create contexts
+    let local_context = LocalContext::new()?;
+    let repo_context = RepositoryContext::new()?;
+    // get argument values
+    let id = matches
+        .value_of("id")
+        .context(MissingRequiredArgument("id".to_string()))?;
+    // check that the object exists
+    if !local_context
+        .connections
+        .contains_key(id)
+        .context(DbOperationFailed)?
+    {
+        return Err(NoObjectForGivenKey(id.to_string()).into());
+    }
+    // gather data and selectors of the connection
+    let details = connection_details(&local_context, &repo_context, id)?;
+    // apply the tail selector, and render selected data as described by the tail node schema
+    let (tail_typed_name, head_typed_name) = parse_connection_id(id)?;
+    let tail_json_schema = get_node_json_schema(
+        &local_context,
+        tail_typed_name,
+        &PortationDirectionType::fromHolium,
+    )?;
+    let data_sets = selected_data_sets_to_json(
+        &details
+            .1
+            .select_cbor(&details.2)
+            .context(Error::TailSelectionFailed(id.to_string()))?,
+        &details.2,
+        &tail_json_schema,
+    )?;
+    // build the head value from this connection only
+    let mut head_data: HoliumCbor = Vec::new();
+    let head_value = head_data
+        .copy_cbor(&vec![details])
+        .and_then(|_| {
+            let json_schema = get_node_json_schema(
+                &local_context,
+                head_typed_name,
+                &PortationDirectionType::toHolium,
+            )?;
+            holium_cbor_to_json(&json_schema, &head_data).or_else(|_| cbor_to_json(&head_data))
+        })
+        .map_err(|err| format!("{:#}", err));
+    // print
+    print_selection_preview(&data_sets, Some(&head_value));
+    Ok(())
+}
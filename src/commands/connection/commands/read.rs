@@ -2,6 +2,7 @@ use crate::utils::errors::Error::{
     BinCodeDeserializeFailed, DbOperationFailed, MissingRequiredArgument, NoObjectForGivenKey,
 };
 use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::prints::output::{output_format_arg, parse_output_format};
 use crate::utils::local::helpers::prints::printable_model::PrintableModel;
 use crate::utils::local::models::connection::Connection;
 use anyhow::{Context, Result};
@@ -11,10 +12,13 @@ use clap::{App, Arg, ArgMatches, SubCommand};
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
     SubCommand::with_name("read")
         .about("Read a connection")
-        .args(&[Arg::with_name("id")
-            .help("ID of the connection")
-            .required(true)
-            .value_name("ID")])
+        .args(&[
+            Arg::with_name("id")
+                .help("ID of the connection")
+                .required(true)
+                .value_name("ID"),
+            output_format_arg(),
+        ])
 }
 
 /// handler
@@ -25,6 +29,7 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let id = matches
         .value_of("id")
         .context(MissingRequiredArgument("id".to_string()))?;
+    let output_format = parse_output_format(matches)?;
     // get object from local database
     let encoded = local_context
         .connections
@@ -36,6 +41,6 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
         .context(BinCodeDeserializeFailed)?;
     decoded.id = id.to_string();
     // print
-    Connection::table_print(vec![&decoded]);
+    decoded.object_print(&output_format)?;
     Ok(())
 }
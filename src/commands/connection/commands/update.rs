@@ -2,13 +2,17 @@ use anyhow::{Context, Result};
 use clap::{App, Arg, ArgMatches, SubCommand};
 
 use crate::utils::errors::Error::{
-    BinCodeSerializeFailed, DbOperationFailed, MissingRequiredArgument, NoObjectForGivenKey,
+    BinCodeDeserializeFailed, BinCodeSerializeFailed, DbOperationFailed, MissingRequiredArgument,
+    NoObjectForGivenKey,
 };
 use crate::utils::local::context::LocalContext;
 
+use crate::utils::local::helpers::arguments::read_literal_arguments;
 use crate::utils::local::helpers::prints::commands_outputs::print_update_success;
-use crate::utils::local::helpers::selector::validate_selector;
-use crate::utils::local::models::connection::OptionalConnection;
+use crate::utils::local::helpers::selector::{
+    parse_selector_argument, validate_selector, validate_selectors_pairing,
+};
+use crate::utils::local::models::connection::{Connection, OptionalConnection};
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
@@ -20,16 +24,16 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .required(true)
                 .value_name("ID"),
             Arg::with_name("tail-selector")
-                .help("Selector at the tail of the connection")
+                .help("Selector at the tail of the connection, as a JSON literal or a path (e.g. 0/2..5), inline, as @FILE or as - to read standard input")
                 .display_order(1)
                 .takes_value(true)
-                .value_name("JSON-SCHEMA")
+                .value_name("SELECTOR")
                 .long("tail-selector"),
             Arg::with_name("head-selector")
-                .help("Selector at the head of the connection")
+                .help("Selector at the head of the connection, as a JSON literal or a path (e.g. 0/2..5), inline, as @FILE or as - to read standard input")
                 .display_order(2)
                 .takes_value(true)
-                .value_name("JSON-SCHEMA")
+                .value_name("SELECTOR")
                 .long("head-selector"),
         ])
 }
@@ -42,8 +46,16 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let id = matches
         .value_of("id")
         .context(MissingRequiredArgument("id".to_string()))?;
-    let tail_selector = matches.value_of("tail-selector");
-    let head_selector = matches.value_of("head-selector");
+    let [tail_selector, head_selector] =
+        read_literal_arguments(matches, ["tail-selector", "head-selector"])?;
+    let tail_selector = tail_selector
+        .as_deref()
+        .map(parse_selector_argument)
+        .transpose()?;
+    let head_selector = head_selector
+        .as_deref()
+        .map(parse_selector_argument)
+        .transpose()?;
     // check that the object exists
     if !local_context
         .connections
@@ -53,17 +65,32 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
         return Err(NoObjectForGivenKey(id.to_string()).into());
     }
     // validate selectors, if any
-    if let Some(tail_selector) = tail_selector {
+    if let Some(tail_selector) = &tail_selector {
         validate_selector(tail_selector)?;
     }
-    if let Some(head_selector) = head_selector {
+    if let Some(head_selector) = &head_selector {
         validate_selector(head_selector)?;
     }
+    // validate that updated selectors can still be paired
+    if tail_selector.is_some() || head_selector.is_some() {
+        let encoded = local_context
+            .connections
+            .get(id)
+            .context(DbOperationFailed)?
+            .ok_or(NoObjectForGivenKey(id.to_string()))?;
+        let decoded: Connection = bincode::deserialize(&encoded[..])
+            .ok()
+            .context(BinCodeDeserializeFailed)?;
+        validate_selectors_pairing(
+            tail_selector.as_ref().unwrap_or(&decoded.tail_selector),
+            head_selector.as_ref().unwrap_or(&decoded.head_selector),
+        )?;
+    }
     // merge object
     let merge_connection = OptionalConnection {
         id: None,
-        tail_selector: tail_selector.map(|s| s.to_string()),
-        head_selector: head_selector.map(|s| s.to_string()),
+        tail_selector,
+        head_selector,
     };
     let merge_connection_encoded =
         bincode::serialize(&merge_connection).context(BinCodeSerializeFailed)?;
@@ -15,6 +15,7 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
         .subcommand(commands::update::cmd())
         .subcommand(commands::delete::cmd())
         .subcommand(commands::list::cmd())
+        .subcommand(commands::preview::cmd())
 }
 
 /// handler
@@ -25,6 +26,7 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
         ("update", Some(matches)) => commands::update::handle_cmd(matches),
         ("delete", Some(matches)) => commands::delete::handle_cmd(matches),
         ("list", Some(matches)) => commands::list::handle_cmd(matches),
+        ("preview", Some(matches)) => commands::preview::handle_cmd(matches),
         _ => unreachable!(), // If all subcommands are defined above, anything else should be unreachable!()
     }
 }
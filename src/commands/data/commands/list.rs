@@ -0,0 +1,41 @@
+//! List pipeline nodes with data stored in the local area
+
+use crate::utils::errors::Error::DbOperationFailed;
+use crate::utils::local::context::helpers::db_key_to_str;
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::prints::output::{output_format_arg, parse_output_format};
+use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+use crate::utils::local::models::data::NodeData;
+use anyhow::{Context, Result};
+use clap::{App, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("list")
+        .about("List all pipeline nodes with stored data")
+        .arg(output_format_arg())
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create local context
+    let local_context = LocalContext::new()?;
+    // get argument values
+    let output_format = parse_output_format(matches)?;
+    // iterate through stored data
+    let objects = local_context
+        .data
+        .iter()
+        .map(|o| -> Result<NodeData> {
+            let (typed_name_vec, holium_cbor) = o.context(DbOperationFailed)?;
+            Ok(NodeData {
+                node_typed_name: db_key_to_str(typed_name_vec)?,
+                size: holium_cbor.len(),
+            })
+        })
+        .collect::<Result<Vec<NodeData>>>()?;
+    let references: Vec<&NodeData> = objects.iter().collect();
+    // print
+    NodeData::list_print(references, &output_format)?;
+    Ok(())
+}
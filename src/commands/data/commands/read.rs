@@ -0,0 +1,53 @@
+//! Read the data stored for a pipeline node
+
+use crate::utils::errors::Error::{DbOperationFailed, MissingRequiredArgument, NoDataForNodeInput};
+use crate::utils::local::context::helpers::{
+    parse_node_typed_name, validate_pipeline_node_existence, PortationDirectionType,
+};
+use crate::utils::local::context::LocalContext;
+use crate::utils::repo::ports::formats::json::holium_cbor_to_json;
+use crate::utils::repo::ports::helpers::get_node_json_schema;
+use anyhow::{Context, Result};
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("read")
+        .about("Read the data stored for a pipeline node, rendered as JSON")
+        .arg(
+            Arg::with_name("node")
+                .help("Typed name of the node (eg: source:my-source)")
+                .required(true)
+                .value_name("TYPED-NAME")
+                .index(1),
+        )
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create local context
+    let local_context = LocalContext::new()?;
+    // get argument values
+    let node = matches
+        .value_of("node")
+        .context(MissingRequiredArgument("node".to_string()))?;
+    // validate the existence of the node
+    let (node_type, node_name) = parse_node_typed_name(node)?;
+    let node_typed_name = validate_pipeline_node_existence(&local_context, &node_type, &node_name)?;
+    // get stored data
+    let holium_cbor = local_context
+        .data
+        .get(&node_typed_name)
+        .context(DbOperationFailed)?
+        .ok_or(NoDataForNodeInput(node_typed_name.clone()))?;
+    // render it as JSON, as described by the node schema
+    let json_schema = get_node_json_schema(
+        &local_context,
+        &node_typed_name,
+        &PortationDirectionType::fromHolium,
+    )?;
+    let value = holium_cbor_to_json(&json_schema, &holium_cbor)?;
+    // print
+    println!("{}", serde_json::to_string_pretty(&value)?);
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Seed the data stored for a pipeline node from a file
+
+use crate::utils::errors::Error::{DbOperationFailed, MissingRequiredArgument};
+use crate::utils::local::context::helpers::{
+    parse_node_typed_name, validate_pipeline_node_existence, PortationDirectionType,
+};
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::prints::commands_outputs::print_update_success;
+use crate::utils::local::models::data::HoliumCbor;
+use crate::utils::repo::models::portation::PortationFileFormat;
+use crate::utils::repo::ports::helpers::get_node_json_schema;
+use crate::utils::repo::ports::import_to_holium::import_reader_to_holium;
+use anyhow::{Context, Error as AnyhowError, Result};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::io::BufReader;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to open file: {0}")]
+    FailedToOpenFile(String),
+    #[error("no data could be read from file: {0}")]
+    EmptyData(String),
+}
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("set")
+        .about("Set the data stored for a pipeline node from a file, without using a portation")
+        .args(&[
+            Arg::with_name("node")
+                .help("Typed name of the node (eg: source:my-source)")
+                .required(true)
+                .value_name("TYPED-NAME")
+                .index(1),
+            Arg::with_name("file")
+                .help("Path of the file to read data from")
+                .display_order(1)
+                .required(true)
+                .takes_value(true)
+                .value_name("PATH")
+                .long("file"),
+            Arg::with_name("format")
+                .help("Format of the file")
+                .display_order(2)
+                .required(true)
+                .takes_value(true)
+                .possible_values(&PortationFileFormat::variants())
+                .case_insensitive(true)
+                .value_name("FORMAT")
+                .long("format"),
+        ])
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create local context
+    let local_context = LocalContext::new()?;
+    // get argument values
+    let node = matches
+        .value_of("node")
+        .context(MissingRequiredArgument("node".to_string()))?;
+    let file_path = matches
+        .value_of("file")
+        .context(MissingRequiredArgument("file".to_string()))?;
+    let file_format_str = matches
+        .value_of("format")
+        .context(MissingRequiredArgument("format".to_string()))?;
+    let file_format = file_format_str
+        .parse::<PortationFileFormat>()
+        .map_err(AnyhowError::msg)?;
+    // validate the existence of the node
+    let (node_type, node_name) = parse_node_typed_name(node)?;
+    let node_typed_name = validate_pipeline_node_existence(&local_context, &node_type, &node_name)?;
+    // import data from the file, as described by the schema of data leaving the node, which is
+    // the data stored for it
+    let json_schema = get_node_json_schema(
+        &local_context,
+        &node_typed_name,
+        &PortationDirectionType::fromHolium,
+    )?;
+    let file =
+        std::fs::File::open(file_path).context(Error::FailedToOpenFile(file_path.to_string()))?;
+    let mut reader = BufReader::new(file);
+    let mut holium_cbor: HoliumCbor = Vec::new();
+    import_reader_to_holium(&json_schema, &file_format, &mut reader, &mut holium_cbor)?;
+    if holium_cbor.is_empty() {
+        return Err(Error::EmptyData(file_path.to_string()).into());
+    }
+    // store data
+    local_context
+        .data
+        .insert(&node_typed_name, holium_cbor)
+        .context(DbOperationFailed)?;
+    // print
+    print_update_success(&node_typed_name);
+    Ok(())
+}
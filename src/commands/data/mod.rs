@@ -0,0 +1,26 @@
+//! Inspect and seed the data stored for pipeline nodes in the local area.
+
+mod commands;
+
+use anyhow::Result;
+use clap::{App, AppSettings, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("data")
+        .about("Inspect and seed the data stored for pipeline nodes")
+        .setting(AppSettings::ArgRequiredElseHelp)
+        .subcommand(commands::list::cmd())
+        .subcommand(commands::read::cmd())
+        .subcommand(commands::set::cmd())
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    match matches.subcommand() {
+        ("list", Some(matches)) => commands::list::handle_cmd(matches),
+        ("read", Some(matches)) => commands::read::handle_cmd(matches),
+        ("set", Some(matches)) => commands::set::handle_cmd(matches),
+        _ => unreachable!(), // If all subcommands are defined above, anything else should be unreachable!()
+    }
+}
@@ -1,6 +1,5 @@
 //! Initialize a repository of Holium objects stored on the file system.
 
-use std::io::Write;
 use std::path::PathBuf;
 use std::{env, fs};
 
@@ -9,7 +8,8 @@ use clap::{App, Arg, ArgMatches, SubCommand};
 use console::style;
 use thiserror::Error;
 
-use crate::utils::repo::constants::{HOLIUM_DIR, INTERPLANETARY_DIR, LOCAL_DIR, PORTATIONS_FILE};
+use crate::utils::repo::constants::{HOLIUM_DIR, INTERPLANETARY_DIR};
+use crate::utils::repo::helpers::create_holium_dir;
 
 #[derive(Error, Debug)]
 /// errors
@@ -94,17 +94,7 @@ fn create_project_structure(
     is_dvc_enabled: bool,
 ) -> Result<()> {
     // Create project structure
-    let holium_dir = root_dir.join(HOLIUM_DIR);
-    fs::create_dir(&holium_dir)?;
-    fs::create_dir(&holium_dir.join(INTERPLANETARY_DIR))?;
-    fs::create_dir(&holium_dir.join(LOCAL_DIR))?;
-    fs::File::create(&holium_dir.join(PORTATIONS_FILE))?;
-
-    // Add a .gitignore file
-    if is_scm_enabled {
-        let gitignore_file = fs::File::create(&holium_dir.join(".gitignore"))?;
-        writeln!(&gitignore_file, "{}", LOCAL_DIR)?;
-    }
+    create_holium_dir(root_dir, is_scm_enabled)?;
 
     // Advise on running the tracking tool(s) once
     advise_to_track(is_scm_enabled, is_dvc_enabled);
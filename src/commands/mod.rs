@@ -1,8 +1,32 @@
 pub mod completion_script;
+pub mod config;
 pub mod connection;
+pub mod data;
 pub mod init;
 pub mod portation;
 pub mod project;
+pub mod selector;
 pub mod shaper;
 pub mod source;
 pub mod transformation;
+
+use anyhow::Result;
+use clap::ArgMatches;
+
+/// Run the command matched by the CLI.
+pub fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    match matches.subcommand() {
+        ("generate-shell-completions", Some(matches)) => completion_script::handle_cmd(matches),
+        ("init", Some(matches)) => init::handle_cmd(matches),
+        ("config", Some(matches)) => config::handle_cmd(matches),
+        ("source", Some(matches)) => source::handle_cmd(matches),
+        ("shaper", Some(matches)) => shaper::handle_cmd(matches),
+        ("transformation", Some(matches)) => transformation::handle_cmd(matches),
+        ("connection", Some(matches)) => connection::handle_cmd(matches),
+        ("data", Some(matches)) => data::handle_cmd(matches),
+        ("portation", Some(matches)) => portation::handle_cmd(matches),
+        ("project", Some(matches)) => project::handle_cmd(matches),
+        ("selector", Some(matches)) => selector::handle_cmd(matches),
+        _ => unreachable!(), // If all subcommands are defined above, anything else should be unreachable!()
+    }
+}
@@ -2,16 +2,20 @@ use anyhow::{Context, Error as AnyhowError, Result};
 use clap::{App, Arg, ArgMatches, SubCommand};
 
 use crate::utils::errors::Error::{MissingRequiredArgument, ObjectAlreadyExistsForGivenKey};
-use crate::utils::local::context::helpers::{build_portation_id, PortationDirectionType};
+use crate::utils::local::context::helpers::{
+    build_named_portation_id, build_portation_id, validate_portation_name, PortationDirectionType,
+};
 use crate::utils::local::context::helpers::{validate_pipeline_node_existence, NodeType};
 use crate::utils::local::context::LocalContext;
 
-use crate::utils::local::helpers::media_type::validate_mimetype_coherence;
+use crate::utils::local::helpers::arguments::read_literal_argument;
 use crate::utils::local::helpers::prints::commands_outputs::print_create_success;
+use crate::utils::local::helpers::selector::parse_selector_argument;
 
 use crate::utils::repo::context::RepositoryContext;
 use crate::utils::repo::helpers::to_relative_path_to_project_root;
 use crate::utils::repo::models::portation::{Portation, PortationFileFormat};
+use crate::utils::repo::ports::helpers::{validate_node_import_portations, validate_portation};
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
@@ -43,7 +47,7 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
             .value_name("NAME")
             .long("node-name"),
         Arg::with_name("file-path")
-            .help("Path of the file to port from/to")
+            .help("Path of the file, or of the directory, to port from/to")
             .display_order(4)
             .required(true)
             .takes_value(true)
@@ -58,6 +62,31 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
             .case_insensitive(true)
             .value_name("FORMAT")
             .long("file-format"),
+        Arg::with_name("file-pattern")
+            .help("Glob pattern selecting the files of the directory to port from/to, one per item of an array")
+            .display_order(6)
+            .takes_value(true)
+            .value_name("GLOB")
+            .long("file-pattern"),
+        Arg::with_name("file-name-field")
+            .help("Object property holding the name of the file of each item of a directory portation")
+            .display_order(7)
+            .takes_value(true)
+            .requires("file-pattern")
+            .value_name("FIELD")
+            .long("file-name-field"),
+        Arg::with_name("name")
+            .help("Name of the portation, to create several portations of a node in the same direction")
+            .display_order(8)
+            .takes_value(true)
+            .value_name("PORTATION_NAME")
+            .long("name"),
+        Arg::with_name("selector")
+            .help("Selector of the part of the node data to port from/to, as a JSON literal or a path, inline, as @FILE or as - to read standard input")
+            .display_order(9)
+            .takes_value(true)
+            .value_name("SELECTOR")
+            .long("selector"),
     ])
 }
 
@@ -82,6 +111,10 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let file_format = matches
         .value_of("file-format")
         .context(MissingRequiredArgument("file-format".to_string()))?;
+    let file_pattern = matches.value_of("file-pattern");
+    let file_name_field = matches.value_of("file-name-field");
+    let portation_name = matches.value_of("name");
+    let selector = matches.value_of("selector");
     // validate the existence of the node
     let node_type = node_type_str
         .parse::<NodeType>()
@@ -91,7 +124,13 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let direction = direction
         .parse::<PortationDirectionType>()
         .map_err(AnyhowError::msg)?;
-    let id = build_portation_id(&direction, &node_typed_name);
+    let id = match portation_name {
+        Some(portation_name) => {
+            validate_portation_name(portation_name)?;
+            build_named_portation_id(&direction, &node_typed_name, portation_name)
+        }
+        None => build_portation_id(&direction, &node_typed_name),
+    };
     // check that the object does not already exist
     if repo_context.portations.contains_key(&id.to_string()) {
         return Err(ObjectAlreadyExistsForGivenKey(id.to_string()).into());
@@ -102,14 +141,21 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let file_format = file_format
         .parse::<PortationFileFormat>()
         .map_err(AnyhowError::msg)?;
-    // validate coherence of file format and file name
-    validate_mimetype_coherence(&file_path, &file_format)?;
-    // create new object
+    // parse selector of partial portations, if any
+    let selector = selector
+        .map(|s| parse_selector_argument(&read_literal_argument(s)?))
+        .transpose()?;
+    // create and validate new object
     let object = Portation {
         id: id.clone(),
         file_path,
         file_format,
+        file_pattern: file_pattern.map(str::to_string),
+        file_name_field: file_name_field.map(str::to_string),
+        selector,
     };
+    validate_portation(&local_context, &object)?;
+    validate_node_import_portations(&repo_context, &object)?;
     // store new object
     repo_context.portations.insert(object.id.clone(), object)?;
     print_create_success(&id);
@@ -1,3 +1,4 @@
+use crate::utils::local::helpers::prints::output::{output_format_arg, parse_output_format};
 use crate::utils::local::helpers::prints::printable_model::PrintableModel;
 use crate::utils::repo::context::RepositoryContext;
 use crate::utils::repo::models::portation::Portation;
@@ -6,16 +7,20 @@ use clap::{App, ArgMatches, SubCommand};
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
-    SubCommand::with_name("list").about("List all portations")
+    SubCommand::with_name("list")
+        .about("List all portations")
+        .arg(output_format_arg())
 }
 
 /// handler
-pub(crate) fn handle_cmd(_matches: &ArgMatches) -> Result<()> {
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     // create repository context
     let repo_context = RepositoryContext::new()?;
+    // get argument values
+    let output_format = parse_output_format(matches)?;
     // iterate through stored objects
     let objects: Vec<&Portation> = repo_context.portations.values().collect();
     // print
-    Portation::table_print(objects);
+    Portation::list_print(objects, &output_format)?;
     Ok(())
 }
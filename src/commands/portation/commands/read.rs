@@ -1,8 +1,8 @@
 use crate::utils::errors::Error::{MissingRequiredArgument, NoObjectForGivenKey};
 
+use crate::utils::local::helpers::prints::output::{output_format_arg, parse_output_format};
 use crate::utils::local::helpers::prints::printable_model::PrintableModel;
 use crate::utils::repo::context::RepositoryContext;
-use crate::utils::repo::models::portation::Portation;
 use anyhow::{Context, Result};
 use clap::{App, Arg, ArgMatches, SubCommand};
 
@@ -10,10 +10,13 @@ use clap::{App, Arg, ArgMatches, SubCommand};
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
     SubCommand::with_name("read")
         .about("Read a portation")
-        .args(&[Arg::with_name("id")
-            .help("ID of the portation")
-            .required(true)
-            .value_name("ID")])
+        .args(&[
+            Arg::with_name("id")
+                .help("ID of the portation")
+                .required(true)
+                .value_name("ID"),
+            output_format_arg(),
+        ])
 }
 
 /// handler
@@ -24,12 +27,13 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let id = matches
         .value_of("id")
         .context(MissingRequiredArgument("id".to_string()))?;
+    let output_format = parse_output_format(matches)?;
     // get object
     let object = repo_context
         .portations
         .get(&id.to_string())
         .ok_or(NoObjectForGivenKey(id.to_string()))?;
     // print
-    Portation::table_print(vec![object]);
+    object.object_print(&output_format)?;
     Ok(())
 }
@@ -3,11 +3,15 @@ use clap::{App, Arg, ArgMatches, SubCommand};
 
 use crate::utils::errors::Error::{MissingRequiredArgument, NoObjectForGivenKey};
 
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::arguments::read_literal_argument;
 use crate::utils::local::helpers::prints::commands_outputs::print_update_success;
+use crate::utils::local::helpers::selector::parse_selector_argument;
 
 use crate::utils::repo::context::RepositoryContext;
 use crate::utils::repo::helpers::to_relative_path_to_project_root;
 use crate::utils::repo::models::portation::{Portation, PortationFileFormat};
+use crate::utils::repo::ports::helpers::{validate_node_import_portations, validate_portation};
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
@@ -19,7 +23,7 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .required(true)
                 .value_name("ID"),
             Arg::with_name("file-path")
-                .help("Path of the file to port from/to")
+                .help("Path of the file, or of the directory, to port from/to")
                 .display_order(1)
                 .takes_value(true)
                 .value_name("FILE")
@@ -32,12 +36,31 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .case_insensitive(true)
                 .value_name("FORMAT")
                 .long("file-format"),
+            Arg::with_name("file-pattern")
+                .help("Glob pattern selecting the files of the directory to port from/to, one per item of an array")
+                .display_order(3)
+                .takes_value(true)
+                .value_name("GLOB")
+                .long("file-pattern"),
+            Arg::with_name("file-name-field")
+                .help("Object property holding the name of the file of each item of a directory portation")
+                .display_order(4)
+                .takes_value(true)
+                .value_name("FIELD")
+                .long("file-name-field"),
+            Arg::with_name("selector")
+                .help("Selector of the part of the node data to port from/to, as a JSON literal or a path, inline, as @FILE or as - to read standard input")
+                .display_order(5)
+                .takes_value(true)
+                .value_name("SELECTOR")
+                .long("selector"),
         ])
 }
 
 /// handler
 pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
-    // create repository context
+    // create contexts
+    let local_context = LocalContext::new()?;
     let mut repo_context = RepositoryContext::new()?;
     // get argument values
     let id = matches
@@ -45,6 +68,9 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
         .context(MissingRequiredArgument("id".to_string()))?;
     let file_path_os_string = matches.value_of("file-path");
     let file_format_str = matches.value_of("file-format");
+    let file_pattern = matches.value_of("file-pattern");
+    let file_name_field = matches.value_of("file-name-field");
+    let selector = matches.value_of("selector");
     // check that the object exists
     let old_value = repo_context
         .portations
@@ -62,12 +88,26 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     } else {
         None
     };
+    // parse selector, if any
+    let selector = selector
+        .map(|s| parse_selector_argument(&read_literal_argument(s)?))
+        .transpose()?;
     // merge objects
     let object = Portation {
         id: (*old_value.id).to_string(),
         file_path: file_path.unwrap_or((*old_value.file_path).to_string()),
         file_format: file_format.unwrap_or(old_value.file_format.clone()),
+        file_pattern: file_pattern
+            .map(str::to_string)
+            .or(old_value.file_pattern.clone()),
+        file_name_field: file_name_field
+            .map(str::to_string)
+            .or(old_value.file_name_field.clone()),
+        selector: selector.or(old_value.selector.clone()),
     };
+    // validate the merged object, as on creation
+    validate_portation(&local_context, &object)?;
+    validate_node_import_portations(&repo_context, &object)?;
     // store object
     repo_context.portations.insert(object.id.clone(), object)?;
     print_update_success(id);
@@ -0,0 +1,61 @@
+//! Reconcile the local area with a declarative manifest
+
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::prints::commands_outputs::print_manifest_plan;
+use crate::utils::local::helpers::prints::output::{
+    output_format_arg, parse_output_format, print_structured, OutputFormat,
+};
+use crate::utils::local::manifest::{apply_manifest_plan, plan_manifest};
+use crate::utils::repo::constants::MANIFEST_FILE;
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::helpers::absolute_path;
+use crate::utils::repo::models::manifest::Manifest;
+
+use anyhow::Result;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::path::Path;
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("apply")
+        .about("Create, update and delete objects of the local area to match a manifest")
+        .args(&[
+            Arg::with_name("file")
+                .help("Path of the manifest file, holium.yaml at the project root by default")
+                .takes_value(true)
+                .value_name("PATH")
+                .long("file"),
+            Arg::with_name("dry-run")
+                .help("Only print the changes that would be applied")
+                .long("dry-run"),
+            output_format_arg(),
+        ])
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create contexts
+    let local_context = LocalContext::new()?;
+    let mut repo_context = RepositoryContext::new()?;
+    // get argument values
+    let output_format = parse_output_format(matches)?;
+    let manifest_path = match matches.value_of("file") {
+        Some(path) => absolute_path(path)?,
+        None => local_context.root_path.join(MANIFEST_FILE),
+    };
+    // read the manifest and compute changes
+    let manifest = Manifest::from_path(&manifest_path)?;
+    let manifest_dir = manifest_path.parent().unwrap_or(Path::new("."));
+    let plan = plan_manifest(&local_context, &repo_context, &manifest, manifest_dir)?;
+    // apply changes, unless asked not to
+    let dry_run = matches.is_present("dry-run");
+    if !dry_run {
+        apply_manifest_plan(&local_context, &mut repo_context, &plan)?;
+    }
+    // print
+    match output_format {
+        OutputFormat::human => print_manifest_plan(&plan.changes, dry_run),
+        _ => print_structured(&plan.changes, &output_format)?,
+    }
+    Ok(())
+}
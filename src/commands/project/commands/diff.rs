@@ -0,0 +1,95 @@
+//! Compare two versions of a pipeline
+
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::diff::diff_pipelines;
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::dag::models::PipelineDag;
+use crate::utils::local::export::export_project;
+use crate::utils::local::helpers::prints::commands_outputs::print_pipeline_diff;
+use crate::utils::local::helpers::prints::output::{
+    output_format_arg, parse_output_format, print_structured, OutputFormat,
+};
+use crate::utils::local::import::find_pipeline_blocks;
+
+use anyhow::{Context, Result};
+use cid::Cid;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::convert::TryFrom;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("invalid pipeline cid: {0}")]
+    InvalidPipelineCid(String),
+    #[error("failed to find a Pipeline block in the interplanetary area")]
+    NoPipelineBlock,
+    #[error("several Pipeline blocks found in the interplanetary area, the cid of the pipeline to compare to is required")]
+    SeveralPipelineBlocks,
+}
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("diff")
+        .about("Compare two versions of a pipeline")
+        .long_about(
+            "Compare two versions of a pipeline. With two cids, both pipelines are read from the \
+            interplanetary area. With a single cid, the current local project is compared to the \
+            given pipeline. Without any cid, it is compared to the pipeline found in the \
+            interplanetary area, which should then hold a single pipeline.",
+        )
+        .args(&[
+            Arg::with_name("old-cid")
+                .help("Cid of the old version of the pipeline")
+                .index(1)
+                .value_name("CID_A"),
+            Arg::with_name("new-cid")
+                .help("Cid of the new version of the pipeline")
+                .index(2)
+                .value_name("CID_B"),
+            output_format_arg(),
+        ])
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create interplanetary context
+    let ip_context = InterplanetaryContext::new()?;
+    // get argument values
+    let output_format = parse_output_format(matches)?;
+    let old_cid = match matches.value_of("old-cid") {
+        Some(cid_str) => parse_cid(cid_str)?,
+        None => find_exported_pipeline_block(&ip_context)?,
+    };
+    // compute the diff, exporting the local project to a temporary area when needed
+    let diff = match matches.value_of("new-cid") {
+        Some(cid_str) => diff_pipelines(&old_cid, &ip_context, &parse_cid(cid_str)?, &ip_context)?,
+        None => {
+            let local_context = LocalContext::new()?;
+            PipelineDag::from_local_context(&local_context)?.is_valid_pipeline()?;
+            let tmp_ip_context = InterplanetaryContext::new_in_memory();
+            let new_cid = export_project(&local_context, &tmp_ip_context)?;
+            diff_pipelines(&old_cid, &ip_context, &new_cid, &tmp_ip_context)?
+        }
+    };
+    // print the result
+    match output_format {
+        OutputFormat::human => print_pipeline_diff(&diff),
+        _ => print_structured(&diff, &output_format)?,
+    }
+    Ok(())
+}
+
+/// Parse a pipeline cid from a command argument.
+fn parse_cid(cid_str: &str) -> Result<Cid> {
+    Cid::try_from(cid_str).context(Error::InvalidPipelineCid(cid_str.to_string()))
+}
+
+/// Find the single pipeline of the interplanetary area, refusing to pick one when several pipelines
+/// are found.
+fn find_exported_pipeline_block(ip_context: &InterplanetaryContext) -> Result<Cid> {
+    let mut pipeline_cids = find_pipeline_blocks(ip_context)?;
+    match pipeline_cids.len() {
+        0 => Err(Error::NoPipelineBlock.into()),
+        1 => Ok(pipeline_cids.remove(0)),
+        _ => Err(Error::SeveralPipelineBlocks.into()),
+    }
+}
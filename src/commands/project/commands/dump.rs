@@ -0,0 +1,66 @@
+//! Describe the local area in a declarative manifest
+
+use crate::utils::errors::Error::ObjectAlreadyExistsForGivenKey;
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::prints::commands_outputs::print_manifest_dump_success;
+use crate::utils::local::manifest::dump_manifest;
+use crate::utils::repo::constants::MANIFEST_FILE;
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::helpers::absolute_path;
+
+use anyhow::Result;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::path::Path;
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("dump")
+        .about("Write a manifest describing the local area")
+        .long_about(
+            "Write a manifest describing the local area. The bytecode of transformations is \
+            written in a directory next to the manifest file.",
+        )
+        .args(&[
+            Arg::with_name("file")
+                .help("Path of the manifest file, holium.yaml at the project root by default")
+                .takes_value(true)
+                .value_name("PATH")
+                .long("file"),
+            Arg::with_name("bytecode-dir")
+                .help("Directory, relative to the manifest file, where bytecode is written")
+                .takes_value(true)
+                .default_value("wasm")
+                .value_name("DIR")
+                .long("bytecode-dir"),
+            Arg::with_name("force")
+                .help("Overwrite an existing manifest file")
+                .short("f")
+                .long("force"),
+        ])
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create contexts
+    let local_context = LocalContext::new()?;
+    let repo_context = RepositoryContext::new()?;
+    // get argument values
+    let manifest_path = match matches.value_of("file") {
+        Some(path) => absolute_path(path)?,
+        None => local_context.root_path.join(MANIFEST_FILE),
+    };
+    let bytecode_dir = matches.value_of("bytecode-dir").unwrap_or("wasm");
+    // check that the manifest does not already exist
+    if manifest_path.exists() && !matches.is_present("force") {
+        return Err(
+            ObjectAlreadyExistsForGivenKey(manifest_path.to_string_lossy().to_string()).into(),
+        );
+    }
+    // build and write the manifest
+    let manifest_dir = manifest_path.parent().unwrap_or(Path::new("."));
+    let manifest = dump_manifest(&local_context, &repo_context, manifest_dir, bytecode_dir)?;
+    manifest.save(&manifest_path)?;
+    // print
+    print_manifest_dump_success(&manifest_path.to_string_lossy());
+    Ok(())
+}
@@ -1,14 +1,19 @@
 use crate::utils::interplanetary::context::InterplanetaryContext;
 use crate::utils::interplanetary::fs::helpers::clear_ip_area::clear_ip_area;
+use crate::utils::interplanetary::multiformats::DEFAULT_MULTIBASE;
 use crate::utils::local::context::LocalContext;
 use crate::utils::local::dag::models::PipelineDag;
 use crate::utils::local::export::export_project;
 use crate::utils::local::helpers::prints::commands_outputs::{
     print_local_pipeline_health_success, print_project_export_success,
 };
+use crate::utils::local::helpers::prints::output::{
+    output_format_arg, parse_output_format, print_structured, OutputFormat,
+};
 
 use anyhow::Result;
 use clap::{App, Arg, ArgMatches, SubCommand};
+use serde_json::json;
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
@@ -19,6 +24,7 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .help("Check ability to export without writing to the interplanetary area")
                 .long("no-write"),
         )
+        .arg(output_format_arg())
 }
 
 /// handler
@@ -26,20 +32,33 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     // create local and interplanetary contexts
     let local_context = LocalContext::new()?;
     let ip_context = InterplanetaryContext::new()?;
+    // get argument values
+    let output_format = parse_output_format(matches)?;
     // create pipeline dag
     let dag = PipelineDag::from_local_context(&local_context)?;
     // check if the dag is healthy for export
     dag.is_valid_pipeline()?;
     // with the --no-write option, stop the execution there
     if matches.is_present("no-write") {
-        print_local_pipeline_health_success();
-        return Ok(());
+        return match output_format {
+            OutputFormat::human => {
+                print_local_pipeline_health_success();
+                Ok(())
+            }
+            _ => print_structured(&json!({ "valid": true }), &output_format),
+        };
     }
     // clean the interplanetary area
     clear_ip_area(&ip_context)?;
     // export pipeline from the local area to the interplanetary area
     let pipeline_cid = export_project(&local_context, &ip_context)?;
     // print success message
-    print_project_export_success(&pipeline_cid);
+    match output_format {
+        OutputFormat::human => print_project_export_success(&pipeline_cid),
+        _ => print_structured(
+            &json!({ "pipeline_cid": pipeline_cid.to_string_of_base(DEFAULT_MULTIBASE)? }),
+            &output_format,
+        )?,
+    }
     Ok(())
 }
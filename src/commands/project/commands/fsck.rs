@@ -0,0 +1,58 @@
+//! Check the integrity of the interplanetary area
+
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::fs::helpers::check_ip_area::{check_ip_area, quarantine_block};
+use crate::utils::local::helpers::prints::commands_outputs::{
+    print_ip_area_integrity_issues, print_ip_area_integrity_success,
+};
+use crate::utils::repo::constants::{HOLIUM_DIR, QUARANTINE_DIR};
+use crate::utils::repo::helpers::get_root_path;
+
+use anyhow::Result;
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("interplanetary area failed the integrity check with {0} issue(s)")]
+    IntegrityCheckFailed(usize),
+}
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("fsck")
+        .about("Check the integrity of all blocks in the interplanetary area")
+        .arg(
+            Arg::with_name("quarantine")
+                .help("Move corrupted blocks out of the interplanetary area")
+                .long("quarantine"),
+        )
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create interplanetary context
+    let ip_context = InterplanetaryContext::new()?;
+    // re-hash all blocks and check their links
+    let (reports, nb_blocks) = check_ip_area(&ip_context)?;
+    if reports.is_empty() {
+        print_ip_area_integrity_success(nb_blocks);
+        return Ok(());
+    }
+    // with the --quarantine option, move bad blocks out of the area
+    let quarantine = matches.is_present("quarantine");
+    let quarantine_dir_path = get_root_path()?.join(HOLIUM_DIR).join(QUARANTINE_DIR);
+    let issues = reports
+        .iter()
+        .map(|report| {
+            let quarantine_path = if quarantine && report.is_bad_block() {
+                let path = quarantine_block(&report.cid, &ip_context, &quarantine_dir_path)?;
+                Some(path.to_string_lossy().to_string())
+            } else {
+                None
+            };
+            Ok((report, quarantine_path))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    print_ip_area_integrity_issues(&issues);
+    Err(Error::IntegrityCheckFailed(reports.len()).into())
+}
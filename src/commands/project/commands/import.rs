@@ -4,11 +4,15 @@ use crate::utils::local::dag::models::PipelineDag;
 use crate::utils::local::helpers::prints::commands_outputs::{
     print_interplanetary_health_success, print_project_import_success,
 };
+use crate::utils::local::helpers::prints::output::{
+    output_format_arg, parse_output_format, print_structured, OutputFormat,
+};
 
 use crate::utils::local::import::import_project;
 
 use anyhow::Result;
 use clap::{App, Arg, ArgMatches, SubCommand};
+use serde_json::json;
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
@@ -19,10 +23,13 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .help("Check ability to import without replacing current local project")
                 .long("no-write"),
         )
+        .arg(output_format_arg())
 }
 
 /// handler
 pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // get argument values
+    let output_format = parse_output_format(matches)?;
     // create an interplanetary context and a temporary local one
     let (tmp_local_context, tmp_dir) = LocalContext::new_tmp()?;
     let ip_context = InterplanetaryContext::new()?;
@@ -33,8 +40,13 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     dag.is_valid_pipeline()?;
     // with the --no-write option, stop the execution there
     if matches.is_present("no-write") {
-        print_interplanetary_health_success();
-        return Ok(());
+        return match output_format {
+            OutputFormat::human => {
+                print_interplanetary_health_success();
+                Ok(())
+            }
+            _ => print_structured(&json!({ "valid": true }), &output_format),
+        };
     }
     // move the imported content to the project local area
     let local_context = LocalContext::new()?;
@@ -42,6 +54,9 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     // close temporary local context directory
     tmp_dir.close()?;
     // print success message
-    print_project_import_success();
+    match output_format {
+        OutputFormat::human => print_project_import_success(),
+        _ => print_structured(&json!({ "imported": true }), &output_format)?,
+    }
     Ok(())
 }
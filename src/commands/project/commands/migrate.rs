@@ -0,0 +1,71 @@
+//! Rewrite the interplanetary area with other settings
+
+use crate::utils::interplanetary::block_store::BlockStoreKind;
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::fs::helpers::migrate_ip_area::migrate_ip_area;
+use crate::utils::interplanetary::multiformats::HashFunction;
+use crate::utils::local::helpers::prints::commands_outputs::print_ip_area_migration_success;
+use crate::utils::local::import::find_pipeline_block;
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::helpers::get_root_path;
+
+use anyhow::Result;
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("migrate")
+        .about("Rewrite all blocks of the interplanetary area with another hash function or block store")
+        .args(&[
+            Arg::with_name("hash-function")
+                .help("Hash function to migrate the interplanetary area to")
+                .required_unless("block-store")
+                .takes_value(true)
+                .possible_values(&HashFunction::variants())
+                .case_insensitive(true)
+                .value_name("HASH_FUNCTION")
+                .long("hash-function"),
+            Arg::with_name("block-store")
+                .help("Block store to migrate the interplanetary area to")
+                .takes_value(true)
+                .possible_values(&BlockStoreKind::variants())
+                .case_insensitive(true)
+                .value_name("BLOCK_STORE")
+                .long("block-store"),
+        ])
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create repository and interplanetary contexts
+    let mut repo_context = RepositoryContext::new()?;
+    let ip_context = InterplanetaryContext::new()?;
+    // get argument values, defaulting to current settings
+    let hash_function = match matches.value_of("hash-function") {
+        Some(s) => s.parse::<HashFunction>()?,
+        None => repo_context.config.hash_function,
+    };
+    let block_store_kind = match matches.value_of("block-store") {
+        Some(s) => s.parse::<BlockStoreKind>()?,
+        None => repo_context.config.block_store,
+    };
+    // create the target interplanetary context
+    let target_ip_context = if block_store_kind == repo_context.config.block_store {
+        InterplanetaryContext {
+            hash_function,
+            ..ip_context.clone()
+        }
+    } else {
+        InterplanetaryContext::from_settings(&get_root_path()?, hash_function, &block_store_kind)?
+    };
+    // rewrite the interplanetary area
+    let migrated = migrate_ip_area(&ip_context, &target_ip_context)?;
+    // newly written blocks should now use the same settings
+    repo_context.config.hash_function = hash_function;
+    repo_context.config.block_store = block_store_kind;
+    repo_context.config.save()?;
+    // print success message, along with the new cid of the pipeline, if any
+    let pipeline_cid = find_pipeline_block(&target_ip_context).ok();
+    print_ip_area_migration_success(migrated.len(), &repo_context.config, pipeline_cid.as_ref());
+    Ok(())
+}
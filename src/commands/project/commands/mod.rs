@@ -1,3 +1,9 @@
+pub mod apply;
+pub mod diff;
+pub mod dump;
 pub mod export;
+pub mod fsck;
 pub mod import;
+pub mod migrate;
 pub mod run;
+pub mod runs;
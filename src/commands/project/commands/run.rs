@@ -1,39 +1,146 @@
 //! Run a transformation pipeline
 
+use crate::utils::interplanetary::context::InterplanetaryContext;
 use crate::utils::local::context::LocalContext;
 use crate::utils::local::dag::models::PipelineDag;
+use crate::utils::local::dag::provenance::{export_run, store_run};
 use crate::utils::local::helpers::prints::commands_outputs::{
-    print_pipeline_export_success, print_pipeline_run_success,
+    print_pipeline_export_success, print_pipeline_run_success, print_run_provenance_success,
 };
+use crate::utils::local::helpers::prints::output::{
+    output_format_arg, parse_output_format, print_structured, OutputFormat,
+};
+use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+use crate::utils::local::models::run::NodeReport;
 
-use anyhow::Result;
-use clap::{App, ArgMatches, SubCommand};
+use anyhow::{Context, Error as AnyhowError, Result};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use serde_json::json;
 
+use crate::utils::errors::Error::MissingRequiredArgument;
 use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::models::portation::PortationFileFormat;
+use crate::utils::repo::ports::ad_hoc::AdHocPortations;
 use crate::utils::run::runtime::Runtime;
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
-    SubCommand::with_name("run").about("Run local transformation pipeline if it is valid")
+    SubCommand::with_name("run")
+        .about("Run local transformation pipeline if it is valid")
+        .args(&[
+            Arg::with_name("import")
+                .help("Ad-hoc portation of data entering a node for this run only, as <typed-name>=<path>, or <typed-name>=- to read standard input")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .use_delimiter(true)
+                .env("HOLIUM_RUN_IMPORT")
+                .value_name("PORTATION")
+                .long("import"),
+            Arg::with_name("export")
+                .help("Ad-hoc portation of data leaving a node for this run only, as <typed-name>=<path>, or <typed-name>=- to write standard output")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .use_delimiter(true)
+                .env("HOLIUM_RUN_EXPORT")
+                .value_name("PORTATION")
+                .long("export"),
+            Arg::with_name("format")
+                .help("Format of the files of ad-hoc portations")
+                .takes_value(true)
+                .possible_values(&PortationFileFormat::variants())
+                .case_insensitive(true)
+                .default_value("json")
+                .env("HOLIUM_RUN_FORMAT")
+                .value_name("FORMAT")
+                .long("format"),
+            Arg::with_name("export-provenance")
+                .help("Export the provenance record of the run to the interplanetary area, along with the project, its pipeline linking to the records of all exported runs")
+                .long("export-provenance"),
+            Arg::with_name("report")
+                .help("Report the time, data sizes and guest memory used by each node")
+                .long("report"),
+            output_format_arg(),
+        ])
 }
 
 /// handler
-pub(crate) fn handle_cmd(_matches: &ArgMatches) -> Result<()> {
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     // create contexts
     let local_context = LocalContext::new()?;
-    let repo_context = RepositoryContext::new()?;
+    let mut repo_context = RepositoryContext::new()?;
+    // get argument values
+    let output_format = parse_output_format(matches)?;
+    let imports: Vec<&str> = matches.values_of("import").unwrap_or_default().collect();
+    let exports: Vec<&str> = matches.values_of("export").unwrap_or_default().collect();
+    let file_format = matches
+        .value_of("format")
+        .context(MissingRequiredArgument("format".to_string()))?
+        .parse::<PortationFileFormat>()
+        .map_err(AnyhowError::msg)?;
+    // register ad-hoc portations for this run
+    let ad_hoc_portations = AdHocPortations::register(
+        &local_context,
+        &mut repo_context,
+        &imports,
+        &exports,
+        &file_format,
+    )?;
     // create runtime
     let mut runtime = Runtime::new()?;
 
     // Run Pipeline dag from local context
-    let node_exports = PipelineDag::run(&mut runtime, &local_context, &repo_context)?;
+    let mut run = PipelineDag::run(&mut runtime, &local_context, &repo_context)?;
+    let node_exports = run.node_exports();
+    let report = if matches.is_present("report") {
+        Some(run.report())
+    } else {
+        None
+    };
+
+    // store the provenance record of the run, and export it if asked to
+    if matches.is_present("export-provenance") {
+        let ip_context = InterplanetaryContext::new()?;
+        export_run(&local_context, &ip_context, &mut run)?;
+    } else {
+        store_run(&local_context, &mut run)?;
+    }
+
+    // data exported to the standard output is the only thing printed on it
+    if ad_hoc_portations.writes_to_stdout() {
+        return ad_hoc_portations.flush_stdout();
+    }
+
+    if output_format != OutputFormat::human {
+        let exports: Vec<_> = node_exports
+            .iter()
+            .map(|(node_typed_name, file_path)| json!({ "node": node_typed_name, "file": file_path }))
+            .collect();
+        let mut output = json!({
+            "success": true,
+            "exports": exports,
+            "run": run.id,
+            "provenance_cid": run.block_cid,
+        });
+        if let Some(report) = &report {
+            output["report"] = report.iter().map(NodeReport::object_to_value).collect();
+        }
+        return print_structured(&output, &output_format);
+    }
 
     print_pipeline_run_success();
+    print_run_provenance_success(&run);
 
     if node_exports.len() > 0usize {
         println!();
         print_pipeline_export_success(&node_exports);
     }
 
+    if let Some(report) = &report {
+        println!();
+        NodeReport::table_print(report.iter().collect());
+    }
+
     Ok(())
 }
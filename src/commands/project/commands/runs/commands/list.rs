@@ -0,0 +1,28 @@
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::dag::provenance::list_runs;
+use crate::utils::local::helpers::prints::output::{output_format_arg, parse_output_format};
+use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+use crate::utils::local::models::run::Run;
+use anyhow::Result;
+use clap::{App, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("list")
+        .about("List the provenance records of all runs")
+        .arg(output_format_arg())
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create local context
+    let local_context = LocalContext::new()?;
+    // get argument values
+    let output_format = parse_output_format(matches)?;
+    // get stored records
+    let runs = list_runs(&local_context)?;
+    let references: Vec<&Run> = runs.iter().collect();
+    // print
+    Run::list_print(references, &output_format)?;
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::utils::errors::Error::MissingRequiredArgument;
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::dag::provenance::get_run;
+use crate::utils::local::helpers::prints::output::{
+    output_format_arg, parse_output_format, OutputFormat,
+};
+use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+use crate::utils::local::models::run::RunNode;
+use anyhow::{Context, Result};
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("show")
+        .about("Show the provenance record of a run")
+        .args(&[
+            Arg::with_name("id")
+                .help("ID of the run")
+                .required(true)
+                .value_name("ID"),
+            output_format_arg(),
+        ])
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create local context
+    let local_context = LocalContext::new()?;
+    // get argument values
+    let id = matches
+        .value_of("id")
+        .context(MissingRequiredArgument("id".to_string()))?;
+    let output_format = parse_output_format(matches)?;
+    // get record from local database
+    let run = get_run(&local_context, id)?;
+    // print the run, followed by the provenance of its nodes in the human format
+    run.object_print(&output_format)?;
+    if output_format == OutputFormat::human {
+        let references: Vec<&RunNode> = run.nodes.iter().collect();
+        RunNode::table_print(references);
+    }
+    Ok(())
+}
@@ -0,0 +1,24 @@
+//! Inspect the provenance records of pipeline runs
+
+mod commands;
+
+use anyhow::Result;
+use clap::{App, AppSettings, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("runs")
+        .about("Inspect the provenance records of pipeline runs")
+        .setting(AppSettings::ArgRequiredElseHelp)
+        .subcommand(commands::list::cmd())
+        .subcommand(commands::show::cmd())
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    match matches.subcommand() {
+        ("list", Some(matches)) => commands::list::handle_cmd(matches),
+        ("show", Some(matches)) => commands::show::handle_cmd(matches),
+        _ => unreachable!(), // If all subcommands are defined above, anything else should be unreachable!()
+    }
+}
@@ -10,17 +10,29 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
     SubCommand::with_name("project")
         .about("Run commands related to the whole project")
         .setting(AppSettings::ArgRequiredElseHelp)
+        .subcommand(commands::apply::cmd())
+        .subcommand(commands::diff::cmd())
+        .subcommand(commands::dump::cmd())
         .subcommand(commands::export::cmd())
+        .subcommand(commands::fsck::cmd())
         .subcommand(commands::import::cmd())
+        .subcommand(commands::migrate::cmd())
         .subcommand(commands::run::cmd())
+        .subcommand(commands::runs::cmd())
 }
 
 /// handler
 pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     match matches.subcommand() {
+        ("apply", Some(matches)) => commands::apply::handle_cmd(matches),
+        ("diff", Some(matches)) => commands::diff::handle_cmd(matches),
+        ("dump", Some(matches)) => commands::dump::handle_cmd(matches),
         ("export", Some(matches)) => commands::export::handle_cmd(matches),
+        ("fsck", Some(matches)) => commands::fsck::handle_cmd(matches),
         ("import", Some(matches)) => commands::import::handle_cmd(matches),
+        ("migrate", Some(matches)) => commands::migrate::handle_cmd(matches),
         ("run", Some(matches)) => commands::run::handle_cmd(matches),
+        ("runs", Some(matches)) => commands::runs::handle_cmd(matches),
         _ => unreachable!(), // If all subcommands are defined above, anything else should be unreachable!()
     }
 }
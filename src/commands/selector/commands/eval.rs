@@ -0,0 +1,80 @@
+//! Evaluate a selector against the data of a pipeline node
+
+use crate::utils::cbor::as_holium_cbor::AsHoliumCbor;
+use crate::utils::errors::Error::MissingRequiredArgument;
+use crate::utils::local::context::helpers::{
+    get_node_data, parse_node_typed_name, validate_pipeline_node_existence, PortationDirectionType,
+};
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::dag::models::node_selector;
+use crate::utils::local::helpers::arguments::read_literal_argument;
+use crate::utils::local::helpers::prints::commands_outputs::print_selection_preview;
+use crate::utils::local::helpers::selector::{
+    parse_selector_argument, selected_data_sets_to_json, validate_selector,
+};
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::ports::helpers::get_node_json_schema;
+use anyhow::{Context, Result};
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("eval")
+        .about("Evaluate a selector against the stored or ported data of a pipeline node")
+        .args(&[
+            Arg::with_name("node")
+                .help("Typed name of the node (eg: source:my-source)")
+                .display_order(1)
+                .required(true)
+                .takes_value(true)
+                .value_name("TYPED-NAME")
+                .long("node"),
+            Arg::with_name("selector")
+                .help("Selector to evaluate, as a JSON literal or a path (e.g. 0/2..5), inline, as @FILE or as - to read standard input")
+                .display_order(2)
+                .required(true)
+                .takes_value(true)
+                .value_name("SELECTOR")
+                .long("selector"),
+        ])
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    // create contexts
+    let local_context = LocalContext::new()?;
+    let repo_context = RepositoryContext::new()?;
+    // get argument values
+    let node = matches
+        .value_of("node")
+        .context(MissingRequiredArgument("node".to_string()))?;
+    let selector = read_literal_argument(
+        matches
+            .value_of("selector")
+            .context(MissingRequiredArgument("selector".to_string()))?,
+    )?;
+    // validate the existence of the node
+    let (node_type, node_name) = parse_node_typed_name(node)?;
+    let node_typed_name = validate_pipeline_node_existence(&local_context, &node_type, &node_name)?;
+    // parse and validate the selector
+    let selector = parse_selector_argument(&selector)?;
+    validate_selector(&selector)?;
+    let selector = node_selector(
+        &local_context,
+        &node_typed_name,
+        &PortationDirectionType::fromHolium,
+        &selector,
+    )?;
+    // apply the selector on node data, and render selected data as described by the node schema
+    let data = get_node_data(&local_context, &repo_context, &node_typed_name)?;
+    let json_schema = get_node_json_schema(
+        &local_context,
+        &node_typed_name,
+        &PortationDirectionType::fromHolium,
+    )?;
+    let data_sets =
+        selected_data_sets_to_json(&data.select_cbor(&selector)?, &selector, &json_schema)?;
+    // print
+    print_selection_preview(&data_sets, None);
+    Ok(())
+}
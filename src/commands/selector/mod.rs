@@ -0,0 +1,22 @@
+//! Work with selectors.
+
+mod commands;
+
+use anyhow::Result;
+use clap::{App, AppSettings, ArgMatches, SubCommand};
+
+/// command
+pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("selector")
+        .about("Work with selectors on the data of pipeline nodes")
+        .setting(AppSettings::ArgRequiredElseHelp)
+        .subcommand(commands::eval::cmd())
+}
+
+/// handler
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
+    match matches.subcommand() {
+        ("eval", Some(matches)) => commands::eval::handle_cmd(matches),
+        _ => unreachable!(), // If all subcommands are defined above, anything else should be unreachable!()
+    }
+}
@@ -8,6 +8,7 @@ use crate::utils::errors::Error::{
 use crate::utils::local::context::helpers::validate_node_name;
 use crate::utils::local::context::LocalContext;
 
+use crate::utils::local::helpers::arguments::read_literal_argument;
 use crate::utils::local::helpers::jsonschema::validate_pipeline_node_json_schema;
 use crate::utils::local::helpers::prints::commands_outputs::print_create_success;
 use crate::utils::local::helpers::prints::errors::Error::StructureCreationError;
@@ -23,7 +24,7 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .required(true)
                 .value_name("NAME"),
             Arg::with_name("json-schema")
-                .help("JSON Schema of the node")
+                .help("JSON Schema of the node, inline, as @FILE or as - to read standard input")
                 .required(true)
                 .takes_value(true)
                 .value_name("JSON-SCHEMA")
@@ -39,9 +40,11 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let name = matches
         .value_of("name")
         .context(MissingRequiredArgument("name".to_string()))?;
-    let json_schema = matches
-        .value_of("json-schema")
-        .context(MissingRequiredArgument("json-schema".to_string()))?;
+    let json_schema = read_literal_argument(
+        matches
+            .value_of("json-schema")
+            .context(MissingRequiredArgument("json-schema".to_string()))?,
+    )?;
     // check that the object does not already exist
     if local_context
         .shapers
@@ -53,7 +56,7 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     // validate the node name
     validate_node_name(name)?;
     // validate JSON schema
-    validate_pipeline_node_json_schema(json_schema)?;
+    validate_pipeline_node_json_schema(&json_schema)?;
     // create new object
     let object = Shaper {
         name: name.to_string(),
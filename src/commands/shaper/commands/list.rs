@@ -1,6 +1,7 @@
 use crate::utils::errors::Error::{BinCodeDeserializeFailed, DbOperationFailed};
 use crate::utils::local::context::helpers::db_key_to_str;
 use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::prints::output::{output_format_arg, parse_output_format};
 use crate::utils::local::helpers::prints::printable_model::PrintableModel;
 use crate::utils::local::models::shaper::Shaper;
 use anyhow::{Context, Result};
@@ -8,13 +9,17 @@ use clap::{App, ArgMatches, SubCommand};
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
-    SubCommand::with_name("list").about("List all nodes of this type")
+    SubCommand::with_name("list")
+        .about("List all nodes of this type")
+        .arg(output_format_arg())
 }
 
 /// handler
-pub(crate) fn handle_cmd(_matches: &ArgMatches) -> Result<()> {
+pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     // create local context
     let local_context = LocalContext::new()?;
+    // get argument values
+    let output_format = parse_output_format(matches)?;
     // iterate through stored objects
     let objects_result: Result<Vec<Shaper>> = local_context
         .shapers
@@ -32,6 +37,6 @@ pub(crate) fn handle_cmd(_matches: &ArgMatches) -> Result<()> {
     let objects = objects_result?;
     let references: Vec<&Shaper> = objects.iter().collect();
     // print
-    Shaper::table_print(references);
+    Shaper::list_print(references, &output_format)?;
     Ok(())
 }
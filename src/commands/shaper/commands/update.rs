@@ -6,6 +6,7 @@ use crate::utils::errors::Error::{
 };
 use crate::utils::local::context::LocalContext;
 
+use crate::utils::local::helpers::arguments::read_literal_argument;
 use crate::utils::local::helpers::jsonschema::validate_pipeline_node_json_schema;
 use crate::utils::local::helpers::prints::commands_outputs::print_update_success;
 use crate::utils::local::models::shaper::OptionalShaper;
@@ -20,7 +21,7 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .required(true)
                 .value_name("NAME"),
             Arg::with_name("json-schema")
-                .help("JSON Schema of the node")
+                .help("JSON Schema of the node, inline, as @FILE or as - to read standard input")
                 .takes_value(true)
                 .value_name("JSON-SCHEMA")
                 .long("json-schema"),
@@ -35,7 +36,10 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let name = matches
         .value_of("name")
         .context(MissingRequiredArgument("name".to_string()))?;
-    let json_schema = matches.value_of("json-schema");
+    let json_schema = matches
+        .value_of("json-schema")
+        .map(read_literal_argument)
+        .transpose()?;
     // check that the object exists
     if !local_context
         .shapers
@@ -45,13 +49,13 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
         return Err(NoObjectForGivenKey(name.to_string()).into());
     }
     // validate JSON schema, if any
-    if let Some(json_schema) = json_schema {
+    if let Some(json_schema) = &json_schema {
         validate_pipeline_node_json_schema(json_schema)?;
     }
     // merge object
     let merge_shaper = OptionalShaper {
         name: None,
-        json_schema: json_schema.map(|s| s.to_string()),
+        json_schema,
     };
     let merge_shaper_encoded = bincode::serialize(&merge_shaper).context(BinCodeSerializeFailed)?;
     local_context
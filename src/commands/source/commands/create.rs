@@ -1,5 +1,7 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Error as AnyhowError, Result};
 use clap::{App, Arg, ArgMatches, SubCommand};
+use std::io::BufReader;
+use std::path::Path;
 
 use crate::utils::errors::Error::{
     BinCodeSerializeFailed, DbOperationFailed, MissingRequiredArgument,
@@ -8,10 +10,23 @@ use crate::utils::errors::Error::{
 use crate::utils::local::context::helpers::validate_node_name;
 use crate::utils::local::context::LocalContext;
 
+use crate::utils::local::helpers::arguments::read_literal_argument;
+use crate::utils::local::helpers::jsonschema::inference::{infer_json_schema, SAMPLE_FORMATS};
 use crate::utils::local::helpers::jsonschema::validate_pipeline_node_json_schema;
-use crate::utils::local::helpers::prints::commands_outputs::print_create_success;
+use crate::utils::local::helpers::prints::commands_outputs::{
+    print_create_success, print_inferred_json_schema,
+};
 use crate::utils::local::helpers::prints::errors::Error::StructureCreationError;
 use crate::utils::local::models::source::Source;
+use crate::utils::repo::models::portation::PortationFileFormat;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to open sample file: {0}")]
+    FailedToOpenSampleFile(String),
+    #[error("format of the sample file could not be guessed from its extension: {0}")]
+    UnknownSampleFormat(String),
+}
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
@@ -23,11 +38,29 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .required(true)
                 .value_name("NAME"),
             Arg::with_name("json-schema")
-                .help("JSON Schema of the node")
-                .required(true)
+                .help("JSON Schema of the node, inline, as @FILE or as - to read standard input")
+                .required_unless("infer-from")
+                .conflicts_with("infer-from")
                 .takes_value(true)
                 .value_name("JSON-SCHEMA")
                 .long("json-schema"),
+            Arg::with_name("infer-from")
+                .help("Sample data file to infer the JSON Schema of the node from")
+                .takes_value(true)
+                .value_name("FILE")
+                .long("infer-from"),
+            Arg::with_name("format")
+                .help("Format of the sample data file, guessed from its extension by default")
+                .takes_value(true)
+                .requires("infer-from")
+                .possible_values(&SAMPLE_FORMATS)
+                .case_insensitive(true)
+                .value_name("FORMAT")
+                .long("format"),
+            Arg::with_name("dry-run")
+                .help("Only print the inferred JSON Schema, without creating the node")
+                .requires("infer-from")
+                .long("dry-run"),
         ])
 }
 
@@ -39,9 +72,22 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let name = matches
         .value_of("name")
         .context(MissingRequiredArgument("name".to_string()))?;
-    let json_schema = matches
-        .value_of("json-schema")
-        .context(MissingRequiredArgument("json-schema".to_string()))?;
+    let json_schema = match matches.value_of("infer-from") {
+        Some(sample_path) => {
+            let json_schema = infer_sample_json_schema(sample_path, matches.value_of("format"))?;
+            // only print the inferred schema for review, if asked to
+            if matches.is_present("dry-run") {
+                print_inferred_json_schema(&json_schema);
+                return Ok(());
+            }
+            json_schema
+        }
+        None => read_literal_argument(
+            matches
+                .value_of("json-schema")
+                .context(MissingRequiredArgument("json-schema".to_string()))?,
+        )?,
+    };
     // check that the object does not already exist
     if local_context
         .sources
@@ -53,7 +99,7 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     // validate the node name
     validate_node_name(name)?;
     // validate JSON schema
-    validate_pipeline_node_json_schema(json_schema)?;
+    validate_pipeline_node_json_schema(&json_schema)?;
     // create new object
     let object = Source {
         name: name.to_string(),
@@ -73,3 +119,21 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     print_create_success(name);
     Ok(())
 }
+
+/// Infer a JSON schema from a sample data file, in a given format or in the one of its extension.
+fn infer_sample_json_schema(sample_path: &str, format: Option<&str>) -> Result<String> {
+    let format = match format {
+        Some(format) => format,
+        None => Path::new(sample_path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .filter(|extension| SAMPLE_FORMATS.contains(&extension.to_lowercase().as_str()))
+            .ok_or_else(|| Error::UnknownSampleFormat(sample_path.to_string()))?,
+    };
+    let format = format
+        .parse::<PortationFileFormat>()
+        .map_err(AnyhowError::msg)?;
+    let file = std::fs::File::open(sample_path)
+        .context(Error::FailedToOpenSampleFile(sample_path.to_string()))?;
+    infer_json_schema(&format, &mut BufReader::new(file))
+}
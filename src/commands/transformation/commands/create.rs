@@ -9,7 +9,8 @@ use crate::utils::errors::Error::{
 };
 use crate::utils::local::context::helpers::validate_node_name;
 use crate::utils::local::context::LocalContext;
-use crate::utils::local::helpers::bytecode::read_all_wasm_module;
+use crate::utils::local::helpers::arguments::read_literal_arguments;
+use crate::utils::local::helpers::bytecode::{read_all_wasm_module, validate_wasm_function_export};
 use crate::utils::local::helpers::jsonschema::validate_pipeline_node_json_schema;
 use crate::utils::local::helpers::prints::commands_outputs::print_create_success;
 use crate::utils::local::helpers::prints::errors::Error::StructureCreationError;
@@ -39,13 +40,13 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .short("h")
                 .long("handle"),
             Arg::with_name("json-schema-in")
-                .help("JSON Schema of the input parameter")
+                .help("JSON Schema of the input parameter, inline, as @FILE or as - to read standard input")
                 .required(true)
                 .takes_value(true)
                 .value_name("JSON-SCHEMA-IN")
                 .long("json-schema-in"),
             Arg::with_name("json-schema-out")
-                .help("JSON Schema of the output parameter")
+                .help("JSON Schema of the output parameter, inline, as @FILE or as - to read standard input")
                 .required(true)
                 .takes_value(true)
                 .value_name("JSON-SCHEMA-OUT")
@@ -67,12 +68,12 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let handle = matches
         .value_of("handle")
         .context(MissingRequiredArgument("handle".to_string()))?;
-    let json_schema_in = matches
-        .value_of("json-schema-in")
-        .context(MissingRequiredArgument("json-schema-in".to_string()))?;
-    let json_schema_out = matches
-        .value_of("json-schema-out")
-        .context(MissingRequiredArgument("json-schema-out".to_string()))?;
+    let [json_schema_in, json_schema_out] =
+        read_literal_arguments(matches, ["json-schema-in", "json-schema-out"])?;
+    let json_schema_in =
+        json_schema_in.context(MissingRequiredArgument("json-schema-in".to_string()))?;
+    let json_schema_out =
+        json_schema_out.context(MissingRequiredArgument("json-schema-out".to_string()))?;
     // check that the object does not already exist
     if local_context
         .transformations
@@ -86,16 +87,18 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     // validate the bytecode file path
     let bytecode_path = PathBuf::from(bytecode_path_os_string);
     let bytecode = read_all_wasm_module(&bytecode_path)?;
+    // validate that the module exports the handle
+    validate_wasm_function_export(&bytecode, handle)?;
     // validate JSON schemata
-    validate_pipeline_node_json_schema(json_schema_in)?;
-    validate_pipeline_node_json_schema(json_schema_out)?;
+    validate_pipeline_node_json_schema(&json_schema_in)?;
+    validate_pipeline_node_json_schema(&json_schema_out)?;
     // create new object
     let object = Transformation {
         name: name.to_string(),
         bytecode,
         handle: handle.to_string(),
-        json_schema_in: json_schema_in.to_string(),
-        json_schema_out: json_schema_out.to_string(),
+        json_schema_in,
+        json_schema_out,
     };
     // store new object
     let encoded: Vec<u8> = bincode::serialize(&object).context(BinCodeSerializeFailed)?;
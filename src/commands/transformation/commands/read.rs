@@ -2,6 +2,7 @@ use crate::utils::errors::Error::{
     BinCodeDeserializeFailed, DbOperationFailed, MissingRequiredArgument, NoObjectForGivenKey,
 };
 use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::prints::output::{output_format_arg, parse_output_format};
 use crate::utils::local::helpers::prints::printable_model::PrintableModel;
 use crate::utils::local::models::transformation::Transformation;
 use anyhow::{Context, Result};
@@ -9,12 +10,13 @@ use clap::{App, Arg, ArgMatches, SubCommand};
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
-    SubCommand::with_name("read")
-        .about("Read a node")
-        .args(&[Arg::with_name("name")
+    SubCommand::with_name("read").about("Read a node").args(&[
+        Arg::with_name("name")
             .help("Name of the node")
             .required(true)
-            .value_name("NAME")])
+            .value_name("NAME"),
+        output_format_arg(),
+    ])
 }
 
 /// handler
@@ -25,6 +27,7 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
     let name = matches
         .value_of("name")
         .context(MissingRequiredArgument("name".to_string()))?;
+    let output_format = parse_output_format(matches)?;
     // get object from local database
     let encoded = local_context
         .transformations
@@ -36,6 +39,6 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
         .context(BinCodeDeserializeFailed)?;
     decoded.name = name.to_string();
     // print
-    Transformation::table_print(vec![&decoded]);
+    decoded.object_print(&output_format)?;
     Ok(())
 }
@@ -4,13 +4,19 @@ use anyhow::{Context, Result};
 use clap::{App, Arg, ArgMatches, SubCommand};
 
 use crate::utils::errors::Error::{
-    BinCodeSerializeFailed, DbOperationFailed, MissingRequiredArgument, NoObjectForGivenKey,
+    BinCodeDeserializeFailed, BinCodeSerializeFailed, DbOperationFailed, MissingRequiredArgument,
+    NoObjectForGivenKey,
 };
 use crate::utils::local::context::LocalContext;
-use crate::utils::local::helpers::bytecode::read_all_wasm_module;
+use crate::utils::local::helpers::arguments::read_literal_arguments;
+use crate::utils::local::helpers::bytecode::{read_all_wasm_module, validate_wasm_function_export};
+use crate::utils::local::helpers::impact::transformation_update_report;
 use crate::utils::local::helpers::jsonschema::validate_pipeline_node_json_schema;
-use crate::utils::local::helpers::prints::commands_outputs::print_update_success;
-use crate::utils::local::models::transformation::OptionalTransformation;
+use crate::utils::local::helpers::prints::commands_outputs::{
+    print_transformation_update_report, print_update_success,
+};
+use crate::utils::local::models::transformation::{OptionalTransformation, Transformation};
+use crate::utils::repo::context::RepositoryContext;
 
 /// command
 pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
@@ -34,38 +40,45 @@ pub(crate) fn cmd<'a, 'b>() -> App<'a, 'b> {
                 .short("h")
                 .long("handle"),
             Arg::with_name("json-schema-in")
-                .help("JSON Schema of the input parameter")
+                .help("JSON Schema of the input parameter, inline, as @FILE or as - to read standard input")
                 .takes_value(true)
                 .value_name("JSON-SCHEMA-IN")
                 .long("json-schema-in"),
             Arg::with_name("json-schema-out")
-                .help("JSON Schema of the output parameter")
+                .help("JSON Schema of the output parameter, inline, as @FILE or as - to read standard input")
                 .takes_value(true)
                 .value_name("JSON-SCHEMA-OUT")
                 .long("json-schema-out"),
+            Arg::with_name("dry-run")
+                .help("Only print the changes and the connections and portations they impact")
+                .long("dry-run"),
         ])
 }
 
 /// handler
 pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
-    // create local context
+    // create contexts
     let local_context = LocalContext::new()?;
+    let repo_context = RepositoryContext::new()?;
     // get argument values
     let name = matches
         .value_of("name")
         .context(MissingRequiredArgument("name".to_string()))?;
     let bytecode_path_os_string = matches.value_of("bytecode");
     let handle = matches.value_of("handle");
-    let json_schema_in = matches.value_of("json-schema-in");
-    let json_schema_out = matches.value_of("json-schema-out");
-    // check that the object exists
-    if !local_context
+    let [json_schema_in, json_schema_out] =
+        read_literal_arguments(matches, ["json-schema-in", "json-schema-out"])?;
+    let dry_run = matches.is_present("dry-run");
+    // get the current object
+    let encoded = local_context
         .transformations
-        .contains_key(name)
+        .get(name)
         .context(DbOperationFailed)?
-    {
-        return Err(NoObjectForGivenKey(name.to_string()).into());
-    }
+        .ok_or(NoObjectForGivenKey(name.to_string()))?;
+    let mut old: Transformation = bincode::deserialize(&encoded[..])
+        .ok()
+        .context(BinCodeDeserializeFailed)?;
+    old.name = name.to_string();
     // validate the bytecode file path, if any
     let bytecode = bytecode_path_os_string
         .map(|path_os_string| {
@@ -74,19 +87,42 @@ pub(crate) fn handle_cmd(matches: &ArgMatches) -> Result<()> {
         })
         .transpose()?;
     // validate JSON schemata, if any
-    if let Some(json_schema_in) = json_schema_in {
+    if let Some(json_schema_in) = &json_schema_in {
         validate_pipeline_node_json_schema(json_schema_in)?;
     }
-    if let Some(json_schema_out) = json_schema_out {
+    if let Some(json_schema_out) = &json_schema_out {
         validate_pipeline_node_json_schema(json_schema_out)?;
     }
+    // build the updated object
+    let new = Transformation {
+        name: name.to_string(),
+        bytecode: bytecode.clone().unwrap_or_else(|| old.bytecode.clone()),
+        handle: handle.unwrap_or(&old.handle).to_string(),
+        json_schema_in: json_schema_in
+            .clone()
+            .unwrap_or_else(|| old.json_schema_in.clone()),
+        json_schema_out: json_schema_out
+            .clone()
+            .unwrap_or_else(|| old.json_schema_out.clone()),
+    };
+    // validate that the module still exports the handle
+    if bytecode.is_some() || handle.is_some() {
+        validate_wasm_function_export(&new.bytecode, &new.handle)?;
+    }
+    // report changes and their impact on connected objects
+    let report = transformation_update_report(&local_context, &repo_context, &old, &new)?;
+    print_transformation_update_report(name, &report);
+    if dry_run {
+        return Ok(());
+    }
+    report.validate(name)?;
     // merge object
     let merge_transformation = OptionalTransformation {
         name: None,
         bytecode,
         handle: handle.map(|s| s.to_string()),
-        json_schema_in: json_schema_in.map(|s| s.to_string()),
-        json_schema_out: json_schema_out.map(|s| s.to_string()),
+        json_schema_in,
+        json_schema_out,
     };
     let merge_transformation_encoded =
         bincode::serialize(&merge_transformation).context(BinCodeSerializeFailed)?;
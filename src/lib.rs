@@ -0,0 +1,36 @@
+//! This crate provides a CLI and a library offering a simple implementation of the
+//! [Holium](https://holium.org/) protocol.
+//!
+//! The library entry point is [Project], a handle on a Holium project stored on the file system:
+//!
+//! ```no_run
+//! use holium::Project;
+//! use serde_json::json;
+//!
+//! let project = Project::open("path/to/project")?;
+//! project.set_data("source:my-source", &json!(["hello"]))?;
+//! project.run()?;
+//! let output = project.data("transformation:my-transformation")?;
+//! # Ok::<(), holium::Error>(())
+//! ```
+//!
+//! Check out the [official documentation](https://docs.holium.org/) for more information.
+
+#[macro_use]
+extern crate alloc;
+extern crate humansize;
+extern crate lazy_static;
+extern crate prettytable;
+
+mod api;
+mod commands;
+mod utils;
+
+pub use api::{Connection, Error, Project, Result, Shaper, Source, Transformation};
+
+/// Entry points of the command line interface, used by the `holium` binary.
+#[doc(hidden)]
+pub mod cli {
+    pub use crate::commands::handle_cmd;
+    pub use crate::utils::cli::build_cli;
+}
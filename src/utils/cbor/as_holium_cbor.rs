@@ -2,7 +2,9 @@ use crate::utils::cbor::helpers::{
     fetch_recursive_elements_detail, read_header, retrieve_cbor_in_reader, ParseError,
     SelectorError,
 };
-use crate::utils::interplanetary::kinds::selector::Selector;
+use crate::utils::interplanetary::kinds::selector::{
+    Condition, ExploreRecursive, Kind, RecursionLimit, Selector,
+};
 use anyhow::Result;
 use std::io::Cursor;
 
@@ -39,7 +41,7 @@ pub trait AsHoliumCbor {
             _ => return Err(ParseError::RootNotArray.into()),
         }
 
-        Ok(major_type.select(selector_envelope)?)
+        Ok(major_type.select(selector_envelope, buff.get_ref())?)
     }
 
     fn select_cbor(&self, selector_envelope: &Selector) -> Result<Vec<Vec<Vec<u8>>>> {
@@ -167,38 +169,129 @@ impl MajorType {
     }
 
     /// Find a major type by using a selector. Returned value is a list of data set description. If
-    /// the selector contains a union (`|`) operator then we have multiple data sets. Then each
-    /// data set contains one or multiple fetched [`MajorType`]
-    pub fn select(&self, selector: &Selector) -> Result<Vec<Vec<MajorType>>> {
+    /// the selector contains union (`|`) operators, at any depth, then we have one data set per
+    /// union branch, in the order they appear in the selector. Then each data set contains one or
+    /// multiple fetched [`MajorType`]. The `source` is the serialized object the major type was
+    /// read from, used to evaluate conditions on values.
+    pub fn select(&self, selector: &Selector, source: &[u8]) -> Result<Vec<Vec<MajorType>>> {
+        self.select_in_recursion(selector, source, None)
+    }
+
+    fn select_in_recursion(
+        &self,
+        selector: &Selector,
+        source: &[u8],
+        recursion: Option<&Recursion>,
+    ) -> Result<Vec<Vec<MajorType>>> {
         match selector {
             Selector::Matcher(_) => Ok(vec![vec![self.clone()]]),
             Selector::ExploreIndex(explore_index) => match &self {
                 MajorType::Array(recursive_type) | MajorType::Map(recursive_type) => {
-                    let major_type = recursive_type.child(explore_index.index as usize)?;
-                    Ok(major_type.select(&explore_index.next)?)
+                    match recursive_type.child(explore_index.index as usize) {
+                        Ok(major_type) => {
+                            major_type.select_in_recursion(&explore_index.next, source, recursion)
+                        }
+                        // while recursing, missing nodes are simply not selected
+                        Err(_) if recursion.is_some() => Ok(vec![]),
+                        Err(err) => Err(err),
+                    }
                 }
+                _ if recursion.is_some() => Ok(vec![]),
                 _ => Err(ParseError::MajorTypeNonRecursive.into()),
             },
-            Selector::ExploreRange(explore_range) => {
-                // After a range we expect a matcher, otherwise error
-                if !explore_range.next.is_matcher() {
-                    return Err(SelectorError::NonValidSelectorStructure.into());
+            Selector::ExploreRange(explore_range) => match &self {
+                MajorType::Array(recursive_type) | MajorType::Map(recursive_type) => {
+                    // merge results of children in the range, data set by data set
+                    let mut data_sets: Vec<Vec<MajorType>> = vec![];
+                    for index in explore_range.start..explore_range.end {
+                        let child_data_sets = recursive_type
+                            .child(index as usize)?
+                            .select_in_recursion(&explore_range.next, source, recursion)?;
+                        for (i, mut data_set) in child_data_sets.into_iter().enumerate() {
+                            match data_sets.get_mut(i) {
+                                Some(merged_data_set) => merged_data_set.append(&mut data_set),
+                                None => data_sets.push(data_set),
+                            }
+                        }
+                    }
+                    // keep one data set per union branch, even with no children
+                    let data_sets_count = explore_range.next.data_sets_count();
+                    if data_sets.len() < data_sets_count {
+                        data_sets.resize(data_sets_count, vec![]);
+                    }
+                    Ok(data_sets)
                 }
-
-                let mut selected_major_types: Vec<MajorType> =
-                    Vec::with_capacity((explore_range.end - explore_range.start) as usize);
-
-                match &self {
-                    MajorType::Array(recursive_type) | MajorType::Map(recursive_type) => {
-                        for index in explore_range.start..explore_range.end {
-                            let major_type = recursive_type.child(index as usize)?;
-                            selected_major_types.push(major_type.clone());
+                _ => Err(ParseError::MajorTypeNonRecursive.into()),
+            },
+            Selector::ExploreAll(explore_all) => match &self {
+                MajorType::Array(recursive_type) | MajorType::Map(recursive_type) => {
+                    // merge results of all children, data set by data set
+                    let mut data_sets: Vec<Vec<MajorType>> = vec![];
+                    for major_type in recursive_type.elements.iter() {
+                        let child_data_sets =
+                            major_type.select_in_recursion(&explore_all.next, source, recursion)?;
+                        for (i, mut data_set) in child_data_sets.into_iter().enumerate() {
+                            match data_sets.get_mut(i) {
+                                Some(merged_data_set) => merged_data_set.append(&mut data_set),
+                                None => data_sets.push(data_set),
+                            }
                         }
                     }
-                    _ => return Err(ParseError::MajorTypeNonRecursive.into()),
+                    // keep one data set per union branch, even with no children
+                    let data_sets_count = explore_all.next.data_sets_count();
+                    if data_sets.len() < data_sets_count {
+                        data_sets.resize(data_sets_count, vec![]);
+                    }
+                    Ok(data_sets)
+                }
+                _ if recursion.is_some() => Ok(vec![]),
+                _ => Err(ParseError::MajorTypeNonRecursive.into()),
+            },
+            Selector::ExploreFields(explore_fields) => {
+                Err(SelectorError::UnresolvedFields(explore_fields.field_names()).into())
+            }
+            Selector::ExploreRecursive(explore_recursive) => {
+                let data_sets = match explore_recursive.limit {
+                    RecursionLimit::Depth(0) => vec![],
+                    RecursionLimit::Depth(depth) => self.select_in_recursion(
+                        &explore_recursive.sequence,
+                        source,
+                        Some(&Recursion {
+                            explore_recursive,
+                            remaining_depth: Some(depth),
+                        }),
+                    )?,
+                    RecursionLimit::None => self.select_in_recursion(
+                        &explore_recursive.sequence,
+                        source,
+                        Some(&Recursion {
+                            explore_recursive,
+                            remaining_depth: None,
+                        }),
+                    )?,
                 };
-
-                Ok(vec![selected_major_types])
+                // all nodes matched while recursing end up in a single data set
+                Ok(vec![data_sets.into_iter().flatten().collect()])
+            }
+            Selector::ExploreRecursiveEdge(_) => {
+                let recursion = recursion.ok_or(SelectorError::EdgeOutsideRecursion)?;
+                // stop if the limit is reached or if the stop condition is met
+                if recursion.remaining_depth.map_or(false, |depth| depth <= 1) {
+                    return Ok(vec![]);
+                }
+                if let Some(stop_at) = &recursion.explore_recursive.stop_at {
+                    if self.matches(stop_at, source)? {
+                        return Ok(vec![]);
+                    }
+                }
+                self.select_in_recursion(
+                    &recursion.explore_recursive.sequence,
+                    source,
+                    Some(&Recursion {
+                        explore_recursive: recursion.explore_recursive,
+                        remaining_depth: recursion.remaining_depth.map(|depth| depth - 1),
+                    }),
+                )
             }
             Selector::ExploreUnion(explore_union) => {
                 let selectors = &explore_union.0;
@@ -206,11 +299,147 @@ impl MajorType {
                     Vec::with_capacity(selectors.len());
 
                 for selector in selectors.iter() {
-                    selectors_results.append(&mut self.select(selector)?);
+                    selectors_results
+                        .append(&mut self.select_in_recursion(selector, source, recursion)?);
                 }
 
                 Ok(selectors_results)
             }
+            Selector::ExploreConditional(explore_conditional) => {
+                if self.matches(&explore_conditional.condition, source)? {
+                    self.select_in_recursion(&explore_conditional.next, source, recursion)
+                } else {
+                    // keep one empty data set per union branch for results to stay paired
+                    Ok(vec![vec![]; explore_conditional.next.data_sets_count()])
+                }
+            }
+        }
+    }
+
+    /// Kind of the node described by a major type, as used in selector conditions
+    fn kind(&self, source: &[u8]) -> Option<Kind> {
+        match self {
+            MajorType::Unsigned(_) | MajorType::Negative(_) => Some(Kind::Integer),
+            MajorType::Bytes(_) => Some(Kind::Bytes),
+            MajorType::String(_) => Some(Kind::String),
+            MajorType::Array(_) => Some(Kind::List),
+            MajorType::Map(_) => Some(Kind::Map),
+            MajorType::SimpleValues(scalar) => match source.get(scalar.header_offset as usize) {
+                Some(0xf4) | Some(0xf5) => Some(Kind::Bool),
+                Some(0xf6) => Some(Kind::Null),
+                _ => None,
+            },
+        }
+    }
+
+    /// Evaluate a selector condition against the node described by a major type
+    fn matches(&self, condition: &Condition, source: &[u8]) -> Result<bool> {
+        match condition {
+            Condition::HasKind(kind) => Ok(self.kind(source) == Some(*kind)),
+            Condition::HasValue(value) => {
+                let mut expected: Vec<u8> = Vec::new();
+                sk_cbor::write(value.clone().into(), &mut expected)
+                    .map_err(|_| SelectorError::NonValidSelectorStructure)?;
+                let (header_offset, size) = self.details();
+                let start = header_offset as usize;
+                Ok(source.get(start..start + size) == Some(&expected[..]))
+            }
         }
     }
 }
+
+/// [Recursion] holds the state of an [ExploreRecursive] selector being applied
+struct Recursion<'a> {
+    explore_recursive: &'a ExploreRecursive,
+    // number of times the sequence may still be applied, if limited
+    remaining_depth: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::local::models::data::HoliumCbor;
+    use sk_cbor::{cbor_array, cbor_text};
+    use std::convert::TryFrom;
+
+    fn holium_cbor(value: sk_cbor::Value) -> HoliumCbor {
+        let mut buffer: HoliumCbor = Vec::new();
+        sk_cbor::write(value, &mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn can_select_all_elements_matching_a_condition() {
+        let data = holium_cbor(cbor_array![cbor_text!("a"), 1, cbor_text!("b"), 2]);
+        let selector =
+            Selector::try_from(r#"{"a": {">": {"&": {"&": {"k": "string"}, ">": {".": {}}}}}}"#)
+                .unwrap();
+
+        let selected = data.select_cbor(&selector).unwrap();
+
+        assert_eq!(
+            vec![vec![
+                holium_cbor(cbor_text!("a")),
+                holium_cbor(cbor_text!("b"))
+            ]],
+            selected
+        );
+    }
+
+    #[test]
+    fn can_select_recursively_with_depth_limit() {
+        let data = holium_cbor(cbor_array![
+            1,
+            cbor_array![2, cbor_array![3, cbor_array![4]]]
+        ]);
+        let selector = Selector::try_from(
+            r#"{"R": {"l": {"depth": 2}, ":>": {"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {"@": {}}}}]}}}"#,
+        )
+        .unwrap();
+
+        let selected = data.select_cbor(&selector).unwrap();
+
+        assert_eq!(
+            vec![vec![holium_cbor(1.into()), holium_cbor(2.into())]],
+            selected
+        );
+    }
+
+    #[test]
+    fn can_select_in_range_then_explore_elements() {
+        let data = holium_cbor(cbor_array![
+            cbor_array![0],
+            cbor_array![1, 2],
+            cbor_array![3],
+            cbor_array![4]
+        ]);
+        let selector =
+            Selector::try_from(r#"{"r": {"^": 1, "$": 3, ">": {"a": {">": {".": {}}}}}}"#).unwrap();
+
+        let selected = data.select_cbor(&selector).unwrap();
+
+        assert_eq!(
+            vec![vec![
+                holium_cbor(1.into()),
+                holium_cbor(2.into()),
+                holium_cbor(3.into())
+            ]],
+            selected
+        );
+    }
+
+    #[test]
+    fn cannot_select_with_unresolved_fields() {
+        let data = holium_cbor(cbor_array![1]);
+        let selector = Selector::try_from(r#"{"f": {"f>": {"id": {".": {}}}}}"#).unwrap();
+
+        let res = data.select_cbor(&selector);
+
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("fields should be resolved against a json schema before selection: id"));
+    }
+}
@@ -28,8 +28,10 @@ pub enum SelectorError {
     NonValidSelectorStructure,
     #[error("no node for given selector")]
     NoNodeFound,
-    #[error("union can only be found at the root of a selector")]
-    UnionOnlyAtRoot,
+    #[error("fields should be resolved against a json schema before selection: {0}")]
+    UnresolvedFields(String),
+    #[error("recursive edge can only be found in the sequence of a recursive selector")]
+    EdgeOutsideRecursion,
     #[error("failed to select data at tail for connection: {0}")]
     DataAtTailSelectionFailed(String),
     #[error("result data set empty after tail selector is applied for connection: {0}")]
@@ -38,16 +40,18 @@ pub enum SelectorError {
 
 #[derive(thiserror::Error, Debug)]
 pub enum WriteError {
-    #[error("non compatible selectors for connection: {0}")]
-    NonCompatibleSelectors(String),
-    #[error("tail and head selectors union does not have the same number of elements in connection: {0}")]
-    DifferentUnionLength(String),
-    #[error("union should only be applied at root level for a Holium selector")]
-    UnionOnlyAtRootLevel,
+    #[error(
+        "tail selector produces {1} data set(s) while head selector expects {2} in connection: {0}"
+    )]
+    DataSetsCountMismatch(String, usize, usize),
+    #[error("unions should be expanded into write paths before writing data")]
+    UnexpandedUnion,
     #[error("tried to apply an index selection on a declared leaf in the tree")]
     IndexSelectionOnLeaf,
     #[error("tried to apply a range selection on a declared leaf in the tree")]
     RangeSelectionOnLeaf,
+    #[error("recursive and conditional selectors can not be used to write data")]
+    UnsupportedSelectorForWriting,
     #[error("index already taken by another element")]
     IndexAlreadyTaken,
     #[error("data set length is not equal to range length")]
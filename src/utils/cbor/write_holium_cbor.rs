@@ -34,35 +34,28 @@ pub trait WriteHoliumCbor {
                 SelectorError::DataAtTailSelectionFailed(connection_id.clone()),
             )?;
 
-            // If head selector a union, check that tail selector is also one with the same number of
-            // selectors
-            match &head_selector {
-                Selector::ExploreUnion(receiver_union) => match &tail_selector {
-                    Selector::ExploreUnion(source_union) => {
-                        if source_union.0.len() != receiver_union.0.len() {
-                            return Err(
-                                WriteError::DifferentUnionLength(connection_id.clone()).into()
-                            );
-                        }
+            if selected_cbor.is_empty() {
+                return Err(
+                    SelectorError::ResultDataSetEmptyAfterSelection(connection_id.clone()).into(),
+                );
+            }
 
-                        for (i, data_set) in selected_cbor.iter_mut().enumerate() {
-                            holium_cbor_constructor
-                                .ingest(&source_union.0[i], data_set)
-                                .context(WriteError::DataCopyFailed(connection_id.clone()))?;
-                        }
-                    }
-                    _ => {
-                        return Err(WriteError::NonCompatibleSelectors(connection_id.clone()).into())
-                    }
-                },
-                _ => {
-                    holium_cbor_constructor.ingest(
-                        &head_selector,
-                        &mut selected_cbor.get_mut(0).ok_or(
-                            SelectorError::ResultDataSetEmptyAfterSelection(connection_id.clone()),
-                        )?,
-                    )?;
-                }
+            // Data sets selected at the tail are paired, in order, with the branches of the head
+            // selector, used as write paths. Both should be in the same number.
+            let head_write_paths = head_selector.branches();
+            if selected_cbor.len() != head_write_paths.len() {
+                return Err(WriteError::DataSetsCountMismatch(
+                    connection_id.clone(),
+                    selected_cbor.len(),
+                    head_write_paths.len(),
+                )
+                .into());
+            }
+
+            for (write_path, data_set) in head_write_paths.iter().zip(selected_cbor.iter_mut()) {
+                holium_cbor_constructor
+                    .ingest(write_path, data_set)
+                    .context(WriteError::DataCopyFailed(connection_id.clone()))?;
             }
         }
 
@@ -120,6 +113,42 @@ impl RecursiveNode {
         }
     }
 
+    /// Get the non leaf child at a given index, creating it if it does not exist yet
+    fn non_leaf_child_as_mut(&mut self, index: usize) -> Result<&mut HoliumCborNode> {
+        if !self.has_child(index)? {
+            self.push_child(HoliumCborNode::NonLeaf(RecursiveNode {
+                index: Some(index as u64),
+                data: Right(vec![]),
+            }))?;
+        }
+        self.child_as_mut(index)
+    }
+
+    /// Ingest a single element of a data set at a given index, with the selector following a
+    /// range or an all selector
+    fn ingest_element(
+        &mut self,
+        index: usize,
+        next: &Selector,
+        element: &mut [Vec<u8>],
+    ) -> Result<()> {
+        match next {
+            Selector::Matcher(_) => {
+                // Making sure index is not already taken
+                if self.has_child(index)? {
+                    return Err(WriteError::IndexAlreadyTaken.into());
+                }
+                self.push_child(HoliumCborNode::Leaf(ScalarNode {
+                    index: index as u64,
+                    data: element.get(0).ok_or(WriteError::NoDataInDataSet)?.clone(),
+                }))
+            }
+            // Elements can only be deconstructed further through indexes
+            Selector::ExploreIndex(_) => self.non_leaf_child_as_mut(index)?.ingest(next, element),
+            _ => Err(SelectorError::NonValidSelectorStructure.into()),
+        }
+    }
+
     fn push_child(&mut self, child: HoliumCborNode) -> Result<()> {
         // Making sure index is not already taken
         if self.has_child(child.get_index().ok_or(WriteError::NoIndexOnChild)? as usize)? {
@@ -191,21 +220,26 @@ impl HoliumCborNode {
                                     }))?;
                                 }
                             }
-                            Selector::ExploreIndex(_) | Selector::ExploreRange(_) => {
-                                // If node does not exist then create it
-                                if !node.has_child(explore_index.index as usize)? {
-                                    node.push_child(HoliumCborNode::NonLeaf(RecursiveNode {
-                                        index: Some(explore_index.index),
-                                        data: Right(vec![]),
-                                    }))?;
-                                }
-
+                            Selector::ExploreIndex(_)
+                            | Selector::ExploreRange(_)
+                            | Selector::ExploreAll(_) => {
                                 return node
-                                    .child_as_mut(explore_index.index as usize)?
+                                    .non_leaf_child_as_mut(explore_index.index as usize)?
                                     .ingest(&explore_index.next, data_set);
                             }
                             Selector::ExploreUnion(_) => {
-                                return Err(SelectorError::UnionOnlyAtRoot.into())
+                                return Err(WriteError::UnexpandedUnion.into())
+                            }
+                            Selector::ExploreFields(explore_fields) => {
+                                return Err(SelectorError::UnresolvedFields(
+                                    explore_fields.field_names(),
+                                )
+                                .into())
+                            }
+                            Selector::ExploreRecursive(_)
+                            | Selector::ExploreRecursiveEdge(_)
+                            | Selector::ExploreConditional(_) => {
+                                return Err(WriteError::UnsupportedSelectorForWriting.into())
                             }
                         }
                     }
@@ -226,20 +260,42 @@ impl HoliumCborNode {
                         for (i, to_set_index) in
                             (explore_range.start..explore_range.end).enumerate()
                         {
-                            // Making sure index is not already taken
-                            if node.has_child(to_set_index as usize)? {
-                                return Err(WriteError::IndexAlreadyTaken.into());
-                            }
-                            node.push_child(HoliumCborNode::Leaf(ScalarNode {
-                                index: to_set_index,
-                                data: data_set[i].clone(),
-                            }))?;
+                            node.ingest_element(
+                                to_set_index as usize,
+                                &explore_range.next,
+                                &mut data_set[i..=i],
+                            )?;
+                        }
+                    }
+                    _ => return Err(WriteError::RangeSelectionOnLeaf.into()),
+                }
+            }
+            Selector::ExploreAll(explore_all) => {
+                // Making sure that we are on a Node and not a leaf. This can be avoided if selector is
+                // properly constructed
+                match self {
+                    HoliumCborNode::NonLeaf(node) => {
+                        // Each element of the data set is set at the index it has in the data set
+                        for to_set_index in 0..data_set.len() {
+                            node.ingest_element(
+                                to_set_index,
+                                &explore_all.next,
+                                &mut data_set[to_set_index..=to_set_index],
+                            )?;
                         }
                     }
                     _ => return Err(WriteError::RangeSelectionOnLeaf.into()),
                 }
             }
-            Selector::ExploreUnion(_) => return Err(WriteError::UnionOnlyAtRootLevel.into()),
+            Selector::ExploreFields(explore_fields) => {
+                return Err(SelectorError::UnresolvedFields(explore_fields.field_names()).into())
+            }
+            Selector::ExploreRecursive(_)
+            | Selector::ExploreRecursiveEdge(_)
+            | Selector::ExploreConditional(_) => {
+                return Err(WriteError::UnsupportedSelectorForWriting.into())
+            }
+            Selector::ExploreUnion(_) => return Err(WriteError::UnexpandedUnion.into()),
             Selector::Matcher(_) => {
                 // If we arrive here it means that we are at root, just checking for safety with
                 // an unreachable macro if not on a node type
@@ -308,3 +364,138 @@ impl HoliumCborNode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::local::models::data::HoliumCbor;
+    use sk_cbor::{cbor_array, cbor_text};
+    use std::convert::TryFrom;
+
+    fn holium_cbor(value: sk_cbor::Value) -> HoliumCbor {
+        let mut buffer: HoliumCbor = Vec::new();
+        sk_cbor::write(value, &mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn can_copy_data_with_nested_unions() {
+        let data_at_tail = holium_cbor(cbor_array![
+            cbor_text!("a"),
+            cbor_array![cbor_text!("b"), cbor_text!("c")]
+        ]);
+        let tail_selector = Selector::try_from(
+            r#"{"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {".": {}}}}]}}}]}"#,
+        )
+        .unwrap();
+        let head_selector = Selector::try_from(
+            r#"{"i": {"i": 0, ">": {"|": [{"i": {"i": 2, ">": {".": {}}}}, {"i": {"i": 1, ">": {".": {}}}}, {"i": {"i": 0, ">": {".": {}}}}]}}}"#,
+        )
+        .unwrap();
+        let mut data: HoliumCbor = Vec::new();
+
+        data.copy_cbor(&vec![(
+            "connection".to_string(),
+            data_at_tail,
+            tail_selector,
+            head_selector,
+        )])
+        .unwrap();
+
+        assert_eq!(
+            holium_cbor(cbor_array![cbor_array![
+                cbor_text!("c"),
+                cbor_text!("b"),
+                cbor_text!("a")
+            ]]),
+            data
+        );
+    }
+
+    #[test]
+    fn can_copy_data_with_union_nested_under_range() {
+        let data_at_tail = holium_cbor(cbor_array![
+            cbor_array![cbor_text!("a"), cbor_text!("b")],
+            cbor_array![cbor_text!("c"), cbor_text!("d")]
+        ]);
+        let tail_selector = Selector::try_from(
+            r#"{"r": {"^": 0, "$": 2, ">": {"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {".": {}}}}]}}}"#,
+        )
+        .unwrap();
+        let head_selector = Selector::try_from(
+            r#"{"r": {"^": 0, "$": 2, ">": {"|": [{"i": {"i": 1, ">": {".": {}}}}, {"i": {"i": 0, ">": {".": {}}}}]}}}"#,
+        )
+        .unwrap();
+        assert_eq!(tail_selector.data_sets_count(), 2);
+        assert_eq!(head_selector.data_sets_count(), 2);
+        let mut data: HoliumCbor = Vec::new();
+
+        data.copy_cbor(&vec![(
+            "connection".to_string(),
+            data_at_tail,
+            tail_selector,
+            head_selector,
+        )])
+        .unwrap();
+
+        assert_eq!(
+            holium_cbor(cbor_array![
+                cbor_array![cbor_text!("b"), cbor_text!("a")],
+                cbor_array![cbor_text!("d"), cbor_text!("c")]
+            ]),
+            data
+        );
+    }
+
+    #[test]
+    fn can_copy_data_with_union_nested_under_all() {
+        let data_at_tail = holium_cbor(cbor_array![cbor_text!("a"), cbor_text!("b")]);
+        let tail_selector =
+            Selector::try_from(r#"{"|": [{"a": {">": {".": {}}}}, {"a": {">": {".": {}}}}]}"#)
+                .unwrap();
+        let head_selector = Selector::try_from(
+            r#"{"a": {">": {"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {".": {}}}}]}}}"#,
+        )
+        .unwrap();
+        let mut data: HoliumCbor = Vec::new();
+
+        data.copy_cbor(&vec![(
+            "connection".to_string(),
+            data_at_tail,
+            tail_selector,
+            head_selector,
+        )])
+        .unwrap();
+
+        assert_eq!(
+            holium_cbor(cbor_array![
+                cbor_array![cbor_text!("a"), cbor_text!("a")],
+                cbor_array![cbor_text!("b"), cbor_text!("b")]
+            ]),
+            data
+        );
+    }
+
+    #[test]
+    fn cannot_copy_data_with_unpaired_data_sets() {
+        let data_at_tail = holium_cbor(cbor_array![cbor_text!("a"), cbor_text!("b")]);
+        let tail_selector = Selector::try_from(
+            r#"{"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {".": {}}}}]}"#,
+        )
+        .unwrap();
+        let head_selector = Selector::try_from(r#"{"i": {"i": 0, ">": {".": {}}}}"#).unwrap();
+        let mut data: HoliumCbor = Vec::new();
+
+        let res = data.copy_cbor(&vec![(
+            "connection".to_string(),
+            data_at_tail,
+            tail_selector,
+            head_selector,
+        )]);
+
+        assert!(res.is_err());
+        assert!(res.err().unwrap().to_string().contains(
+            "tail selector produces 2 data set(s) while head selector expects 1 in connection: connection"
+        ));
+    }
+}
@@ -13,11 +13,14 @@ pub fn build_cli() -> App<'static, 'static> {
         .subcommands(vec![
             commands::completion_script::cmd(),
             commands::init::cmd(),
+            commands::config::cmd(),
             commands::source::cmd(),
             commands::shaper::cmd(),
             commands::transformation::cmd(),
             commands::connection::cmd(),
+            commands::data::cmd(),
             commands::portation::cmd(),
             commands::project::cmd(),
+            commands::selector::cmd(),
         ])
 }
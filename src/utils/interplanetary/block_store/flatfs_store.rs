@@ -0,0 +1,117 @@
+use crate::utils::interplanetary::block_store::BlockStore;
+use crate::utils::interplanetary::multiformats::{cid_to_object_path, object_path_to_cid};
+
+use anyhow::{Context, Result};
+use cid::Cid;
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to open the interplanetary area")]
+    FailedToOpen,
+    #[error("failed to write block in the interplanetary area: {0}")]
+    FailedToWriteBlock(String),
+    #[error("failed to read block from the interplanetary area: {0}")]
+    FailedToReadBlock(String),
+    #[error("failed to delete block from the interplanetary area: {0}")]
+    FailedToDeleteBlock(String),
+    #[error("failed to list blocks of the interplanetary area")]
+    FailedToListBlocks,
+}
+
+/// Block store keeping one file per block, sharded in directories.
+/// We follow specifications from the `ipfs/go-ds-flatfs` repository.
+/// Reference: https://github.com/ipfs/go-ds-flatfs/blob/master/readme.go
+pub struct FlatFsStore {
+    path: PathBuf,
+}
+
+impl FlatFsStore {
+    /// Open, or create, a flatfs block store in the given directory.
+    pub fn open(path: PathBuf) -> Result<Self> {
+        if !path.exists() {
+            fs::create_dir_all(&path).context(Error::FailedToOpen)?
+        }
+        Ok(FlatFsStore { path })
+    }
+
+    /// Deterministically convert an object CID to the path of its file.
+    fn block_path(&self, cid: &Cid) -> Result<PathBuf> {
+        Ok(self.path.join(cid_to_object_path(cid)?))
+    }
+}
+
+impl BlockStore for FlatFsStore {
+    fn get(&self, cid: &Cid) -> Result<Vec<u8>> {
+        fs::read(self.block_path(cid)?).context(Error::FailedToReadBlock(cid.to_string()))
+    }
+
+    fn put(&self, cid: &Cid, content: &[u8]) -> Result<()> {
+        let path = self.block_path(cid)?;
+        // write file if it does not already exist
+        if !path.exists() {
+            // create parent directory if necessary
+            let parent_path = &path
+                .parent()
+                .ok_or(Error::FailedToWriteBlock(cid.to_string()))?;
+            if !parent_path.exists() {
+                fs::create_dir(&parent_path).context(Error::FailedToWriteBlock(cid.to_string()))?;
+            }
+            // write file
+            let mut file = fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+                .context(Error::FailedToWriteBlock(cid.to_string()))?;
+            file.write_all(content)
+                .context(Error::FailedToWriteBlock(cid.to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn has(&self, cid: &Cid) -> Result<bool> {
+        Ok(self.block_path(cid)?.is_file())
+    }
+
+    fn delete(&self, cid: &Cid) -> Result<()> {
+        let path = self.block_path(cid)?;
+        if path.exists() {
+            fs::remove_file(path).context(Error::FailedToDeleteBlock(cid.to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<Cid>> {
+        let mut cids = Vec::new();
+        for first_level_entry in fs::read_dir(&self.path).context(Error::FailedToListBlocks)? {
+            let first_level_path = first_level_entry.context(Error::FailedToListBlocks)?.path();
+            if first_level_path.is_dir() {
+                for second_level_entry in
+                    fs::read_dir(first_level_path).context(Error::FailedToListBlocks)?
+                {
+                    let second_level_path = second_level_entry
+                        .context(Error::FailedToListBlocks)?
+                        .path();
+                    // files that are not named after a cid are not blocks
+                    if second_level_path.is_file() {
+                        if let Ok(rel_path) = second_level_path.strip_prefix(&self.path) {
+                            if let Ok(cid) = object_path_to_cid(&rel_path.to_path_buf()) {
+                                cids.push(cid);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        cids.sort();
+        Ok(cids)
+    }
+
+    fn location(&self) -> String {
+        let path = fs::canonicalize(&self.path).unwrap_or_else(|_| self.path.clone());
+        format!("flatfs://{}", path.to_string_lossy())
+    }
+}
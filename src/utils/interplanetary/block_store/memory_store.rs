@@ -0,0 +1,70 @@
+use crate::utils::interplanetary::block_store::BlockStore;
+use crate::utils::interplanetary::multiformats::DEFAULT_MULTIBASE;
+
+use anyhow::Result;
+use cid::Cid;
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("block not found in memory: {0}")]
+    BlockNotFound(String),
+}
+
+/// Counter used to identify the memory stores created by the process.
+static NEXT_MEMORY_STORE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Block store keeping all blocks in memory. Mostly useful for tests and temporary areas.
+pub struct MemoryStore {
+    id: usize,
+    blocks: RefCell<BTreeMap<Cid, Vec<u8>>>,
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        MemoryStore {
+            id: NEXT_MEMORY_STORE_ID.fetch_add(1, Ordering::Relaxed),
+            blocks: RefCell::new(BTreeMap::new()),
+        }
+    }
+}
+
+impl BlockStore for MemoryStore {
+    fn get(&self, cid: &Cid) -> Result<Vec<u8>> {
+        self.blocks.borrow().get(cid).cloned().ok_or_else(|| {
+            Error::BlockNotFound(
+                cid.to_string_of_base(DEFAULT_MULTIBASE)
+                    .unwrap_or(cid.to_string()),
+            )
+            .into()
+        })
+    }
+
+    fn put(&self, cid: &Cid, content: &[u8]) -> Result<()> {
+        self.blocks
+            .borrow_mut()
+            .entry(*cid)
+            .or_insert_with(|| content.to_vec());
+        Ok(())
+    }
+
+    fn has(&self, cid: &Cid) -> Result<bool> {
+        Ok(self.blocks.borrow().contains_key(cid))
+    }
+
+    fn delete(&self, cid: &Cid) -> Result<()> {
+        self.blocks.borrow_mut().remove(cid);
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<Cid>> {
+        Ok(self.blocks.borrow().keys().cloned().collect())
+    }
+
+    fn location(&self) -> String {
+        format!("memory://{}", self.id)
+    }
+}
@@ -0,0 +1,149 @@
+//! Storage backends for the blocks of the interplanetary area.
+
+pub mod flatfs_store;
+pub mod memory_store;
+pub mod sled_store;
+
+use crate::utils::interplanetary::block_store::flatfs_store::FlatFsStore;
+use crate::utils::interplanetary::block_store::sled_store::SledStore;
+use crate::utils::repo::constants::{INTERPLANETARY_DB_DIR, INTERPLANETARY_DIR};
+
+use anyhow::Result;
+use cid::Cid;
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+use std::path::Path;
+use std::rc::Rc;
+use std::str::FromStr;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("unknown block store: {0}")]
+    UnknownBlockStore(String),
+}
+
+/// Trait to implement to store the blocks of an interplanetary area, indexed by their cid.
+/// Implementations are not expected to check the integrity of stored contents.
+pub trait BlockStore {
+    /// Get the content of a block.
+    fn get(&self, cid: &Cid) -> Result<Vec<u8>>;
+
+    /// Store the content of a block. Storing an already existing block is a no-op.
+    fn put(&self, cid: &Cid, content: &[u8]) -> Result<()>;
+
+    /// Check if a block is stored.
+    fn has(&self, cid: &Cid) -> Result<bool>;
+
+    /// Remove a block from the store.
+    fn delete(&self, cid: &Cid) -> Result<()>;
+
+    /// List the cids of all stored blocks, in ascending order.
+    fn list(&self) -> Result<Vec<Cid>>;
+
+    /// Identify where blocks are stored, as a URI. Stores sharing a location hold the same blocks.
+    fn location(&self) -> String;
+}
+
+/// Block stores that can be selected in the project settings.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BlockStoreKind {
+    /// One file per block, sharded in directories, following the `go-ds-flatfs` layout.
+    #[serde(rename = "flatfs")]
+    FlatFs,
+    /// All blocks in a single sled database.
+    #[serde(rename = "sled")]
+    Sled,
+}
+
+impl BlockStoreKind {
+    /// Names of the available block stores, as used in CLI arguments and configuration files.
+    pub fn variants() -> [&'static str; 2] {
+        ["flatfs", "sled"]
+    }
+
+    /// Open the block store of a project, given the path of its holium directory.
+    pub fn open(&self, holium_root_path: &Path) -> Result<Rc<dyn BlockStore>> {
+        Ok(match self {
+            BlockStoreKind::FlatFs => Rc::new(FlatFsStore::open(
+                holium_root_path.join(INTERPLANETARY_DIR),
+            )?),
+            BlockStoreKind::Sled => Rc::new(SledStore::open(
+                holium_root_path.join(INTERPLANETARY_DB_DIR),
+            )?),
+        })
+    }
+}
+
+impl Default for BlockStoreKind {
+    fn default() -> Self {
+        BlockStoreKind::FlatFs
+    }
+}
+
+impl fmt::Display for BlockStoreKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockStoreKind::FlatFs => write!(f, "flatfs"),
+            BlockStoreKind::Sled => write!(f, "sled"),
+        }
+    }
+}
+
+impl FromStr for BlockStoreKind {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "flatfs" => Ok(BlockStoreKind::FlatFs),
+            "sled" => Ok(BlockStoreKind::Sled),
+            _ => Err(Error::UnknownBlockStore(s.to_string()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::interplanetary::block_store::memory_store::MemoryStore;
+    use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
+    use crate::utils::interplanetary::multiformats::{compute_cid, HashFunction};
+    use std::io::Cursor;
+
+    /// Run the same scenario on any block store implementation.
+    fn check_block_store(store: &dyn BlockStore) {
+        let content = b"some block content".to_vec();
+        let cid = compute_cid(
+            Cursor::new(content.clone()),
+            &BlockMulticodec::Raw,
+            &HashFunction::default(),
+        )
+        .unwrap();
+        assert!(!store.has(&cid).unwrap());
+        assert!(store.get(&cid).is_err());
+        store.put(&cid, &content).unwrap();
+        store.put(&cid, &content).unwrap();
+        assert!(store.has(&cid).unwrap());
+        assert_eq!(store.get(&cid).unwrap(), content);
+        assert_eq!(store.list().unwrap(), vec![cid]);
+        store.delete(&cid).unwrap();
+        assert!(!store.has(&cid).unwrap());
+        assert!(store.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn can_use_memory_store() {
+        check_block_store(&MemoryStore::default());
+    }
+
+    #[test]
+    fn can_use_flatfs_store() {
+        let dir = tempfile::tempdir().unwrap();
+        check_block_store(&FlatFsStore::open(dir.path().join("area")).unwrap());
+    }
+
+    #[test]
+    fn can_use_sled_store() {
+        let dir = tempfile::tempdir().unwrap();
+        check_block_store(&SledStore::open(dir.path().join("area")).unwrap());
+    }
+}
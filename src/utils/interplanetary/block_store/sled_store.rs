@@ -0,0 +1,93 @@
+use crate::utils::interplanetary::block_store::BlockStore;
+use crate::utils::interplanetary::multiformats::DEFAULT_MULTIBASE;
+
+use anyhow::{Context, Result};
+use cid::Cid;
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to open the interplanetary database")]
+    FailedToOpen,
+    #[error("failed to operate on the interplanetary database")]
+    DbOperationFailed,
+    #[error("block not found in the interplanetary database: {0}")]
+    BlockNotFound(String),
+    #[error("failed to parse key of the interplanetary database as a cid")]
+    InvalidKey,
+}
+
+/// Block store keeping all blocks in a single sled database, keyed by the binary form of their cid.
+pub struct SledStore {
+    path: PathBuf,
+    db: sled::Db,
+}
+
+impl SledStore {
+    /// Open, or create, a sled block store at the given path.
+    pub fn open(path: PathBuf) -> Result<Self> {
+        let db = sled::open(&path).context(Error::FailedToOpen)?;
+        Ok(SledStore { path, db })
+    }
+}
+
+impl BlockStore for SledStore {
+    fn get(&self, cid: &Cid) -> Result<Vec<u8>> {
+        let content = self
+            .db
+            .get(cid.to_bytes())
+            .context(Error::DbOperationFailed)?
+            .ok_or_else(|| {
+                Error::BlockNotFound(
+                    cid.to_string_of_base(DEFAULT_MULTIBASE)
+                        .unwrap_or(cid.to_string()),
+                )
+            })?;
+        Ok(content.to_vec())
+    }
+
+    fn put(&self, cid: &Cid, content: &[u8]) -> Result<()> {
+        self.db
+            .compare_and_swap(cid.to_bytes(), None as Option<&[u8]>, Some(content))
+            .context(Error::DbOperationFailed)?
+            .ok();
+        self.db.flush().context(Error::DbOperationFailed)?;
+        Ok(())
+    }
+
+    fn has(&self, cid: &Cid) -> Result<bool> {
+        self.db
+            .contains_key(cid.to_bytes())
+            .context(Error::DbOperationFailed)
+    }
+
+    fn delete(&self, cid: &Cid) -> Result<()> {
+        self.db
+            .remove(cid.to_bytes())
+            .context(Error::DbOperationFailed)?;
+        self.db.flush().context(Error::DbOperationFailed)?;
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<Cid>> {
+        let mut cids = self
+            .db
+            .iter()
+            .keys()
+            .map(|key| {
+                let key = key.context(Error::DbOperationFailed)?;
+                Cid::try_from(key.as_ref()).context(Error::InvalidKey)
+            })
+            .collect::<Result<Vec<Cid>>>()?;
+        cids.sort();
+        Ok(cids)
+    }
+
+    fn location(&self) -> String {
+        let path = fs::canonicalize(&self.path).unwrap_or_else(|_| self.path.clone());
+        format!("sled://{}", path.to_string_lossy())
+    }
+}
@@ -1,11 +1,16 @@
 use std::fs;
 
 use std::path::PathBuf;
+use std::rc::Rc;
 
 use anyhow::{Context, Result};
 
-use crate::utils::repo::constants::{HOLIUM_DIR, INTERPLANETARY_DIR};
+use crate::utils::interplanetary::block_store::memory_store::MemoryStore;
+use crate::utils::interplanetary::block_store::{BlockStore, BlockStoreKind};
+use crate::utils::interplanetary::multiformats::HashFunction;
+use crate::utils::repo::constants::{CONFIG_FILE, HOLIUM_DIR};
 use crate::utils::repo::helpers::get_root_path;
+use crate::utils::repo::models::config::ProjectConfig;
 
 #[derive(thiserror::Error, Debug)]
 enum Error {
@@ -15,8 +20,12 @@ enum Error {
 
 /// Context structure helping accessing the interplanetary area in a consistent way throughout the CLI
 /// commands.
+#[derive(Clone)]
 pub struct InterplanetaryContext {
-    pub ip_area_path: PathBuf,
+    /// Hash function used to compute the cid of newly written blocks.
+    pub hash_function: HashFunction,
+    /// Backend storing the blocks of the area.
+    pub block_store: Rc<dyn BlockStore>,
 }
 
 impl InterplanetaryContext {
@@ -27,19 +36,40 @@ impl InterplanetaryContext {
         Self::from_root_path(&root_path)
     }
 
-    /// Initialize an interplanetary context from a project root path.
-    fn from_root_path(root_path: &PathBuf) -> Result<Self> {
+    /// Initialize an [ InterplanetaryContext ] object backed by an in-memory block store.
+    pub fn new_in_memory() -> Self {
+        InterplanetaryContext {
+            hash_function: HashFunction::default(),
+            block_store: Rc::new(MemoryStore::default()),
+        }
+    }
+
+    /// Initialize an interplanetary context from a project root path, according to the project
+    /// settings.
+    pub fn from_root_path(root_path: &PathBuf) -> Result<Self> {
+        let config = ProjectConfig::from_path(root_path.join(HOLIUM_DIR).join(CONFIG_FILE))?;
+        Self::from_settings(root_path, config.hash_function, &config.block_store)
+    }
+
+    /// Initialize an interplanetary context from a project root path, with the given settings.
+    pub fn from_settings(
+        root_path: &PathBuf,
+        hash_function: HashFunction,
+        block_store_kind: &BlockStoreKind,
+    ) -> Result<Self> {
         // create the holium root directory if it does not exist
         let holium_root_path = root_path.join(HOLIUM_DIR);
         if !holium_root_path.exists() {
             fs::create_dir(&holium_root_path).context(Error::FailedToInit)?
         }
-        // create the interplanetary area directory if it does not exist
-        let ip_area_path = holium_root_path.join(INTERPLANETARY_DIR);
-        if !ip_area_path.exists() {
-            fs::create_dir(&ip_area_path).context(Error::FailedToInit)?
-        }
-        // configure local context
-        Ok(InterplanetaryContext { ip_area_path })
+        // open the block store
+        let block_store = block_store_kind
+            .open(&holium_root_path)
+            .context(Error::FailedToInit)?;
+        // configure interplanetary context
+        Ok(InterplanetaryContext {
+            hash_function,
+            block_store,
+        })
     }
 }
@@ -0,0 +1,286 @@
+//! Compute the differences between two pipelines stored in interplanetary areas.
+
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::fs::traits::as_ip_block::AsInterplanetaryBlock;
+use crate::utils::interplanetary::kinds::connection::Connection;
+use crate::utils::interplanetary::kinds::dry_transformation::DryTransformation;
+use crate::utils::interplanetary::kinds::metadata::Metadata;
+use crate::utils::interplanetary::kinds::module_bytecode_envelope::ModuleBytecodeEnvelope;
+use crate::utils::interplanetary::kinds::pipeline::Pipeline;
+use crate::utils::interplanetary::kinds::selector::SelectorEnvelope;
+use crate::utils::interplanetary::multiformats::DEFAULT_MULTIBASE;
+use crate::utils::local::context::helpers::build_connection_id;
+
+use anyhow::{Context, Result};
+use cid::Cid;
+use serde::Serialize;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to read pipeline: {0}")]
+    FailedToReadPipeline(String),
+    #[error("no Metadata block linked from the pipeline node")]
+    NoMetadataFoundInTheNode,
+    #[error("node name missing from the Metadata block")]
+    NoNameInMetadata,
+    #[error("pipeline edge refers to an unknown vertex index: {0}")]
+    UnknownVertexIndex(u64),
+    #[error("failed to parse selector")]
+    FailedToParseSelector,
+}
+
+/// Change of a single field of a pipeline element, between two versions of a pipeline.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Changed pipeline element (node or connection), along with the list of its changed fields.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ElementChange {
+    pub id: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Differences between two versions of a pipeline. Nodes are identified by their typed name and
+/// connections by their id.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct PipelineDiff {
+    pub added_nodes: Vec<String>,
+    pub removed_nodes: Vec<String>,
+    pub changed_nodes: Vec<ElementChange>,
+    pub added_connections: Vec<String>,
+    pub removed_connections: Vec<String>,
+    pub changed_connections: Vec<ElementChange>,
+}
+
+impl PipelineDiff {
+    /// Check if both versions of the pipeline are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.changed_nodes.is_empty()
+            && self.added_connections.is_empty()
+            && self.removed_connections.is_empty()
+            && self.changed_connections.is_empty()
+    }
+}
+
+/// Comparable fields of a pipeline element, indexed by field name.
+type ElementFields = BTreeMap<String, Option<String>>;
+
+/// Pipeline elements, indexed by their identifier.
+type Elements = BTreeMap<String, ElementFields>;
+
+/// Flattened and comparable representation of a pipeline.
+struct PipelineSnapshot {
+    nodes: Elements,
+    connections: Elements,
+}
+
+/// Compute the differences between an old and a new version of a pipeline, each of them being
+/// read from its own interplanetary area.
+pub fn diff_pipelines(
+    old_cid: &Cid,
+    old_ip_context: &InterplanetaryContext,
+    new_cid: &Cid,
+    new_ip_context: &InterplanetaryContext,
+) -> Result<PipelineDiff> {
+    let old = snapshot_pipeline(old_cid, old_ip_context)?;
+    let new = snapshot_pipeline(new_cid, new_ip_context)?;
+    let (added_nodes, removed_nodes, changed_nodes) = diff_elements(&old.nodes, &new.nodes);
+    let (added_connections, removed_connections, changed_connections) =
+        diff_elements(&old.connections, &new.connections);
+    Ok(PipelineDiff {
+        added_nodes,
+        removed_nodes,
+        changed_nodes,
+        added_connections,
+        removed_connections,
+        changed_connections,
+    })
+}
+
+/// Walk a pipeline DAG and gather comparable fields of its nodes and connections.
+fn snapshot_pipeline(cid: &Cid, ip_context: &InterplanetaryContext) -> Result<PipelineSnapshot> {
+    let cid_str = cid_to_string(cid);
+    let pipeline_block = sk_cbor::Value::read_from_ip_area(cid, ip_context)
+        .context(Error::FailedToReadPipeline(cid_str.clone()))?;
+    let pipeline =
+        Pipeline::try_from(*pipeline_block).context(Error::FailedToReadPipeline(cid_str))?;
+    // gather vertices fields
+    let mut typed_names = Vec::new();
+    let mut nodes = Elements::new();
+    for v in pipeline.vertices.iter() {
+        let mut fields = ElementFields::new();
+        // fetch metadata
+        let metadata_cid = v.metadata.ok_or(Error::NoMetadataFoundInTheNode)?;
+        let metadata_block = sk_cbor::Value::read_from_ip_area(&metadata_cid, ip_context)?;
+        let metadata = Metadata::try_from(*metadata_block)?;
+        let typed_name = metadata.name.ok_or(Error::NoNameInMetadata)?;
+        fields.insert("json_schema".to_string(), metadata.json_schema);
+        fields.insert("json_schema_in".to_string(), metadata.json_schema_in);
+        fields.insert("json_schema_out".to_string(), metadata.json_schema_out);
+        // fetch dry transformation, if any
+        let (handle, bytecode) = match &v.dry_transformation {
+            Some(dry_transformation_cid) => {
+                let dry_transformation_block =
+                    sk_cbor::Value::read_from_ip_area(dry_transformation_cid, ip_context)?;
+                let dry_transformation = DryTransformation::try_from(*dry_transformation_block)?;
+                let module_bytecode_envelope_block = sk_cbor::Value::read_from_ip_area(
+                    &dry_transformation.module_bytecode_envelope_cid,
+                    ip_context,
+                )?;
+                let module_bytecode_envelope =
+                    ModuleBytecodeEnvelope::try_from(*module_bytecode_envelope_block)?;
+                (
+                    Some(dry_transformation.handle),
+                    Some(cid_to_string(&module_bytecode_envelope.module_bytecode_cid)),
+                )
+            }
+            None => (None, None),
+        };
+        fields.insert("handle".to_string(), handle);
+        fields.insert("bytecode".to_string(), bytecode);
+        // data is compared through the cid of its root block
+        fields.insert("data".to_string(), v.data.as_ref().map(cid_to_string));
+        typed_names.push(typed_name.clone());
+        nodes.insert(typed_name, fields);
+    }
+    // gather edges fields
+    let mut connections = Elements::new();
+    for e in pipeline.edges.iter() {
+        let typed_name = |idx: u64| -> Result<&String> {
+            typed_names
+                .get(idx as usize)
+                .ok_or_else(|| Error::UnknownVertexIndex(idx).into())
+        };
+        let id = build_connection_id(typed_name(e.tail_index)?, typed_name(e.head_index)?);
+        let connection_block = sk_cbor::Value::read_from_ip_area(&e.connection_cid, ip_context)?;
+        let connection = Connection::try_from(*connection_block)?;
+        let mut fields = ElementFields::new();
+        fields.insert(
+            "tail_selector".to_string(),
+            Some(read_selector(&connection.tail_selector, ip_context)?),
+        );
+        fields.insert(
+            "head_selector".to_string(),
+            Some(read_selector(&connection.head_selector, ip_context)?),
+        );
+        connections.insert(id, fields);
+    }
+    Ok(PipelineSnapshot { nodes, connections })
+}
+
+/// Read a Selector from its CID in the interplanetary area and serialize it to a JSON string.
+fn read_selector(selector_cid: &Cid, ip_context: &InterplanetaryContext) -> Result<String> {
+    let selector_block = sk_cbor::Value::read_from_ip_area(selector_cid, ip_context)?;
+    let selector = SelectorEnvelope::try_from(*selector_block)?.0;
+    serde_json::to_string(&serde_json::Value::from(selector)).context(Error::FailedToParseSelector)
+}
+
+fn cid_to_string(cid: &Cid) -> String {
+    cid.to_string_of_base(DEFAULT_MULTIBASE)
+        .unwrap_or(cid.to_string())
+}
+
+/// Compare two sets of elements. Returns the identifiers of added and removed elements, as well
+/// as the detailed changes of elements present in both sets.
+fn diff_elements(old: &Elements, new: &Elements) -> (Vec<String>, Vec<String>, Vec<ElementChange>) {
+    let added = new
+        .keys()
+        .filter(|id| !old.contains_key(*id))
+        .cloned()
+        .collect();
+    let removed = old
+        .keys()
+        .filter(|id| !new.contains_key(*id))
+        .cloned()
+        .collect();
+    let changed = old
+        .iter()
+        .filter_map(|(id, old_fields)| {
+            let new_fields = new.get(id)?;
+            let changes = diff_fields(old_fields, new_fields);
+            if changes.is_empty() {
+                return None;
+            }
+            Some(ElementChange {
+                id: id.clone(),
+                changes,
+            })
+        })
+        .collect();
+    (added, removed, changed)
+}
+
+/// Compare fields of the old and new versions of a single element.
+fn diff_fields(old: &ElementFields, new: &ElementFields) -> Vec<FieldChange> {
+    let field_names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    field_names
+        .into_iter()
+        .filter_map(|field| {
+            let old_value = old.get(field).cloned().flatten();
+            let new_value = new.get(field).cloned().flatten();
+            if old_value == new_value {
+                return None;
+            }
+            Some(FieldChange {
+                field: field.clone(),
+                old: old_value,
+                new: new_value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(fields: &[(&str, Option<&str>)]) -> ElementFields {
+        fields
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.map(|v| v.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn can_diff_pipeline_elements() {
+        let mut old = Elements::new();
+        old.insert("source:a".to_string(), element(&[("data", Some("x"))]));
+        old.insert("source:b".to_string(), element(&[("data", Some("y"))]));
+        old.insert(
+            "transformation:c".to_string(),
+            element(&[("handle", Some("main")), ("data", None)]),
+        );
+        let mut new = Elements::new();
+        new.insert("source:b".to_string(), element(&[("data", Some("y"))]));
+        new.insert(
+            "transformation:c".to_string(),
+            element(&[("handle", Some("run")), ("data", None)]),
+        );
+        new.insert("shaper:d".to_string(), element(&[]));
+
+        let (added, removed, changed) = diff_elements(&old, &new);
+
+        assert_eq!(added, vec!["shaper:d".to_string()]);
+        assert_eq!(removed, vec!["source:a".to_string()]);
+        assert_eq!(
+            changed,
+            vec![ElementChange {
+                id: "transformation:c".to_string(),
+                changes: vec![FieldChange {
+                    field: "handle".to_string(),
+                    old: Some("main".to_string()),
+                    new: Some("run".to_string()),
+                }],
+            }]
+        );
+    }
+}
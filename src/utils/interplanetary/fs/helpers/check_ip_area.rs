@@ -0,0 +1,175 @@
+//! Helpers checking the integrity of the blocks stored in an interplanetary area.
+
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
+use crate::utils::interplanetary::kinds::link::{Link, IPLD_CONTENT_CBOR_TAG_ID};
+use crate::utils::interplanetary::multiformats::{verify_cid, DEFAULT_MULTIBASE};
+
+use anyhow::{Context, Result};
+use cid::Cid;
+use sk_cbor::Value;
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to read block of the interplanetary area: {0}")]
+    FailedToReadBlock(String),
+    #[error("failed to move block to quarantine: {0}")]
+    FailedToQuarantineBlock(String),
+}
+
+/// Enumeration of the issues that can be detected on a block of the interplanetary area.
+#[derive(Debug)]
+pub enum BlockIssue {
+    /// The content of the block does not match the multihash of its cid.
+    CorruptedContent,
+    /// The block is advertised as DAG-CBOR but cannot be parsed as such.
+    UnparsableContent,
+    /// The block links to another block that cannot be found in the area.
+    MissingLink(Cid),
+}
+
+/// Report of an issue found on a block of the interplanetary area.
+#[derive(Debug)]
+pub struct BlockReport {
+    pub cid: Cid,
+    pub issue: BlockIssue,
+}
+
+impl BlockReport {
+    /// Bad blocks are the ones whose content cannot be trusted, and that should be quarantined.
+    pub fn is_bad_block(&self) -> bool {
+        match self.issue {
+            BlockIssue::MissingLink(_) => false,
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for BlockReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let issue = match &self.issue {
+            BlockIssue::CorruptedContent => "content does not match the cid".to_string(),
+            BlockIssue::UnparsableContent => "content cannot be parsed as DAG-CBOR".to_string(),
+            BlockIssue::MissingLink(cid) => {
+                format!("missing linked block: {}", cid_to_string(cid))
+            }
+        };
+        write!(f, "{} → {}", cid_to_string(&self.cid), issue)
+    }
+}
+
+fn cid_to_string(cid: &Cid) -> String {
+    cid.to_string_of_base(DEFAULT_MULTIBASE)
+        .unwrap_or(cid.to_string())
+}
+
+/// Check all blocks of an interplanetary area, re-hashing their content and checking that blocks
+/// they link to exist. Returns the list of detected issues, and the number of checked blocks.
+pub fn check_ip_area(ip_context: &InterplanetaryContext) -> Result<(Vec<BlockReport>, usize)> {
+    let cids = ip_context.block_store.list()?;
+    let mut reports = Vec::new();
+    for cid in cids.iter() {
+        reports.append(&mut check_block(cid, ip_context)?);
+    }
+    Ok((reports, cids.len()))
+}
+
+/// Check a single block of an interplanetary area.
+fn check_block(cid: &Cid, ip_context: &InterplanetaryContext) -> Result<Vec<BlockReport>> {
+    let report = |issue: BlockIssue| BlockReport { cid: *cid, issue };
+    // re-hash the content of the block
+    let content = ip_context
+        .block_store
+        .get(cid)
+        .context(Error::FailedToReadBlock(cid_to_string(cid)))?;
+    if verify_cid(Cursor::new(&content), &cid).is_err() {
+        return Ok(vec![report(BlockIssue::CorruptedContent)]);
+    }
+    // only DAG-CBOR blocks may hold links
+    if cid.codec() != u64::from(&BlockMulticodec::DagCbor) {
+        return Ok(vec![]);
+    }
+    let value = match sk_cbor::read(&content) {
+        Ok(value) => value,
+        Err(_) => return Ok(vec![report(BlockIssue::UnparsableContent)]),
+    };
+    // check that all linked blocks exist in the area
+    let mut links = Vec::new();
+    collect_links(&value, &mut links);
+    let mut reports = Vec::new();
+    for link in links {
+        if !ip_context.block_store.has(&link)? {
+            reports.push(report(BlockIssue::MissingLink(link)));
+        }
+    }
+    Ok(reports)
+}
+
+/// Recursively collect the cids of all links held by a DAG-CBOR value.
+fn collect_links(value: &Value, links: &mut Vec<Cid>) {
+    match value {
+        Value::Tag(tag, _) if *tag == IPLD_CONTENT_CBOR_TAG_ID => {
+            if let Ok(Link(cid)) = Link::try_from(value.clone()) {
+                links.push(cid);
+            }
+        }
+        Value::Tag(_, boxed_value) => collect_links(boxed_value, links),
+        Value::Array(values) => values.iter().for_each(|v| collect_links(v, links)),
+        Value::Map(tuples) => tuples.iter().for_each(|(k, v)| {
+            collect_links(k, links);
+            collect_links(v, links);
+        }),
+        _ => {}
+    }
+}
+
+/// Move a block out of the interplanetary area, into a quarantine directory.
+/// Returns the path of the quarantined block.
+pub fn quarantine_block(
+    cid: &Cid,
+    ip_context: &InterplanetaryContext,
+    quarantine_dir_path: &Path,
+) -> Result<PathBuf> {
+    let error = || Error::FailedToQuarantineBlock(cid_to_string(cid));
+    let content = ip_context.block_store.get(cid).context(error())?;
+    fs::create_dir_all(quarantine_dir_path).context(error())?;
+    let quarantine_path = quarantine_dir_path.join(cid_to_string(cid));
+    fs::write(&quarantine_path, content).context(error())?;
+    ip_context.block_store.delete(cid).context(error())?;
+    Ok(quarantine_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::interplanetary::kinds::connection::Connection;
+    use crate::utils::interplanetary::multiformats::{compute_cid, HashFunction};
+
+    #[test]
+    fn can_collect_links_from_dag_cbor_value() {
+        let tail_cid = compute_cid(
+            Cursor::new(b"tail".to_vec()),
+            &BlockMulticodec::DagCbor,
+            &HashFunction::default(),
+        )
+        .unwrap();
+        let head_cid = compute_cid(
+            Cursor::new(b"head".to_vec()),
+            &BlockMulticodec::DagCbor,
+            &HashFunction::default(),
+        )
+        .unwrap();
+        let value = Value::from(Connection::new(tail_cid, head_cid));
+
+        let mut links = Vec::new();
+        collect_links(&value, &mut links);
+
+        assert_eq!(links, vec![tail_cid, head_cid]);
+    }
+}
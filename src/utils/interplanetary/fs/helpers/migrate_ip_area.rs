@@ -0,0 +1,196 @@
+//! Helpers rewriting the blocks of an interplanetary area with other settings.
+
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
+use crate::utils::interplanetary::fs::traits::as_ip_block::AsInterplanetaryBlock;
+use crate::utils::interplanetary::kinds::data_chunk::DataChunk;
+use crate::utils::interplanetary::kinds::link::{Link, IPLD_CONTENT_CBOR_TAG_ID};
+
+use anyhow::{Context, Result};
+use cid::Cid;
+use sk_cbor::Value;
+
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to migrate block: {0}")]
+    FailedToMigrateBlock(String),
+    #[error("failed to remove migrated block: {0}")]
+    FailedToRemoveBlock(String),
+}
+
+/// Rewrite all blocks of an interplanetary area into a target area, with the hash function and
+/// block store of the target context, updating links between blocks accordingly. Blocks of the
+/// source area are removed once the whole area has been migrated, unless they are also part of
+/// the target area. Returns a mapping from old cids to new ones.
+pub fn migrate_ip_area(
+    source: &InterplanetaryContext,
+    target: &InterplanetaryContext,
+) -> Result<HashMap<Cid, Cid>> {
+    let mut migrator = Migrator {
+        source,
+        target,
+        migrated: HashMap::new(),
+    };
+    // migrate all blocks, linked blocks being migrated first
+    let cids = source.block_store.list()?;
+    for cid in cids.iter() {
+        migrator.migrate_block(cid)?;
+    }
+    // remove outdated blocks
+    let same_store = source.block_store.location() == target.block_store.location();
+    let new_cids: HashSet<&Cid> = migrator.migrated.values().collect();
+    for cid in cids.iter() {
+        if !same_store || !new_cids.contains(cid) {
+            source
+                .block_store
+                .delete(cid)
+                .context(Error::FailedToRemoveBlock(cid.to_string()))?;
+        }
+    }
+    Ok(migrator.migrated)
+}
+
+/// Structure holding the state of an interplanetary area migration.
+struct Migrator<'a> {
+    source: &'a InterplanetaryContext,
+    target: &'a InterplanetaryContext,
+    migrated: HashMap<Cid, Cid>,
+}
+
+impl<'a> Migrator<'a> {
+    /// Migrate a single block, after having migrated the blocks it links to. Returns its new cid.
+    fn migrate_block(&mut self, cid: &Cid) -> Result<Cid> {
+        if let Some(new_cid) = self.migrated.get(cid) {
+            return Ok(*new_cid);
+        }
+        let new_cid = if cid.codec() == u64::from(&BlockMulticodec::DagCbor) {
+            let value = Value::read_from_ip_area(cid, self.source)
+                .context(Error::FailedToMigrateBlock(cid.to_string()))?;
+            self.replace_links(*value)?
+                .write_to_ip_area(self.target)
+                .context(Error::FailedToMigrateBlock(cid.to_string()))?
+        } else {
+            DataChunk::read_from_ip_area(cid, self.source)
+                .context(Error::FailedToMigrateBlock(cid.to_string()))?
+                .write_to_ip_area(self.target)
+                .context(Error::FailedToMigrateBlock(cid.to_string()))?
+        };
+        self.migrated.insert(*cid, new_cid);
+        Ok(new_cid)
+    }
+
+    /// Recursively replace the links held by a DAG-CBOR value with links to migrated blocks.
+    fn replace_links(&mut self, value: Value) -> Result<Value> {
+        match value {
+            Value::Tag(tag, _) if tag == IPLD_CONTENT_CBOR_TAG_ID => {
+                let Link(cid) = Link::try_from(value)?;
+                Ok(Link(self.migrate_block(&cid)?).into())
+            }
+            Value::Tag(tag, boxed_value) => {
+                Ok(Value::Tag(tag, Box::new(self.replace_links(*boxed_value)?)))
+            }
+            Value::Array(values) => Ok(Value::Array(
+                values
+                    .into_iter()
+                    .map(|v| self.replace_links(v))
+                    .collect::<Result<Vec<Value>>>()?,
+            )),
+            Value::Map(tuples) => Ok(Value::Map(
+                tuples
+                    .into_iter()
+                    .map(|(k, v)| Ok((self.replace_links(k)?, self.replace_links(v)?)))
+                    .collect::<Result<Vec<(Value, Value)>>>()?,
+            )),
+            _ => Ok(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::interplanetary::block_store::flatfs_store::FlatFsStore;
+    use crate::utils::interplanetary::kinds::connection::Connection;
+    use crate::utils::interplanetary::multiformats::HashFunction;
+    use std::rc::Rc;
+
+    #[test]
+    fn can_migrate_linked_blocks() {
+        let ip_context = InterplanetaryContext::new_in_memory();
+        let chunk_cid = DataChunk {
+            content: b"some content".to_vec(),
+        }
+        .write_to_ip_area(&ip_context)
+        .unwrap();
+        let connection_cid = Value::from(Connection::new(chunk_cid, chunk_cid))
+            .write_to_ip_area(&ip_context)
+            .unwrap();
+        let target_ip_context = InterplanetaryContext {
+            hash_function: HashFunction::Sha2_256,
+            ..ip_context.clone()
+        };
+
+        let migrated = migrate_ip_area(&ip_context, &target_ip_context).unwrap();
+
+        // all blocks have been migrated and old ones removed
+        assert_eq!(migrated.len(), 2);
+        assert_eq!(ip_context.block_store.list().unwrap().len(), 2);
+        // links point to migrated blocks
+        let new_chunk_cid = migrated.get(&chunk_cid).unwrap();
+        let new_connection_cid = migrated.get(&connection_cid).unwrap();
+        assert_eq!(new_chunk_cid.hash().code(), HashFunction::Sha2_256.code());
+        let connection_block = Value::read_from_ip_area(new_connection_cid, &ip_context).unwrap();
+        let connection = Connection::try_from(*connection_block).unwrap();
+        assert_eq!(connection.tail_selector, *new_chunk_cid);
+    }
+
+    #[test]
+    fn can_migrate_to_another_block_store() {
+        let ip_context = InterplanetaryContext::new_in_memory();
+        let chunk_cid = DataChunk {
+            content: b"some content".to_vec(),
+        }
+        .write_to_ip_area(&ip_context)
+        .unwrap();
+        let target_ip_context = InterplanetaryContext::new_in_memory();
+
+        let migrated = migrate_ip_area(&ip_context, &target_ip_context).unwrap();
+
+        // cids are kept, and blocks moved
+        assert_eq!(migrated.get(&chunk_cid), Some(&chunk_cid));
+        assert!(ip_context.block_store.list().unwrap().is_empty());
+        assert_eq!(
+            target_ip_context.block_store.list().unwrap(),
+            vec![chunk_cid]
+        );
+    }
+
+    #[test]
+    fn can_migrate_to_the_same_location() {
+        let dir = tempfile::tempdir().unwrap();
+        let open_ip_context = || InterplanetaryContext {
+            hash_function: HashFunction::default(),
+            block_store: Rc::new(FlatFsStore::open(dir.path().to_path_buf()).unwrap()),
+        };
+        let ip_context = open_ip_context();
+        let chunk_cid = DataChunk {
+            content: b"some content".to_vec(),
+        }
+        .write_to_ip_area(&ip_context)
+        .unwrap();
+        // the target area is opened separately, on the same directory
+        let target_ip_context = open_ip_context();
+
+        let migrated = migrate_ip_area(&ip_context, &target_ip_context).unwrap();
+
+        // cids are kept, and blocks are not removed
+        assert_eq!(migrated.get(&chunk_cid), Some(&chunk_cid));
+        assert_eq!(
+            target_ip_context.block_store.list().unwrap(),
+            vec![chunk_cid]
+        );
+    }
+}
@@ -1 +1,3 @@
+pub mod check_ip_area;
 pub mod clear_ip_area;
+pub mod migrate_ip_area;
@@ -1,14 +1,12 @@
 use crate::utils::interplanetary::context::InterplanetaryContext;
 use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
-use crate::utils::interplanetary::multiformats::{cid_to_path, compute_cid};
+use crate::utils::interplanetary::multiformats::{compute_cid, verify_cid};
 
 use anyhow::Context;
 
 use anyhow::Result;
 use cid::Cid;
 
-use std::fs;
-use std::fs::File;
 use std::io;
 use std::io::{Cursor, Read, Seek, Write};
 
@@ -18,6 +16,8 @@ enum Error {
     FailedToWriteBlock(String),
     #[error("failed to create interplanetary block structure from content")]
     FailedCreationFromContent,
+    #[error("failed to read block from the interplanetary area: {0}")]
+    FailedToReadBlock(String),
 }
 
 /// Trait helping with filesystem operations on InterPlanetary blocks
@@ -38,14 +38,16 @@ pub trait AsInterplanetaryBlock<ContentType: Read + Write + Seek + Default> {
     fn from_content(content: &ContentType) -> Result<Box<Self>>;
 
     /// Read and parse a block in the interplanetary area.
+    /// The content of the block is checked against its cid before being parsed.
     fn read_from_ip_area(cid: &Cid, ip_context: &InterplanetaryContext) -> Result<Box<Self>> {
-        // compute block path related to the cid
-        let path = cid_to_path(&cid, &ip_context)?;
         // read the whole block
-        let mut file = File::open(path)?;
-        let mut buffer = Vec::new();
-        file.read_to_end(&mut buffer)?;
+        let buffer = ip_context
+            .block_store
+            .get(cid)
+            .context(Error::FailedToReadBlock(cid.to_string()))?;
         let mut buf_cursor = Cursor::new(buffer);
+        // verify the integrity of the block
+        verify_cid(&mut buf_cursor, cid)?;
         let mut content: ContentType = ContentType::default();
         io::copy(&mut buf_cursor, &mut content)?;
         // returned boxed object
@@ -56,24 +58,18 @@ pub trait AsInterplanetaryBlock<ContentType: Read + Write + Seek + Default> {
     fn write_to_ip_area(&self, ip_context: &InterplanetaryContext) -> Result<Cid> {
         let mut content: ContentType = self.get_content();
         // compute cid from reader
-        let cid = compute_cid(&mut content, &Self::codec())?;
-        // compute related block path
-        let path = cid_to_path(&cid, &ip_context)?;
-        // write file if it does not already exist
-        if !path.exists() {
-            // create parent directory if necessary
-            let parent_path = &path.parent().ok_or(Error::FailedToWriteBlock(cid.into()))?;
-            if !parent_path.exists() {
-                fs::create_dir(&parent_path).context(Error::FailedToWriteBlock(cid.into()))?;
-            }
-            // write file
-            let mut file = fs::OpenOptions::new()
-                .write(true)
-                .create_new(true)
-                .open(&path)
+        let cid = compute_cid(&mut content, &Self::codec(), &ip_context.hash_function)?;
+        // store block if it does not already exist
+        if !ip_context.block_store.has(&cid)? {
+            let mut buffer = Vec::new();
+            content
+                .read_to_end(&mut buffer)
                 .context(Error::FailedToWriteBlock(cid.into()))?;
-            io::copy(&mut content, &mut file).context(Error::FailedToWriteBlock(cid.into()))?;
             Seek::rewind(&mut content).context(Error::FailedToWriteBlock(cid.into()))?;
+            ip_context
+                .block_store
+                .put(&cid, &buffer)
+                .context(Error::FailedToWriteBlock(cid.into()))?;
         }
         // return CID
         Ok(cid)
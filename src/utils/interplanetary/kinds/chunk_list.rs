@@ -0,0 +1,56 @@
+use crate::utils::interplanetary::kinds::link::Link;
+use anyhow::Error as AnyhowError;
+use anyhow::Result;
+use cid::Cid;
+use sk_cbor::cbor_array_vec;
+use sk_cbor::cbor_map;
+use sk_cbor::Value;
+
+use std::convert::TryFrom;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to manipulate chunk list kind")]
+    FailedToManipulate,
+}
+
+static DISCRIMINANT_KEY_V0: &str = "cl_0";
+
+/// [ ChunkList ] links, in order, the [ DataChunk ] blocks a large content has been split into.
+pub struct ChunkList {
+    pub chunks_cids: Vec<Cid>,
+}
+
+impl From<ChunkList> for sk_cbor::Value {
+    fn from(object: ChunkList) -> Self {
+        let links: Vec<sk_cbor::Value> = object
+            .chunks_cids
+            .into_iter()
+            .map(|cid| -> sk_cbor::Value { Link(cid).into() })
+            .collect();
+        cbor_map! {
+            "typedVersion" => DISCRIMINANT_KEY_V0,
+            "content" => cbor_array_vec!(links),
+        }
+    }
+}
+
+impl TryFrom<sk_cbor::Value> for ChunkList {
+    type Error = AnyhowError;
+    fn try_from(value: Value) -> Result<Self> {
+        if let Value::Map(tuples) = value {
+            let (_, discriminant_key) = tuples.get(1).ok_or(Error::FailedToManipulate)?;
+            if *discriminant_key == Value::TextString(DISCRIMINANT_KEY_V0.to_string()) {
+                let (_, links_value) = tuples.get(0).ok_or(Error::FailedToManipulate)?;
+                if let Value::Array(links) = links_value {
+                    let chunks_cids = links
+                        .iter()
+                        .map(|value| Link::try_from(value.clone()).map(|link| link.0))
+                        .collect::<Result<Vec<Cid>>>()?;
+                    return Ok(ChunkList { chunks_cids });
+                }
+            }
+        }
+        Err(Error::FailedToManipulate.into())
+    }
+}
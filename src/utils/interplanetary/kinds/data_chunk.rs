@@ -0,0 +1,27 @@
+use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
+use crate::utils::interplanetary::fs::traits::as_ip_block::AsInterplanetaryBlock;
+
+use anyhow::Result;
+
+use std::io::Cursor;
+
+/// [ DataChunk ] holds a slice of a content that has been split into several blocks.
+pub struct DataChunk {
+    pub content: Vec<u8>,
+}
+
+impl AsInterplanetaryBlock<Cursor<Vec<u8>>> for DataChunk {
+    fn codec() -> BlockMulticodec {
+        BlockMulticodec::Raw
+    }
+
+    fn get_content(&self) -> Cursor<Vec<u8>> {
+        Cursor::new(self.content.clone())
+    }
+
+    fn from_content(content: &Cursor<Vec<u8>>) -> Result<Box<Self>> {
+        Ok(Box::new(DataChunk {
+            content: content.get_ref().clone(),
+        }))
+    }
+}
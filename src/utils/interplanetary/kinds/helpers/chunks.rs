@@ -0,0 +1,99 @@
+//! Helpers splitting large contents into content-defined chunks, so that no block of the
+//! interplanetary area exceeds usual block size limits and identical parts of successive versions
+//! of a content are deduplicated.
+
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::fs::traits::as_ip_block::AsInterplanetaryBlock;
+use crate::utils::interplanetary::kinds::chunk_list::ChunkList;
+use crate::utils::interplanetary::kinds::data_chunk::DataChunk;
+
+use anyhow::Result;
+use cid::Cid;
+use fastcdc::v2020::FastCDC;
+use sk_cbor::Value;
+
+/// Size, in bytes, above which a content is split into chunks before being written to the
+/// interplanetary area.
+pub const CHUNKING_THRESHOLD: usize = 1_048_576;
+
+/// Minimal, average and maximal sizes of chunks, in bytes.
+const CHUNK_MIN_SIZE: u32 = 65_536;
+const CHUNK_AVG_SIZE: u32 = 262_144;
+const CHUNK_MAX_SIZE: u32 = 1_048_576;
+
+/// Split a content into chunks with content-defined boundaries.
+fn split_in_chunks(content: &[u8]) -> Vec<&[u8]> {
+    FastCDC::new(content, CHUNK_MIN_SIZE, CHUNK_AVG_SIZE, CHUNK_MAX_SIZE)
+        .map(|chunk| &content[chunk.offset..chunk.offset + chunk.length])
+        .collect()
+}
+
+/// Write a content to the interplanetary area as a list of chunks. Returns the cid of the
+/// [ ChunkList ] block.
+pub fn write_chunks_to_ip_area(content: &[u8], ip_context: &InterplanetaryContext) -> Result<Cid> {
+    let chunks_cids = split_in_chunks(content)
+        .into_iter()
+        .map(|chunk| {
+            DataChunk {
+                content: chunk.to_vec(),
+            }
+            .write_to_ip_area(ip_context)
+        })
+        .collect::<Result<Vec<Cid>>>()?;
+    Value::from(ChunkList { chunks_cids }).write_to_ip_area(ip_context)
+}
+
+/// Reassemble a content from the chunks listed in a [ ChunkList ].
+pub fn read_chunks_from_ip_area(
+    chunk_list: &ChunkList,
+    ip_context: &InterplanetaryContext,
+) -> Result<Vec<u8>> {
+    let mut content = Vec::new();
+    for chunk_cid in chunk_list.chunks_cids.iter() {
+        let chunk = DataChunk::read_from_ip_area(chunk_cid, ip_context)?;
+        content.extend_from_slice(&chunk.content);
+    }
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pseudo_random_content(len: usize, seed: u64) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 33) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chunks_cover_the_whole_content() {
+        let content = pseudo_random_content(4 * CHUNKING_THRESHOLD, 1);
+        let chunks = split_in_chunks(&content);
+        assert!(chunks.len() > 1);
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.len() <= CHUNK_MAX_SIZE as usize));
+        assert_eq!(chunks.concat(), content);
+    }
+
+    #[test]
+    fn chunks_are_shared_between_close_contents() {
+        let content = pseudo_random_content(4 * CHUNKING_THRESHOLD, 1);
+        let mut prefixed_content = pseudo_random_content(1000, 2);
+        prefixed_content.extend_from_slice(&content);
+        let chunks = split_in_chunks(&content);
+        let prefixed_chunks = split_in_chunks(&prefixed_content);
+        let nb_shared_chunks = chunks
+            .iter()
+            .filter(|chunk| prefixed_chunks.contains(chunk))
+            .count();
+        assert!(nb_shared_chunks >= chunks.len() - 2);
+    }
+}
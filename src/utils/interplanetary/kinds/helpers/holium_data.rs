@@ -1,5 +1,9 @@
 use crate::utils::interplanetary::context::InterplanetaryContext;
 use crate::utils::interplanetary::fs::traits::as_ip_block::AsInterplanetaryBlock;
+use crate::utils::interplanetary::kinds::chunk_list::ChunkList;
+use crate::utils::interplanetary::kinds::helpers::chunks::{
+    read_chunks_from_ip_area, write_chunks_to_ip_area, CHUNKING_THRESHOLD,
+};
 use crate::utils::interplanetary::kinds::recursive_data::RecursiveData;
 use crate::utils::interplanetary::kinds::recursive_data_envelope::RecursiveDataEnvelope;
 use crate::utils::interplanetary::kinds::scalar_data::ScalarData;
@@ -49,6 +53,13 @@ impl HoliumInterplanetaryNodeData {
             // unwrap scalar data
             let data = sk_cbor::read(&scalar_data.content)
                 .map_err(|_| Error::FailedToReadHoliumCborData)?;
+            // reassemble large scalar data that has been split into chunks
+            if let Ok(chunk_list) = ChunkList::try_from(data.clone()) {
+                let content = read_chunks_from_ip_area(&chunk_list, ip_context)?;
+                let data =
+                    sk_cbor::read(&content).map_err(|_| Error::FailedToReadHoliumCborData)?;
+                return Ok(data);
+            }
             Ok(data)
         } else if let Ok(recursive_data_envelope) = RecursiveDataEnvelope::try_from(*block) {
             // fetch recursive data from ip area
@@ -96,8 +107,13 @@ impl HoliumInterplanetaryNodeData {
                 let mut data_vec: Vec<u8> = Vec::new();
                 sk_cbor::write(self.0.clone(), &mut data_vec)
                     .map_err(|_| Error::FailedToWriteHoliumCborData)?;
-                let scalar_data = ScalarData { content: data_vec };
-                let scalar_data_cid = scalar_data.write_to_ip_area(&ip_context)?;
+                // large scalar data is split into chunks
+                let scalar_data_cid = if data_vec.len() > CHUNKING_THRESHOLD {
+                    write_chunks_to_ip_area(&data_vec, &ip_context)?
+                } else {
+                    let scalar_data = ScalarData { content: data_vec };
+                    scalar_data.write_to_ip_area(&ip_context)?
+                };
                 // write scalar data envelope to ip area
                 let scalar_data_envelope = ScalarDataEnvelope { scalar_data_cid };
                 let scalar_data_envelope_cid =
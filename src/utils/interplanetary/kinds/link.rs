@@ -15,7 +15,7 @@ enum Error {
 
 /// CBOR Tag ID registered to identify IPLD content identifiers.
 /// Reference: https://www.iana.org/assignments/cbor-tags/cbor-tags.xhtml
-static IPLD_CONTENT_CBOR_TAG_ID: u64 = 42;
+pub static IPLD_CONTENT_CBOR_TAG_ID: u64 = 42;
 
 pub struct Link(pub Cid);
 
@@ -1,7 +1,9 @@
 //! Structures used to interface Kinds from the Holium Framework with their stored representations
 //! of a file system.
 
+pub mod chunk_list;
 pub mod connection;
+pub mod data_chunk;
 pub mod dry_transformation;
 pub mod helpers;
 pub mod link;
@@ -13,6 +15,7 @@ pub mod pipeline_edge;
 pub mod pipeline_vertex;
 pub mod recursive_data;
 pub mod recursive_data_envelope;
+pub mod run_record;
 pub mod scalar_data;
 pub mod scalar_data_envelope;
 pub mod selector;
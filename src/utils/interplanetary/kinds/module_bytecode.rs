@@ -1,7 +1,16 @@
+use crate::utils::interplanetary::context::InterplanetaryContext;
 use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
 use crate::utils::interplanetary::fs::traits::as_ip_block::AsInterplanetaryBlock;
+use crate::utils::interplanetary::kinds::chunk_list::ChunkList;
+use crate::utils::interplanetary::kinds::helpers::chunks::{
+    read_chunks_from_ip_area, write_chunks_to_ip_area, CHUNKING_THRESHOLD,
+};
 
 use anyhow::Result;
+use cid::Cid;
+use sk_cbor::Value;
+
+use std::convert::TryFrom;
 
 use std::io::Cursor;
 
@@ -15,6 +24,30 @@ impl ModuleBytecode {
             bytecode: Cursor::new(bytecode),
         }
     }
+
+    /// Write the bytecode to the interplanetary area, as a single raw block or, if it is large, as
+    /// a list of chunks.
+    pub fn write_chunked_to_ip_area(&self, ip_context: &InterplanetaryContext) -> Result<Cid> {
+        if self.bytecode.get_ref().len() > CHUNKING_THRESHOLD {
+            write_chunks_to_ip_area(self.bytecode.get_ref(), ip_context)
+        } else {
+            self.write_to_ip_area(ip_context)
+        }
+    }
+
+    /// Read a bytecode from the interplanetary area, reassembling it if it has been chunked.
+    pub fn read_chunked_from_ip_area(
+        cid: &Cid,
+        ip_context: &InterplanetaryContext,
+    ) -> Result<Box<Self>> {
+        if cid.codec() == u64::from(&BlockMulticodec::DagCbor) {
+            let chunk_list_block = Value::read_from_ip_area(cid, ip_context)?;
+            let chunk_list = ChunkList::try_from(*chunk_list_block)?;
+            let bytecode = read_chunks_from_ip_area(&chunk_list, ip_context)?;
+            return Ok(Box::new(ModuleBytecode::new(bytecode)));
+        }
+        Self::read_from_ip_area(cid, ip_context)
+    }
 }
 
 impl AsInterplanetaryBlock<Cursor<Vec<u8>>> for ModuleBytecode {
@@ -1,8 +1,10 @@
+use crate::utils::interplanetary::kinds::link::Link;
 use crate::utils::interplanetary::kinds::pipeline_edge::PipelineEdge;
 use crate::utils::interplanetary::kinds::pipeline_vertex::PipelineVertex;
 use crate::utils::local::export::{VerticesContentMap, VerticesKeyMap};
 use anyhow::Error as AnyhowError;
 use anyhow::Result;
+use cid::Cid;
 
 use sk_cbor::cbor_array;
 use sk_cbor::cbor_map;
@@ -23,6 +25,8 @@ static DISCRIMINANT_KEY_V0: &str = "pl_0";
 pub struct Pipeline {
     pub vertices: Vec<PipelineVertex>,
     pub edges: Vec<PipelineEdge>,
+    /// Links to the provenance records of runs of the pipeline, only written when not empty
+    pub runs: Vec<Cid>,
 }
 
 impl Pipeline {
@@ -30,6 +34,7 @@ impl Pipeline {
         vertices_key_mapping: &VerticesKeyMap,
         vertices_content: &VerticesContentMap,
         edges: Vec<PipelineEdge>,
+        runs: Vec<Cid>,
     ) -> Result<Self> {
         // create an array of rightly sorted vertices
         let nb_nodes = vertices_key_mapping.len();
@@ -40,7 +45,11 @@ impl Pipeline {
                 .ok_or(Error::FailedToCreatePipelineVertex)?;
             let _ = std::mem::replace(&mut vertices[idx as usize], content.clone());
         }
-        Ok(Pipeline { vertices, edges })
+        Ok(Pipeline {
+            vertices,
+            edges,
+            runs,
+        })
     }
 }
 
@@ -62,9 +71,21 @@ impl From<Pipeline> for sk_cbor::Value {
                 .map(|v| -> Value { v.clone().into() })
                 .collect(),
         );
+        let content = if object.runs.is_empty() {
+            cbor_array![vertices, edges]
+        } else {
+            let runs = sk_cbor::Value::Array(
+                object
+                    .runs
+                    .into_iter()
+                    .map(|cid| -> Value { Link(cid).into() })
+                    .collect(),
+            );
+            cbor_array![vertices, edges, runs]
+        };
         cbor_map! {
             "typedVersion" => DISCRIMINANT_KEY_V0,
-            "content" => cbor_array![ vertices, edges ],
+            "content" => content,
         }
     }
 }
@@ -89,7 +110,21 @@ impl TryFrom<sk_cbor::Value> for Pipeline {
                                     .map(|v| -> Result<PipelineEdge> { v.clone().try_into() })
                                     .collect();
                                 let edges = edges?;
-                                return Ok(Pipeline { vertices, edges });
+                                let runs = match tuple.get(2) {
+                                    Some(Value::Array(runs_value)) => runs_value
+                                        .iter()
+                                        .map(|v| -> Result<Cid> {
+                                            Ok(Link::try_from(v.clone())?.0)
+                                        })
+                                        .collect::<Result<Vec<Cid>>>()?,
+                                    Some(_) => return Err(Error::FailedToManipulate.into()),
+                                    None => vec![],
+                                };
+                                return Ok(Pipeline {
+                                    vertices,
+                                    edges,
+                                    runs,
+                                });
                             }
                         }
                     }
@@ -33,10 +33,10 @@ impl TryFrom<sk_cbor::Value> for RecursiveDataEnvelope {
     type Error = AnyhowError;
     fn try_from(value: Value) -> Result<Self> {
         if let sk_cbor::Value::Map(tuples) = value {
-            let (_, discriminant_key) = tuples.get(0).ok_or(Error::FailedToManipulate)?;
+            let (_, discriminant_key) = tuples.get(1).ok_or(Error::FailedToManipulate)?;
             if *discriminant_key == Value::TextString(DISCRIMINANT_KEY_V0.to_string()) {
                 let (_, recursive_data_cid_value) =
-                    tuples.get(1).ok_or(Error::FailedToManipulate)?;
+                    tuples.get(0).ok_or(Error::FailedToManipulate)?;
                 let Link(recursive_data_cid) = Link::try_from(recursive_data_cid_value.clone())?;
                 return Ok(RecursiveDataEnvelope { recursive_data_cid });
             }
@@ -44,3 +44,35 @@ impl TryFrom<sk_cbor::Value> for RecursiveDataEnvelope {
         Err(Error::FailedToManipulate.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
+    use crate::utils::interplanetary::multiformats::{compute_cid, HashFunction};
+    use std::io::Cursor;
+
+    #[test]
+    fn can_decode_written_envelope() {
+        let cid = compute_cid(
+            Cursor::new(b"some data".to_vec()),
+            &BlockMulticodec::DagCbor,
+            &HashFunction::Blake3,
+        )
+        .unwrap();
+        let mut buffer: Vec<u8> = Vec::new();
+        sk_cbor::write(
+            RecursiveDataEnvelope {
+                recursive_data_cid: cid,
+            }
+            .into(),
+            &mut buffer,
+        )
+        .unwrap();
+
+        // map entries are read back in canonical order, with the content before the version
+        let envelope = RecursiveDataEnvelope::try_from(sk_cbor::read(&buffer).unwrap()).unwrap();
+
+        assert_eq!(envelope.recursive_data_cid, cid);
+    }
+}
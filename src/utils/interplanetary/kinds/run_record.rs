@@ -0,0 +1,89 @@
+//! Manipulate interplanetary blocks holding the provenance record of a run. Records are linked from
+//! the pipeline that has been run, listing them after its vertices and edges.
+//!
+//! Cids of the data, bytecode and files of nodes are kept as text, as they address raw content that
+//! is not stored in the interplanetary area.
+
+use crate::utils::local::models::run::{Run, RunConnection, RunNode, RunPortation};
+use sk_cbor::Value;
+use sk_cbor::{cbor_array, cbor_array_vec, cbor_map, cbor_null, cbor_text, cbor_unsigned};
+
+static DISCRIMINANT_KEY_V0: &str = "rn_0";
+
+pub struct RunRecord {
+    pub run: Run,
+}
+
+impl RunRecord {
+    pub fn new(run: Run) -> Self {
+        RunRecord { run }
+    }
+}
+
+fn optional_text(value: &Option<String>) -> Value {
+    match value {
+        Some(text) => cbor_text!(text.clone()),
+        None => cbor_null!(),
+    }
+}
+
+fn connection_value(connection: &RunConnection) -> Value {
+    cbor_array![
+        cbor_text!(connection.id.clone()),
+        cbor_text!(connection.tail_selector.clone()),
+        cbor_text!(connection.head_selector.clone()),
+    ]
+}
+
+fn portation_value(portation: &RunPortation) -> Value {
+    cbor_array![
+        cbor_text!(portation.id.clone()),
+        cbor_text!(portation.file_path.clone()),
+        optional_text(&portation.selector),
+        optional_text(&portation.file_cid),
+    ]
+}
+
+fn node_value(node: &RunNode) -> Value {
+    cbor_array![
+        cbor_text!(node.node_typed_name.clone()),
+        cbor_text!(node.input_cid.clone()),
+        cbor_text!(node.output_cid.clone()),
+        optional_text(&node.bytecode_cid),
+        optional_text(&node.handle),
+        cbor_array_vec!(node
+            .connections
+            .iter()
+            .map(connection_value)
+            .collect::<Vec<Value>>()),
+        cbor_array_vec!(node
+            .imports
+            .iter()
+            .map(portation_value)
+            .collect::<Vec<Value>>()),
+        cbor_array_vec!(node
+            .exports
+            .iter()
+            .map(portation_value)
+            .collect::<Vec<Value>>()),
+    ]
+}
+
+impl From<RunRecord> for sk_cbor::Value {
+    fn from(object: RunRecord) -> Self {
+        let nodes = cbor_array_vec!(object
+            .run
+            .nodes
+            .iter()
+            .map(node_value)
+            .collect::<Vec<Value>>());
+        cbor_map! {
+            "typedVersion" => DISCRIMINANT_KEY_V0,
+            "content" => cbor_array![
+                cbor_unsigned!(object.run.started_at),
+                cbor_unsigned!(object.run.finished_at),
+                nodes,
+            ],
+        }
+    }
+}
@@ -32,9 +32,9 @@ impl TryFrom<sk_cbor::Value> for ScalarDataEnvelope {
     type Error = AnyhowError;
     fn try_from(value: Value) -> Result<Self> {
         if let sk_cbor::Value::Map(tuples) = value {
-            let (_, discriminant_key) = tuples.get(0).ok_or(Error::FailedToManipulate)?;
+            let (_, discriminant_key) = tuples.get(1).ok_or(Error::FailedToManipulate)?;
             if *discriminant_key == Value::TextString(DISCRIMINANT_KEY_V0.to_string()) {
-                let (_, scalar_data_cid_value) = tuples.get(1).ok_or(Error::FailedToManipulate)?;
+                let (_, scalar_data_cid_value) = tuples.get(0).ok_or(Error::FailedToManipulate)?;
                 let Link(scalar_data_cid) = Link::try_from(scalar_data_cid_value.clone())?;
                 return Ok(ScalarDataEnvelope { scalar_data_cid });
             }
@@ -42,3 +42,35 @@ impl TryFrom<sk_cbor::Value> for ScalarDataEnvelope {
         Err(Error::FailedToManipulate.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
+    use crate::utils::interplanetary::multiformats::{compute_cid, HashFunction};
+    use std::io::Cursor;
+
+    #[test]
+    fn can_decode_written_envelope() {
+        let cid = compute_cid(
+            Cursor::new(b"some data".to_vec()),
+            &BlockMulticodec::DagCbor,
+            &HashFunction::Blake3,
+        )
+        .unwrap();
+        let mut buffer: Vec<u8> = Vec::new();
+        sk_cbor::write(
+            ScalarDataEnvelope {
+                scalar_data_cid: cid,
+            }
+            .into(),
+            &mut buffer,
+        )
+        .unwrap();
+
+        // map entries are read back in canonical order, with the content before the version
+        let envelope = ScalarDataEnvelope::try_from(sk_cbor::read(&buffer).unwrap()).unwrap();
+
+        assert_eq!(envelope.scalar_data_cid, cid);
+    }
+}
@@ -4,7 +4,10 @@ use anyhow::{Context, Error as AnyhowError};
 use serde_json::map::Map;
 use serde_json::value::Value as JsonValue;
 use serde_json::Number;
-use sk_cbor::{cbor_array_vec, cbor_map, cbor_unsigned};
+use sk_cbor::{
+    cbor_array_vec, cbor_bool, cbor_int, cbor_map, cbor_map_collection, cbor_map_options,
+    cbor_null, cbor_text, cbor_unsigned,
+};
 
 use std::convert::{TryFrom, TryInto};
 
@@ -16,6 +19,19 @@ enum Error {
     FailedToParseJsonLiteral,
     #[error("failed to manipulate selector kind")]
     FailedToManipulate,
+    #[error("unknown selector condition kind: {0}")]
+    UnknownConditionKind(String),
+}
+
+/// Retrieve the value associated to a text key in a cbor map
+fn cbor_map_get<'a>(
+    tuples: &'a [(sk_cbor::Value, sk_cbor::Value)],
+    key: &str,
+) -> Option<&'a sk_cbor::Value> {
+    tuples.iter().find_map(|(k, v)| match k {
+        sk_cbor::Value::TextString(k) if k == key => Some(v),
+        _ => None,
+    })
 }
 
 /****************
@@ -60,12 +76,17 @@ impl TryFrom<sk_cbor::Value> for SelectorEnvelope {
 Selector
 ****************/
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Selector {
     Matcher(Matcher),
+    ExploreAll(Box<ExploreAll>),
+    ExploreFields(Box<ExploreFields>),
     ExploreIndex(Box<ExploreIndex>),
     ExploreRange(Box<ExploreRange>),
+    ExploreRecursive(Box<ExploreRecursive>),
+    ExploreRecursiveEdge(ExploreRecursiveEdge),
     ExploreUnion(Box<ExploreUnion>),
+    ExploreConditional(Box<ExploreConditional>),
 }
 
 impl Selector {
@@ -82,6 +103,92 @@ impl Selector {
             _ => false,
         }
     }
+
+    /// Expand a selector into its branches, one per union branch however deep it is in the
+    /// selector, in the order they appear. Branches do not contain any union, except in recursion
+    /// sequences, as all nodes matched while recursing end up in a single data set.
+    pub fn branches(&self) -> Vec<Selector> {
+        match self {
+            Selector::Matcher(_) | Selector::ExploreRecursive(_) => vec![self.clone()],
+            Selector::ExploreRecursiveEdge(_) => vec![],
+            Selector::ExploreUnion(child) => child.0.iter().flat_map(Selector::branches).collect(),
+            Selector::ExploreAll(child) => child
+                .next
+                .branches()
+                .into_iter()
+                .map(|next| {
+                    Selector::ExploreAll(Box::new(ExploreAll {
+                        next: Box::new(next),
+                    }))
+                })
+                .collect(),
+            Selector::ExploreRange(child) => child
+                .next
+                .branches()
+                .into_iter()
+                .map(|next| {
+                    Selector::ExploreRange(Box::new(ExploreRange {
+                        start: child.start,
+                        end: child.end,
+                        next: Box::new(next),
+                    }))
+                })
+                .collect(),
+            Selector::ExploreIndex(child) => child
+                .next
+                .branches()
+                .into_iter()
+                .map(|next| {
+                    Selector::ExploreIndex(Box::new(ExploreIndex {
+                        index: child.index,
+                        next: Box::new(next),
+                    }))
+                })
+                .collect(),
+            Selector::ExploreFields(child) => child
+                .fields
+                .iter()
+                .flat_map(|(name, selector)| {
+                    selector.branches().into_iter().map(move |next| {
+                        Selector::ExploreFields(Box::new(ExploreFields {
+                            fields: vec![(name.clone(), next)],
+                        }))
+                    })
+                })
+                .collect(),
+            Selector::ExploreConditional(child) => child
+                .next
+                .branches()
+                .into_iter()
+                .map(|next| {
+                    Selector::ExploreConditional(Box::new(ExploreConditional {
+                        condition: child.condition.clone(),
+                        next: Box::new(next),
+                    }))
+                })
+                .collect(),
+        }
+    }
+
+    /// Number of data sets produced when selecting data with a selector, that is its number of
+    /// [branches](Selector::branches).
+    pub fn data_sets_count(&self) -> usize {
+        self.branches().len()
+    }
+
+    /// Check if a selector, or any of its children, addresses fields by their property name
+    pub fn has_fields(&self) -> bool {
+        match self {
+            Selector::Matcher(_) | Selector::ExploreRecursiveEdge(_) => false,
+            Selector::ExploreFields(_) => true,
+            Selector::ExploreAll(child) => child.next.has_fields(),
+            Selector::ExploreIndex(child) => child.next.has_fields(),
+            Selector::ExploreRange(child) => child.next.has_fields(),
+            Selector::ExploreRecursive(child) => child.sequence.has_fields(),
+            Selector::ExploreUnion(child) => child.0.iter().any(|s| s.has_fields()),
+            Selector::ExploreConditional(child) => child.next.has_fields(),
+        }
+    }
 }
 
 impl TryFrom<&str> for Selector {
@@ -97,9 +204,14 @@ impl From<Selector> for sk_cbor::Value {
     fn from(object: Selector) -> Self {
         let (key, child_selector): (&str, sk_cbor::Value) = match object {
             Selector::Matcher(child) => (".", child.into()),
+            Selector::ExploreAll(child) => ("a", { *child }.into()),
+            Selector::ExploreFields(child) => ("f", { *child }.into()),
             Selector::ExploreIndex(child) => ("i", { *child }.into()),
             Selector::ExploreRange(child) => ("r", { *child }.into()),
+            Selector::ExploreRecursive(child) => ("R", { *child }.into()),
+            Selector::ExploreRecursiveEdge(child) => ("@", child.into()),
             Selector::ExploreUnion(child) => ("|", { *child }.into()),
+            Selector::ExploreConditional(child) => ("&", { *child }.into()),
         };
         cbor_map! {
             key => child_selector
@@ -111,9 +223,14 @@ impl From<Selector> for JsonValue {
     fn from(object: Selector) -> Self {
         let (key, child_selector): (&str, JsonValue) = match object {
             Selector::Matcher(child) => (".", child.into()),
+            Selector::ExploreAll(child) => ("a", { *child }.into()),
+            Selector::ExploreFields(child) => ("f", { *child }.into()),
             Selector::ExploreIndex(child) => ("i", { *child }.into()),
             Selector::ExploreRange(child) => ("r", { *child }.into()),
+            Selector::ExploreRecursive(child) => ("R", { *child }.into()),
+            Selector::ExploreRecursiveEdge(child) => ("@", child.into()),
             Selector::ExploreUnion(child) => ("|", { *child }.into()),
+            Selector::ExploreConditional(child) => ("&", { *child }.into()),
         };
         let mut map = Map::new();
         map.insert(key.to_string(), child_selector);
@@ -140,6 +257,21 @@ impl TryFrom<sk_cbor::Value> for Selector {
                     } else if k == "|" {
                         let child = ExploreUnion::try_from(v.clone())?;
                         return Ok(Selector::ExploreUnion(Box::new(child)));
+                    } else if k == "a" {
+                        let child = ExploreAll::try_from(v.clone())?;
+                        return Ok(Selector::ExploreAll(Box::new(child)));
+                    } else if k == "f" {
+                        let child = ExploreFields::try_from(v.clone())?;
+                        return Ok(Selector::ExploreFields(Box::new(child)));
+                    } else if k == "R" {
+                        let child = ExploreRecursive::try_from(v.clone())?;
+                        return Ok(Selector::ExploreRecursive(Box::new(child)));
+                    } else if k == "@" {
+                        let child = ExploreRecursiveEdge::try_from(v.clone())?;
+                        return Ok(Selector::ExploreRecursiveEdge(child));
+                    } else if k == "&" {
+                        let child = ExploreConditional::try_from(v.clone())?;
+                        return Ok(Selector::ExploreConditional(Box::new(child)));
                     }
                 }
             }
@@ -160,6 +292,20 @@ impl TryFrom<JsonValue> for Selector {
                 return Ok(Selector::ExploreRange(Box::new(child.clone().try_into()?)));
             } else if let Some(child) = map.get("|") {
                 return Ok(Selector::ExploreUnion(Box::new(child.clone().try_into()?)));
+            } else if let Some(child) = map.get("a") {
+                return Ok(Selector::ExploreAll(Box::new(child.clone().try_into()?)));
+            } else if let Some(child) = map.get("f") {
+                return Ok(Selector::ExploreFields(Box::new(child.clone().try_into()?)));
+            } else if let Some(child) = map.get("R") {
+                return Ok(Selector::ExploreRecursive(Box::new(
+                    child.clone().try_into()?,
+                )));
+            } else if let Some(child) = map.get("@") {
+                return Ok(Selector::ExploreRecursiveEdge(child.clone().try_into()?));
+            } else if let Some(child) = map.get("&") {
+                return Ok(Selector::ExploreConditional(Box::new(
+                    child.clone().try_into()?,
+                )));
             }
         };
         Err(Error::FailedToParseJsonLiteral.into())
@@ -170,7 +316,7 @@ impl TryFrom<JsonValue> for Selector {
 Matcher
 ****************/
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Matcher {
     pub label: Option<String>,
 }
@@ -233,7 +379,7 @@ impl TryFrom<JsonValue> for Matcher {
 ExploreIndex
 ****************/
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ExploreIndex {
     pub index: u64,
     pub next: Box<Selector>,
@@ -306,7 +452,7 @@ impl TryFrom<sk_cbor::Value> for ExploreIndex {
 ExploreRange
 ****************/
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ExploreRange {
     pub start: u64,
     pub end: u64,
@@ -395,7 +541,7 @@ impl TryFrom<sk_cbor::Value> for ExploreRange {
 /****************
 ExploreUnion
 ****************/
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ExploreUnion(pub Vec<Selector>);
 
 impl From<ExploreUnion> for sk_cbor::Value {
@@ -445,3 +591,572 @@ impl TryFrom<sk_cbor::Value> for ExploreUnion {
         Err(Error::FailedToManipulate.into())
     }
 }
+
+/****************
+ExploreAll
+****************/
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExploreAll {
+    pub next: Box<Selector>,
+}
+
+impl From<ExploreAll> for sk_cbor::Value {
+    fn from(object: ExploreAll) -> Self {
+        let selector: sk_cbor::Value = { *object.next }.into();
+        cbor_map! {
+            ">" => selector,
+        }
+    }
+}
+
+impl From<ExploreAll> for JsonValue {
+    fn from(object: ExploreAll) -> Self {
+        let selector: JsonValue = { *object.next }.into();
+        let mut map = Map::new();
+        map.insert(">".to_string(), selector);
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<JsonValue> for ExploreAll {
+    type Error = AnyhowError;
+    fn try_from(json_value: JsonValue) -> Result<Self> {
+        if let JsonValue::Object(map) = json_value {
+            if let Some(next_value) = map.get(">") {
+                return Ok(ExploreAll {
+                    next: Box::new(next_value.clone().try_into()?),
+                });
+            }
+        };
+        Err(Error::FailedToParseJsonLiteral.into())
+    }
+}
+
+impl TryFrom<sk_cbor::Value> for ExploreAll {
+    type Error = AnyhowError;
+    fn try_from(value: sk_cbor::Value) -> Result<Self> {
+        if let sk_cbor::Value::Map(tuples) = value {
+            if let Some(next_value) = cbor_map_get(&tuples, ">") {
+                return Ok(ExploreAll {
+                    next: Box::new(next_value.clone().try_into()?),
+                });
+            }
+        };
+        Err(Error::FailedToManipulate.into())
+    }
+}
+
+/****************
+ExploreFields
+****************/
+
+/// [ExploreFields] addresses children of an object by their property name. As Holium data objects
+/// are serialized as arrays, property names have to be resolved to indices against a JSON schema
+/// before the selector can be applied.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExploreFields {
+    pub fields: Vec<(String, Selector)>,
+}
+
+impl ExploreFields {
+    /// Comma separated list of the addressed property names
+    pub fn field_names(&self) -> String {
+        self.fields
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<&str>>()
+            .join(", ")
+    }
+}
+
+impl From<ExploreFields> for sk_cbor::Value {
+    fn from(object: ExploreFields) -> Self {
+        let fields: Vec<(sk_cbor::Value, sk_cbor::Value)> = object
+            .fields
+            .into_iter()
+            .map(|(name, selector)| (cbor_text!(name), selector.into()))
+            .collect();
+        cbor_map! {
+            "f>" => cbor_map_collection!(fields),
+        }
+    }
+}
+
+impl From<ExploreFields> for JsonValue {
+    fn from(object: ExploreFields) -> Self {
+        let mut fields = Map::new();
+        for (name, selector) in object.fields {
+            fields.insert(name, selector.into());
+        }
+        let mut map = Map::new();
+        map.insert("f>".to_string(), JsonValue::Object(fields));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<JsonValue> for ExploreFields {
+    type Error = AnyhowError;
+    fn try_from(json_value: JsonValue) -> Result<Self> {
+        if let JsonValue::Object(map) = json_value {
+            if let Some(JsonValue::Object(fields_map)) = map.get("f>") {
+                let fields = fields_map
+                    .iter()
+                    .map(|(name, v)| Ok((name.clone(), v.clone().try_into()?)))
+                    .collect::<Result<Vec<(String, Selector)>>>()?;
+                return Ok(ExploreFields { fields });
+            }
+        };
+        Err(Error::FailedToParseJsonLiteral.into())
+    }
+}
+
+impl TryFrom<sk_cbor::Value> for ExploreFields {
+    type Error = AnyhowError;
+    fn try_from(value: sk_cbor::Value) -> Result<Self> {
+        if let sk_cbor::Value::Map(tuples) = value {
+            if let Some(sk_cbor::Value::Map(fields_tuples)) = cbor_map_get(&tuples, "f>") {
+                let fields = fields_tuples
+                    .iter()
+                    .map(|(k, v)| match k {
+                        sk_cbor::Value::TextString(name) => {
+                            Ok((name.clone(), v.clone().try_into()?))
+                        }
+                        _ => Err(Error::FailedToManipulate.into()),
+                    })
+                    .collect::<Result<Vec<(String, Selector)>>>()?;
+                return Ok(ExploreFields { fields });
+            }
+        };
+        Err(Error::FailedToManipulate.into())
+    }
+}
+
+/****************
+ExploreRecursive
+****************/
+
+/// [ExploreRecursive] applies a sequence selector and, every time an [ExploreRecursiveEdge] is
+/// reached in it, applies the sequence again from the reached node. All matched nodes are
+/// gathered in a single data set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExploreRecursive {
+    pub limit: RecursionLimit,
+    pub sequence: Box<Selector>,
+    pub stop_at: Option<Condition>,
+}
+
+/// [RecursionLimit] bounds the number of times the sequence of an [ExploreRecursive] selector may
+/// be applied.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RecursionLimit {
+    None,
+    Depth(u64),
+}
+
+impl From<RecursionLimit> for sk_cbor::Value {
+    fn from(object: RecursionLimit) -> Self {
+        match object {
+            RecursionLimit::None => cbor_map! { "none" => cbor_map! {} },
+            RecursionLimit::Depth(depth) => cbor_map! { "depth" => cbor_unsigned!(depth) },
+        }
+    }
+}
+
+impl From<RecursionLimit> for JsonValue {
+    fn from(object: RecursionLimit) -> Self {
+        let mut map = Map::new();
+        match object {
+            RecursionLimit::None => map.insert("none".to_string(), JsonValue::Object(Map::new())),
+            RecursionLimit::Depth(depth) => {
+                map.insert("depth".to_string(), JsonValue::Number(Number::from(depth)))
+            }
+        };
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<JsonValue> for RecursionLimit {
+    type Error = AnyhowError;
+    fn try_from(json_value: JsonValue) -> Result<Self> {
+        if let JsonValue::Object(map) = json_value {
+            if map.get("none").is_some() {
+                return Ok(RecursionLimit::None);
+            }
+            if let Some(JsonValue::Number(depth)) = map.get("depth") {
+                return Ok(RecursionLimit::Depth(
+                    depth.as_u64().ok_or(Error::FailedToParseJsonLiteral)?,
+                ));
+            }
+        };
+        Err(Error::FailedToParseJsonLiteral.into())
+    }
+}
+
+impl TryFrom<sk_cbor::Value> for RecursionLimit {
+    type Error = AnyhowError;
+    fn try_from(value: sk_cbor::Value) -> Result<Self> {
+        if let sk_cbor::Value::Map(tuples) = value {
+            if cbor_map_get(&tuples, "none").is_some() {
+                return Ok(RecursionLimit::None);
+            }
+            if let Some(sk_cbor::Value::Unsigned(depth)) = cbor_map_get(&tuples, "depth") {
+                return Ok(RecursionLimit::Depth(*depth));
+            }
+        };
+        Err(Error::FailedToManipulate.into())
+    }
+}
+
+impl From<ExploreRecursive> for sk_cbor::Value {
+    fn from(object: ExploreRecursive) -> Self {
+        let limit: sk_cbor::Value = object.limit.into();
+        let sequence: sk_cbor::Value = { *object.sequence }.into();
+        let stop_at: Option<sk_cbor::Value> = object.stop_at.map(|c| c.into());
+        cbor_map_options! {
+            "l" => limit,
+            ":>" => sequence,
+            "!" => stop_at,
+        }
+    }
+}
+
+impl From<ExploreRecursive> for JsonValue {
+    fn from(object: ExploreRecursive) -> Self {
+        let mut map = Map::new();
+        map.insert("l".to_string(), object.limit.into());
+        map.insert(":>".to_string(), { *object.sequence }.into());
+        if let Some(stop_at) = object.stop_at {
+            map.insert("!".to_string(), stop_at.into());
+        }
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<JsonValue> for ExploreRecursive {
+    type Error = AnyhowError;
+    fn try_from(json_value: JsonValue) -> Result<Self> {
+        if let JsonValue::Object(map) = json_value {
+            if let Some(limit_value) = map.get("l") {
+                if let Some(sequence_value) = map.get(":>") {
+                    let stop_at = match map.get("!") {
+                        Some(stop_at_value) => Some(stop_at_value.clone().try_into()?),
+                        None => None,
+                    };
+                    return Ok(ExploreRecursive {
+                        limit: limit_value.clone().try_into()?,
+                        sequence: Box::new(sequence_value.clone().try_into()?),
+                        stop_at,
+                    });
+                }
+            }
+        };
+        Err(Error::FailedToParseJsonLiteral.into())
+    }
+}
+
+impl TryFrom<sk_cbor::Value> for ExploreRecursive {
+    type Error = AnyhowError;
+    fn try_from(value: sk_cbor::Value) -> Result<Self> {
+        if let sk_cbor::Value::Map(tuples) = value {
+            if let Some(limit_value) = cbor_map_get(&tuples, "l") {
+                if let Some(sequence_value) = cbor_map_get(&tuples, ":>") {
+                    let stop_at = match cbor_map_get(&tuples, "!") {
+                        Some(stop_at_value) => Some(stop_at_value.clone().try_into()?),
+                        None => None,
+                    };
+                    return Ok(ExploreRecursive {
+                        limit: limit_value.clone().try_into()?,
+                        sequence: Box::new(sequence_value.clone().try_into()?),
+                        stop_at,
+                    });
+                }
+            }
+        };
+        Err(Error::FailedToManipulate.into())
+    }
+}
+
+/****************
+ExploreRecursiveEdge
+****************/
+
+/// [ExploreRecursiveEdge] marks the place, in the sequence of an [ExploreRecursive] selector,
+/// where the sequence should be applied again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExploreRecursiveEdge;
+
+impl From<ExploreRecursiveEdge> for sk_cbor::Value {
+    fn from(_: ExploreRecursiveEdge) -> Self {
+        cbor_map! {}
+    }
+}
+
+impl From<ExploreRecursiveEdge> for JsonValue {
+    fn from(_: ExploreRecursiveEdge) -> Self {
+        JsonValue::Object(Map::new())
+    }
+}
+
+impl TryFrom<JsonValue> for ExploreRecursiveEdge {
+    type Error = AnyhowError;
+    fn try_from(json_value: JsonValue) -> Result<Self> {
+        if let JsonValue::Object(_) = json_value {
+            return Ok(ExploreRecursiveEdge);
+        };
+        Err(Error::FailedToParseJsonLiteral.into())
+    }
+}
+
+impl TryFrom<sk_cbor::Value> for ExploreRecursiveEdge {
+    type Error = AnyhowError;
+    fn try_from(value: sk_cbor::Value) -> Result<Self> {
+        if let sk_cbor::Value::Map(_) = value {
+            return Ok(ExploreRecursiveEdge);
+        };
+        Err(Error::FailedToManipulate.into())
+    }
+}
+
+/****************
+ExploreConditional
+****************/
+
+/// [ExploreConditional] applies its next selector only to nodes matching a condition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExploreConditional {
+    pub condition: Condition,
+    pub next: Box<Selector>,
+}
+
+impl From<ExploreConditional> for sk_cbor::Value {
+    fn from(object: ExploreConditional) -> Self {
+        let condition: sk_cbor::Value = object.condition.into();
+        let selector: sk_cbor::Value = { *object.next }.into();
+        cbor_map! {
+            "&" => condition,
+            ">" => selector,
+        }
+    }
+}
+
+impl From<ExploreConditional> for JsonValue {
+    fn from(object: ExploreConditional) -> Self {
+        let mut map = Map::new();
+        map.insert("&".to_string(), object.condition.into());
+        map.insert(">".to_string(), { *object.next }.into());
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<JsonValue> for ExploreConditional {
+    type Error = AnyhowError;
+    fn try_from(json_value: JsonValue) -> Result<Self> {
+        if let JsonValue::Object(map) = json_value {
+            if let Some(condition_value) = map.get("&") {
+                if let Some(next_value) = map.get(">") {
+                    return Ok(ExploreConditional {
+                        condition: condition_value.clone().try_into()?,
+                        next: Box::new(next_value.clone().try_into()?),
+                    });
+                }
+            }
+        };
+        Err(Error::FailedToParseJsonLiteral.into())
+    }
+}
+
+impl TryFrom<sk_cbor::Value> for ExploreConditional {
+    type Error = AnyhowError;
+    fn try_from(value: sk_cbor::Value) -> Result<Self> {
+        if let sk_cbor::Value::Map(tuples) = value {
+            if let Some(condition_value) = cbor_map_get(&tuples, "&") {
+                if let Some(next_value) = cbor_map_get(&tuples, ">") {
+                    return Ok(ExploreConditional {
+                        condition: condition_value.clone().try_into()?,
+                        next: Box::new(next_value.clone().try_into()?),
+                    });
+                }
+            }
+        };
+        Err(Error::FailedToManipulate.into())
+    }
+}
+
+/****************
+Condition
+****************/
+
+/// [Condition] is evaluated against a node of a Holium data object, either on its kind (`k`) or
+/// on its scalar value (`=`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Condition {
+    HasKind(Kind),
+    HasValue(ConditionValue),
+}
+
+/// [Kind] lists the kinds of nodes that can be found in a Holium data object.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Null,
+    Bool,
+    Integer,
+    String,
+    Bytes,
+    List,
+    Map,
+}
+
+impl Kind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Kind::Null => "null",
+            Kind::Bool => "bool",
+            Kind::Integer => "integer",
+            Kind::String => "string",
+            Kind::Bytes => "bytes",
+            Kind::List => "list",
+            Kind::Map => "map",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "null" => Ok(Kind::Null),
+            "bool" => Ok(Kind::Bool),
+            "integer" => Ok(Kind::Integer),
+            "string" => Ok(Kind::String),
+            "bytes" => Ok(Kind::Bytes),
+            "list" => Ok(Kind::List),
+            "map" => Ok(Kind::Map),
+            _ => Err(Error::UnknownConditionKind(name.to_string()).into()),
+        }
+    }
+}
+
+/// [ConditionValue] is a scalar value a node can be compared to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConditionValue {
+    Null,
+    Bool(bool),
+    Unsigned(u64),
+    Negative(i64),
+    Text(String),
+}
+
+impl From<ConditionValue> for sk_cbor::Value {
+    fn from(object: ConditionValue) -> Self {
+        match object {
+            ConditionValue::Null => cbor_null!(),
+            ConditionValue::Bool(b) => cbor_bool!(b),
+            ConditionValue::Unsigned(u) => cbor_unsigned!(u),
+            ConditionValue::Negative(i) => cbor_int!(i),
+            ConditionValue::Text(t) => cbor_text!(t),
+        }
+    }
+}
+
+impl From<ConditionValue> for JsonValue {
+    fn from(object: ConditionValue) -> Self {
+        match object {
+            ConditionValue::Null => JsonValue::Null,
+            ConditionValue::Bool(b) => JsonValue::Bool(b),
+            ConditionValue::Unsigned(u) => JsonValue::Number(Number::from(u)),
+            ConditionValue::Negative(i) => JsonValue::Number(Number::from(i)),
+            ConditionValue::Text(t) => JsonValue::String(t),
+        }
+    }
+}
+
+impl TryFrom<JsonValue> for ConditionValue {
+    type Error = AnyhowError;
+    fn try_from(json_value: JsonValue) -> Result<Self> {
+        match json_value {
+            JsonValue::Null => Ok(ConditionValue::Null),
+            JsonValue::Bool(b) => Ok(ConditionValue::Bool(b)),
+            JsonValue::Number(n) => {
+                if let Some(u) = n.as_u64() {
+                    Ok(ConditionValue::Unsigned(u))
+                } else if let Some(i) = n.as_i64() {
+                    Ok(ConditionValue::Negative(i))
+                } else {
+                    Err(Error::FailedToParseJsonLiteral.into())
+                }
+            }
+            JsonValue::String(t) => Ok(ConditionValue::Text(t)),
+            _ => Err(Error::FailedToParseJsonLiteral.into()),
+        }
+    }
+}
+
+impl TryFrom<sk_cbor::Value> for ConditionValue {
+    type Error = AnyhowError;
+    fn try_from(value: sk_cbor::Value) -> Result<Self> {
+        match value {
+            sk_cbor::Value::Simple(sk_cbor::SimpleValue::NullValue) => Ok(ConditionValue::Null),
+            sk_cbor::Value::Simple(sk_cbor::SimpleValue::TrueValue) => {
+                Ok(ConditionValue::Bool(true))
+            }
+            sk_cbor::Value::Simple(sk_cbor::SimpleValue::FalseValue) => {
+                Ok(ConditionValue::Bool(false))
+            }
+            sk_cbor::Value::Unsigned(u) => Ok(ConditionValue::Unsigned(u)),
+            sk_cbor::Value::Negative(i) => Ok(ConditionValue::Negative(i)),
+            sk_cbor::Value::TextString(t) => Ok(ConditionValue::Text(t)),
+            _ => Err(Error::FailedToManipulate.into()),
+        }
+    }
+}
+
+impl From<Condition> for sk_cbor::Value {
+    fn from(object: Condition) -> Self {
+        match object {
+            Condition::HasKind(kind) => cbor_map! { "k" => kind.name() },
+            Condition::HasValue(value) => {
+                let value: sk_cbor::Value = value.into();
+                cbor_map! { "=" => value }
+            }
+        }
+    }
+}
+
+impl From<Condition> for JsonValue {
+    fn from(object: Condition) -> Self {
+        let mut map = Map::new();
+        match object {
+            Condition::HasKind(kind) => {
+                map.insert("k".to_string(), JsonValue::String(kind.name().to_string()))
+            }
+            Condition::HasValue(value) => map.insert("=".to_string(), value.into()),
+        };
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<JsonValue> for Condition {
+    type Error = AnyhowError;
+    fn try_from(json_value: JsonValue) -> Result<Self> {
+        if let JsonValue::Object(map) = json_value {
+            if let Some(JsonValue::String(kind)) = map.get("k") {
+                return Ok(Condition::HasKind(Kind::from_name(kind)?));
+            } else if let Some(value) = map.get("=") {
+                return Ok(Condition::HasValue(value.clone().try_into()?));
+            }
+        };
+        Err(Error::FailedToParseJsonLiteral.into())
+    }
+}
+
+impl TryFrom<sk_cbor::Value> for Condition {
+    type Error = AnyhowError;
+    fn try_from(value: sk_cbor::Value) -> Result<Self> {
+        if let sk_cbor::Value::Map(tuples) = value {
+            if let Some(sk_cbor::Value::TextString(kind)) = cbor_map_get(&tuples, "k") {
+                return Ok(Condition::HasKind(Kind::from_name(kind)?));
+            } else if let Some(value) = cbor_map_get(&tuples, "=") {
+                return Ok(Condition::HasValue(value.clone().try_into()?));
+            }
+        };
+        Err(Error::FailedToManipulate.into())
+    }
+}
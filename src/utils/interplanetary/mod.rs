@@ -1,4 +1,6 @@
+pub mod block_store;
 pub mod context;
+pub mod diff;
 pub mod fs;
 pub mod kinds;
 pub mod multiformats;
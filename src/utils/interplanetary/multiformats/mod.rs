@@ -2,23 +2,28 @@
 //! Reference: https://multiformats.io/
 
 use std::convert::TryFrom;
+use std::fmt;
 use std::io;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use cid::multibase::Base;
 use cid::multihash::Multihash;
 use cid::{Cid, Version};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use thiserror;
 
-use crate::utils::interplanetary::context::InterplanetaryContext;
 use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
 
 use std::io::{Read, Seek};
 
 /// Blake3 multicodec code.
 pub const BLAKE3_HASH_FUNC_TYPE: u8 = 0x1e;
+/// Sha2-256 multicodec code.
+pub const SHA2_256_HASH_FUNC_TYPE: u8 = 0x12;
 /// Cid version used in the framework
 pub const CID_VERSION: Version = Version::V1;
 /// Default multi base for printing and file naming operations
@@ -28,55 +33,145 @@ pub const DEFAULT_MULTIBASE: Base = Base::Base32Lower;
 /// Errors for the interplanetary utility module.
 pub(crate) enum Error {
     #[error("failed to run the hashing algorithm")]
-    Blake3HashingError,
-    #[error("failed to create multihash from blake3 hash")]
-    Blake3MultihashCreationError,
+    HashingError,
+    #[error("failed to create multihash from hash digest")]
+    MultihashCreationError,
     #[error("failed to create cid")]
     CidCreationError,
     #[error("failed to create block path from cid")]
     BlockPathCreationError,
     #[error("failed to parse block path as cid")]
     CidFromPathError,
+    #[error("unsupported hash function in cid: {0:#x}")]
+    UnsupportedHashFunction(u64),
+    #[error("unknown hash function: {0}")]
+    UnknownHashFunction(String),
+    #[error("block content does not match its cid: {0}")]
+    BlockIntegrityCheckFailed(String),
+}
+
+/// Hash functions that can be used to compute the cid of interplanetary blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HashFunction {
+    #[serde(rename = "blake3")]
+    Blake3,
+    #[serde(rename = "sha2-256")]
+    Sha2_256,
+}
+
+impl HashFunction {
+    /// Names of the available hash functions, as used in CLI arguments and configuration files.
+    pub fn variants() -> [&'static str; 2] {
+        ["blake3", "sha2-256"]
+    }
+
+    /// Multicodec code of the hash function.
+    pub fn code(&self) -> u64 {
+        match self {
+            HashFunction::Blake3 => BLAKE3_HASH_FUNC_TYPE as u64,
+            HashFunction::Sha2_256 => SHA2_256_HASH_FUNC_TYPE as u64,
+        }
+    }
+
+    /// Find the hash function related to a multicodec code.
+    pub fn from_code(code: u64) -> Result<Self> {
+        match code {
+            c if c == BLAKE3_HASH_FUNC_TYPE as u64 => Ok(HashFunction::Blake3),
+            c if c == SHA2_256_HASH_FUNC_TYPE as u64 => Ok(HashFunction::Sha2_256),
+            _ => Err(Error::UnsupportedHashFunction(code).into()),
+        }
+    }
+}
+
+impl Default for HashFunction {
+    fn default() -> Self {
+        HashFunction::Blake3
+    }
+}
+
+impl fmt::Display for HashFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashFunction::Blake3 => write!(f, "blake3"),
+            HashFunction::Sha2_256 => write!(f, "sha2-256"),
+        }
+    }
+}
+
+impl FromStr for HashFunction {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "blake3" => Ok(HashFunction::Blake3),
+            "sha2-256" => Ok(HashFunction::Sha2_256),
+            _ => Err(Error::UnknownHashFunction(s.to_string()).into()),
+        }
+    }
 }
 
 /// Compute the cid of a block;
-pub fn compute_cid<T: Read + Seek>(mut content: T, codec: &BlockMulticodec) -> Result<Cid> {
-    // initialize a hasher
-    let mut hasher = blake3::Hasher::new();
-    // write block content to it
-    io::copy(&mut content, &mut hasher).context(Error::Blake3HashingError)?;
-    content.rewind().context(Error::Blake3HashingError)?;
-    // finalize hashing
-    let hash = hasher.finalize();
+pub fn compute_cid<T: Read + Seek>(
+    content: T,
+    codec: &BlockMulticodec,
+    hash_function: &HashFunction,
+) -> Result<Cid> {
+    // hash block content
+    let hash = hash_content(content, hash_function)?;
     // create multihash
-    let multihash = blake3_hash_to_multihash(*hash.as_bytes())?;
+    let multihash = hash_to_multihash(hash_function, hash)?;
     // create and return cid
     let cid = Cid::new(CID_VERSION, codec.into(), multihash).context(Error::CidCreationError)?;
     Ok(cid)
 }
 
-/// Create a Multihash from a 32-byte blake3 digest.
-/// [hash] should be the output of the Blake3 algorithm although obviously no verification
-/// is performed.
-pub fn blake3_hash_to_multihash(hash: [u8; 32]) -> Result<Multihash> {
-    let mut multihash_bytes = vec![BLAKE3_HASH_FUNC_TYPE, hash.len() as u8];
-    multihash_bytes.extend_from_slice(hash.as_ref());
-    Multihash::from_bytes(multihash_bytes.as_slice()).context(Error::Blake3MultihashCreationError)
+/// Check that the content of a block matches the multihash held by its cid. The hash function
+/// used is the one advertised by the cid.
+pub fn verify_cid<T: Read + Seek>(content: T, cid: &Cid) -> Result<()> {
+    let multihash = cid.hash();
+    let hash_function = HashFunction::from_code(multihash.code())?;
+    // re-hash block content and compare digests
+    let hash = hash_content(content, &hash_function)?;
+    if multihash.digest() != &hash[..] {
+        let cid_str = cid
+            .to_string_of_base(DEFAULT_MULTIBASE)
+            .unwrap_or(cid.to_string());
+        return Err(Error::BlockIntegrityCheckFailed(cid_str).into());
+    }
+    Ok(())
 }
 
-/// Deterministically convert an object CID to a path for local storage.
-pub(crate) fn cid_to_path(cid: &Cid, ip_context: &InterplanetaryContext) -> Result<PathBuf> {
-    // create relative path from cid
-    let rel_path = cid_to_object_path(&cid)?;
-    // create absolute path with context and return
-    Ok(ip_context.ip_area_path.join(rel_path))
+/// Run a hashing algorithm on some content, rewinding it afterwards.
+fn hash_content<T: Read + Seek>(mut content: T, hash_function: &HashFunction) -> Result<[u8; 32]> {
+    let hash = match hash_function {
+        HashFunction::Blake3 => {
+            let mut hasher = blake3::Hasher::new();
+            io::copy(&mut content, &mut hasher).context(Error::HashingError)?;
+            *hasher.finalize().as_bytes()
+        }
+        HashFunction::Sha2_256 => {
+            let mut hasher = Sha256::new();
+            io::copy(&mut content, &mut hasher).context(Error::HashingError)?;
+            hasher.finalize().into()
+        }
+    };
+    content.rewind().context(Error::HashingError)?;
+    Ok(hash)
+}
+
+/// Create a Multihash from a 32-byte digest.
+/// [hash] should be the output of the given hash function although obviously no verification
+/// is performed.
+pub fn hash_to_multihash(hash_function: &HashFunction, hash: [u8; 32]) -> Result<Multihash> {
+    let mut multihash_bytes = vec![hash_function.code() as u8, hash.len() as u8];
+    multihash_bytes.extend_from_slice(hash.as_ref());
+    Multihash::from_bytes(multihash_bytes.as_slice()).context(Error::MultihashCreationError)
 }
 
 /// Deterministically convert an object CID to a relative path.
 /// A form of sharding is performed.
 /// We follow specifications from the `ipfs/go-ds-flatfs` repository.
 /// Reference: https://github.com/ipfs/go-ds-flatfs/blob/master/readme.go
-fn cid_to_object_path(cid: &Cid) -> Result<PathBuf> {
+pub(crate) fn cid_to_object_path(cid: &Cid) -> Result<PathBuf> {
     // create base 32 cid string
     let cid_str = cid
         .to_string_of_base(DEFAULT_MULTIBASE)
@@ -97,23 +192,9 @@ fn cid_to_object_path(cid: &Cid) -> Result<PathBuf> {
     Ok(PathBuf::from(format!("{}{}/{}", c0, c1, cs.as_str())))
 }
 
-/// Deterministically convert an object absolute path used for local storage to related CID.
-pub fn path_to_cid(path: &PathBuf, ip_context: &InterplanetaryContext) -> Result<Cid> {
-    // check if given path is in expected directory according to the context
-    let interplanetary_dir_path = &ip_context.ip_area_path;
-    if !path.starts_with(&interplanetary_dir_path) {
-        return Err(Error::CidFromPathError.into());
-    }
-    // strip prefix, convert relative path to cid, and return it
-    let rel_path = path
-        .strip_prefix(&interplanetary_dir_path)
-        .context(Error::CidFromPathError)?;
-    object_path_to_cid(&rel_path.to_path_buf())
-}
-
 /// Deterministically convert an object local path to related CID.
 /// This function should be the inverse of [cid_to_object_path].
-fn object_path_to_cid(path: &PathBuf) -> Result<Cid> {
+pub(crate) fn object_path_to_cid(path: &PathBuf) -> Result<Cid> {
     let file_name = path.file_name().ok_or(Error::CidFromPathError)?;
     let cid_str = format!("b{}", file_name.to_string_lossy());
     Cid::try_from(cid_str).context(Error::CidFromPathError)
@@ -122,16 +203,76 @@ fn object_path_to_cid(path: &PathBuf) -> Result<Cid> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Cursor;
 
     #[test]
     fn can_get_multihash_from_blake3_hash() {
         let hash = [0x42 as u8; 32];
-        let multihash = blake3_hash_to_multihash(hash.clone()).unwrap();
+        let multihash = hash_to_multihash(&HashFunction::Blake3, hash.clone()).unwrap();
         assert_eq!(multihash.code(), BLAKE3_HASH_FUNC_TYPE as u64);
         assert_eq!(multihash.size(), 0x20);
         assert_eq!(*multihash.digest(), hash[..]);
     }
 
+    #[test]
+    fn can_get_multihash_from_sha2_256_hash() {
+        let hash = [0x42 as u8; 32];
+        let multihash = hash_to_multihash(&HashFunction::Sha2_256, hash.clone()).unwrap();
+        assert_eq!(multihash.code(), SHA2_256_HASH_FUNC_TYPE as u64);
+        assert_eq!(multihash.size(), 0x20);
+        assert_eq!(*multihash.digest(), hash[..]);
+    }
+
+    #[test]
+    fn can_verify_cid_of_untouched_content() {
+        let content = b"some block content".to_vec();
+        for hash_function in [HashFunction::Blake3, HashFunction::Sha2_256].iter() {
+            let cid = compute_cid(
+                Cursor::new(content.clone()),
+                &BlockMulticodec::Raw,
+                hash_function,
+            )
+            .unwrap();
+            assert_eq!(cid.hash().code(), hash_function.code());
+            verify_cid(Cursor::new(content.clone()), &cid).unwrap();
+        }
+    }
+
+    #[test]
+    fn can_compute_cid_compatible_with_ipfs() {
+        let cid = compute_cid(
+            Cursor::new(b"hello world".to_vec()),
+            &BlockMulticodec::Raw,
+            &HashFunction::Sha2_256,
+        )
+        .unwrap();
+        assert_eq!(
+            cid.to_string_of_base(DEFAULT_MULTIBASE).unwrap(),
+            "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e"
+        );
+    }
+
+    #[test]
+    fn cannot_verify_cid_of_tampered_content() {
+        let content = b"some block content".to_vec();
+        let cid = compute_cid(
+            Cursor::new(content),
+            &BlockMulticodec::Raw,
+            &HashFunction::default(),
+        )
+        .unwrap();
+        let tampered_content = b"some tampered content".to_vec();
+
+        let res = verify_cid(Cursor::new(tampered_content), &cid);
+
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("block content does not match its cid"));
+    }
+
     #[test]
     fn test_cid_to_object_path() {
         let cid =
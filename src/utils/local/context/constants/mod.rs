@@ -8,3 +8,6 @@ pub static CONNECTION_ID_SEPARATOR: &str = "→";
 pub static PORTATION_PREFIX_SEPARATOR: &str = ":";
 pub const PORTATION_FROM_HOLIUM_PREFIX: &str = "from";
 pub const PORTATION_TO_HOLIUM_PREFIX: &str = "to";
+/// Separator between a portation id and its name, for the named portations of a node
+/// (*eg* `from:transformation:my-transformation:json`).
+pub static PORTATION_NAME_SEPARATOR: &str = ":";
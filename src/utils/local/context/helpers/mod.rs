@@ -7,15 +7,20 @@ use thiserror;
 
 use crate::utils::errors::Error::{DbOperationFailed, NoDataForNodeInput};
 use crate::utils::local::context::constants::{
-    CONNECTION_ID_SEPARATOR, PORTATION_FROM_HOLIUM_PREFIX, PORTATION_PREFIX_SEPARATOR,
-    PORTATION_TO_HOLIUM_PREFIX, TYPED_NODE_NAME_SEPARATOR,
+    CONNECTION_ID_SEPARATOR, PORTATION_FROM_HOLIUM_PREFIX, PORTATION_NAME_SEPARATOR,
+    PORTATION_PREFIX_SEPARATOR, PORTATION_TO_HOLIUM_PREFIX, TYPED_NODE_NAME_SEPARATOR,
 };
 use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::jsonschema::{HoliumJsonSchema, HoliumJsonSchemaType};
 use crate::utils::local::models::data::HoliumCbor;
 use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::models::portation::Portation;
 use crate::utils::repo::ports::export_from_holium::export_from_holium;
+use crate::utils::repo::ports::helpers::get_node_json_schema;
 use crate::utils::repo::ports::import_to_holium::import_to_holium;
 
+use sk_cbor::cbor_array_vec;
+use sk_cbor::Value as CborValue;
 use std::str::{from_utf8, FromStr};
 
 #[derive(Debug, thiserror::Error)]
@@ -26,6 +31,8 @@ enum Error {
     InvalidConnectionId(String),
     #[error("invalid portation id: {0}")]
     InvalidPortationId(String),
+    #[error("a portation name cannot be empty nor contain the '{1}' character: {0}")]
+    InvalidPortationName(String, String),
     #[error("invalid node typed name: {0}")]
     InvalidNodeTypedName(String),
     #[error("no {0} node found with name: {1}")]
@@ -36,6 +43,8 @@ enum Error {
     PortationImportFailed(String),
     #[error("export from Holium via portation failed for node: {0}")]
     PortationExportFailed(String),
+    #[error("portations of node {0} import different values at index {1}")]
+    ConflictingPortationData(String, usize),
 }
 
 arg_enum! {
@@ -143,11 +152,27 @@ pub fn build_portation_id(direction: &PortationDirectionType, node_typed_name: &
     )
 }
 
+/// Build the id of a named portation (*eg* `from:transformation:my-transformation:json`), one of
+/// the several portations a node may have in a direction.
+pub fn build_named_portation_id(
+    direction: &PortationDirectionType,
+    node_typed_name: &str,
+    portation_name: &str,
+) -> String {
+    format!(
+        "{}{}{}",
+        build_portation_id(direction, node_typed_name),
+        PORTATION_NAME_SEPARATOR,
+        portation_name
+    )
+}
+
 /// Parse a portation id (*eg* `from:transformation:my-transformation`) and return a tuple holding the direction of the
 /// portation (from Holium or to Holium) and the typed name of the node (*eg* `transformation:my-transformation`).
+/// The name of a named portation is left out, see [parse_portation_name].
 pub fn parse_portation_id(portation_id: &str) -> Result<(PortationDirectionType, &str)> {
     // split string at first occurrence character
-    let (direction_str, node_typed_name) = portation_id
+    let (direction_str, named_node_typed_name) = portation_id
         .split_once(PORTATION_PREFIX_SEPARATOR)
         .ok_or(Error::InvalidPortationId(portation_id.to_string()))?;
     let direction = match direction_str {
@@ -155,77 +180,191 @@ pub fn parse_portation_id(portation_id: &str) -> Result<(PortationDirectionType,
         PORTATION_TO_HOLIUM_PREFIX => PortationDirectionType::toHolium,
         _ => return Err(Error::InvalidPortationId(portation_id.to_string()).into()),
     };
+    // leave out the name of the portation, following the separator of the node typed name
+    let node_typed_name = match named_node_typed_name
+        .match_indices(PORTATION_NAME_SEPARATOR)
+        .nth(1)
+    {
+        Some((index, _)) => &named_node_typed_name[..index],
+        None => named_node_typed_name,
+    };
     Ok((direction, node_typed_name))
 }
 
+/// Parse the name of a named portation (*eg* `json` in `from:transformation:my-transformation:json`)
+/// from its id, if any.
+pub fn parse_portation_name(portation_id: &str) -> Option<&str> {
+    portation_id.splitn(4, PORTATION_NAME_SEPARATOR).nth(3)
+}
+
+/// Validate the name of a named portation.
+pub fn validate_portation_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.contains(PORTATION_NAME_SEPARATOR) {
+        return Err(Error::InvalidPortationName(
+            name.to_string(),
+            PORTATION_NAME_SEPARATOR.to_string(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Get the portations of a node in a direction, ordered by id so that its unnamed portation comes
+/// first.
+pub fn get_node_portations<'a>(
+    repo_context: &'a RepositoryContext,
+    direction: &PortationDirectionType,
+    node_typed_name: &str,
+) -> Result<Vec<&'a Portation>> {
+    let mut portations = Vec::new();
+    for portation in repo_context.portations.values() {
+        let (portation_direction, portation_node_typed_name) = parse_portation_id(&portation.id)?;
+        if &portation_direction == direction && portation_node_typed_name == node_typed_name {
+            portations.push(portation);
+        }
+    }
+    portations.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(portations)
+}
+
 /// Helper method parsing a vectorized key name from the DB into its string version.
 pub fn db_key_to_str(k: sled::IVec) -> Result<String> {
     let name = from_utf8(k.as_ref())?;
     Ok(name.to_string())
 }
 
-/// Helper to get data for a node from a local context
+/// Helper to get data for a node from a local context. Data imported by several portations of the
+/// node is combined, see [combine_imported_data]. Partial portations are left out, as their data is
+/// only part of the node data.
 pub fn get_node_data(
     local_context: &LocalContext,
     repo_context: &RepositoryContext,
     node_typed_name: &str,
 ) -> Result<HoliumCbor> {
-    let portation = repo_context.portations.get(&build_portation_id(
+    let portations: Vec<&Portation> = get_node_portations(
+        repo_context,
         &PortationDirectionType::toHolium,
         node_typed_name,
-    ));
-
-    match portation {
-        Some(portation) => {
-            let mut portation_data: HoliumCbor = Vec::new();
-
-            import_to_holium(local_context, portation, &mut portation_data)
-                .context(Error::PortationImportFailed(node_typed_name.to_string()))?;
-
-            if portation_data.len() == 0usize {
-                return Err(Error::PortationDataInvalid(node_typed_name.to_string()).into());
-            }
+    )?
+    .into_iter()
+    .filter(|portation| portation.selector.is_none())
+    .collect();
 
-            Ok(portation_data)
-        }
-        None => Ok(local_context
+    if portations.is_empty() {
+        return Ok(local_context
             .data
             .get(node_typed_name)
             .context(DbOperationFailed)?
             .ok_or(NoDataForNodeInput(node_typed_name.to_string().into()))?
             .as_ref()
-            .to_vec()),
+            .to_vec());
+    }
+
+    let mut imports: Vec<HoliumCbor> = Vec::new();
+    for portation in portations {
+        let mut portation_data: HoliumCbor = Vec::new();
+
+        import_to_holium(local_context, portation, &mut portation_data)
+            .context(Error::PortationImportFailed(node_typed_name.to_string()))?;
+
+        if portation_data.len() == 0usize {
+            return Err(Error::PortationDataInvalid(node_typed_name.to_string()).into());
+        }
+
+        imports.push(portation_data);
     }
+
+    if imports.len() == 1 {
+        return Ok(imports.remove(0));
+    }
+    let json_schema = get_node_json_schema(
+        local_context,
+        node_typed_name,
+        &PortationDirectionType::toHolium,
+    )?;
+    combine_imported_data(&json_schema, node_typed_name, &imports)
+}
+
+/// Combine the data imported for a node by several portations, in their order. Elements of the
+/// root tuple holding arrays of items are concatenated, while other elements should be the same in
+/// all imports.
+fn combine_imported_data(
+    json_schema: &HoliumJsonSchema,
+    node_typed_name: &str,
+    imports: &[HoliumCbor],
+) -> Result<HoliumCbor> {
+    let invalid_data = || Error::PortationDataInvalid(node_typed_name.to_string());
+    let element_schemata = match json_schema.1.as_ref() {
+        HoliumJsonSchemaType::TupleArray(element_schemata) => element_schemata,
+        _ => return Err(invalid_data().into()),
+    };
+    let mut combined: Option<Vec<CborValue>> = None;
+    for import in imports.iter() {
+        let elements = match sk_cbor::read(import) {
+            Ok(CborValue::Array(elements)) if elements.len() == element_schemata.len() => elements,
+            _ => return Err(invalid_data().into()),
+        };
+        combined = Some(match combined {
+            None => elements,
+            Some(previous_elements) => previous_elements
+                .into_iter()
+                .zip(elements)
+                .zip(element_schemata.iter())
+                .enumerate()
+                .map(|(index, ((previous, element), schema))| {
+                    match (schema.1.as_ref(), previous, element) {
+                        (
+                            HoliumJsonSchemaType::ItemsArray(_),
+                            CborValue::Array(mut items),
+                            CborValue::Array(other_items),
+                        ) => {
+                            items.extend(other_items);
+                            Ok(CborValue::Array(items))
+                        }
+                        (_, previous, element) if previous == element => Ok(previous),
+                        _ => Err(Error::ConflictingPortationData(
+                            node_typed_name.to_string(),
+                            index,
+                        )
+                        .into()),
+                    }
+                })
+                .collect::<Result<Vec<CborValue>>>()?,
+        });
+    }
+    let mut buffer: HoliumCbor = Vec::new();
+    sk_cbor::write(cbor_array_vec!(combined.unwrap_or_default()), &mut buffer)
+        .map_err(|_| invalid_data())?;
+    Ok(buffer)
 }
 
-/// [store_node_output] will first try to export the data by using a portation and then store data in
-/// local context. If some data have been exported then the path to the file written is returned.
-pub fn store_node_output(
+/// [store_node_output] will store data in local context and then export it with every portation of
+/// the node. Portations used to write files are returned.
+pub fn store_node_output<'a>(
     local_context: &LocalContext,
-    repo_context: &RepositoryContext,
+    repo_context: &'a RepositoryContext,
     node_typed_name: &str,
     data: &HoliumCbor,
-) -> Result<Option<String>> {
+) -> Result<Vec<&'a Portation>> {
     // Write data in local context
     local_context
         .data
         .insert(node_typed_name, data.to_vec())
         .context(DbOperationFailed)?;
 
-    // Try to export with portation
-    let mut portation_file_path: Option<String> = None;
-    let res_portation = repo_context.portations.get(&build_portation_id(
+    // Export with portations
+    let portations = get_node_portations(
+        repo_context,
         &PortationDirectionType::fromHolium,
         node_typed_name,
-    ));
+    )?;
 
-    if let Some(portation) = res_portation {
+    for portation in portations.iter() {
         export_from_holium(local_context, portation, &mut std::io::Cursor::new(data))
             .context(Error::PortationExportFailed(node_typed_name.to_string()))?;
-        portation_file_path = Some(portation.file_path.clone());
     }
 
-    Ok(portation_file_path)
+    Ok(portations)
 }
 
 #[cfg(test)]
@@ -262,6 +401,78 @@ mod test {
         assert_eq!(node_typed_name, "source:source-name");
     }
 
+    // test the parsing of the id of a named portation
+    #[test]
+    fn test_parse_named_portation_id() {
+        let named_portation_id = build_named_portation_id(
+            &PortationDirectionType::toHolium,
+            "source:source-name",
+            "csv",
+        );
+        let (direction, node_typed_name) = parse_portation_id(&named_portation_id).unwrap();
+        assert_eq!(direction, PortationDirectionType::toHolium);
+        assert_eq!(node_typed_name, "source:source-name");
+        assert_eq!(parse_portation_name(&named_portation_id), Some("csv"));
+        assert_eq!(parse_portation_name("to:source:source-name"), None);
+    }
+
+    #[test]
+    fn cannot_validate_portation_name_with_separator() {
+        assert!(validate_portation_name("csv").is_ok());
+        assert!(validate_portation_name("").is_err());
+        assert!(validate_portation_name("a:b").is_err());
+    }
+
+    /*************************************
+     * Combination of imported data
+     *************************************/
+
+    #[test]
+    fn can_combine_imported_arrays_of_items() {
+        let json_schema =
+            crate::utils::local::helpers::jsonschema::parse_root_json_schema(&serde_json::json!({
+                "type": "array",
+                "prefixItems": [
+                    { "type": "string" },
+                    { "type": "array", "items": { "type": "number" } }
+                ]
+            }))
+            .unwrap();
+        let import = |label: &str, items: Vec<u64>| {
+            let mut buffer = Vec::new();
+            sk_cbor::write(
+                cbor_array_vec!(vec![
+                    sk_cbor::cbor_text!(label),
+                    cbor_array_vec!(items
+                        .into_iter()
+                        .map(|item| sk_cbor::cbor_unsigned!(item))
+                        .collect::<Vec<CborValue>>())
+                ]),
+                &mut buffer,
+            )
+            .unwrap();
+            buffer
+        };
+        // arrays of items are concatenated
+        let combined = combine_imported_data(
+            &json_schema,
+            "source:source",
+            &[import("a", vec![1, 2]), import("a", vec![3])],
+        )
+        .unwrap();
+        assert_eq!(
+            sk_cbor::read(&combined).unwrap(),
+            sk_cbor::read(&import("a", vec![1, 2, 3])).unwrap()
+        );
+        // other values should be the same
+        let res = combine_imported_data(
+            &json_schema,
+            "source:source",
+            &[import("a", vec![1]), import("b", vec![2])],
+        );
+        assert!(res.is_err());
+    }
+
     /*************************************
      * Validate node name
      *************************************/
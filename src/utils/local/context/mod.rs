@@ -29,6 +29,7 @@ pub struct LocalContext {
     pub shapers: sled::Tree,
     pub transformations: sled::Tree,
     pub connections: sled::Tree,
+    pub runs: sled::Tree,
 }
 
 impl LocalContext {
@@ -48,7 +49,7 @@ impl LocalContext {
     }
 
     /// Initialize a local context from a project root path.
-    fn from_root_path(root_path: &PathBuf) -> Result<Self> {
+    pub fn from_root_path(root_path: &PathBuf) -> Result<Self> {
         // create the holium root directory if it does not exist
         let holium_root_path = root_path.join(HOLIUM_DIR);
         if !holium_root_path.exists() {
@@ -76,6 +77,7 @@ impl LocalContext {
         transformations.set_merge_operator(models::transformation::merge);
         let connections: sled::Tree = db.open_tree(models::connection::TREE_NAME)?;
         connections.set_merge_operator(models::connection::merge);
+        let runs: sled::Tree = db.open_tree(models::run::TREE_NAME)?;
         // Return the context handler
         Ok(LocalContext {
             data,
@@ -85,6 +87,7 @@ impl LocalContext {
             shapers,
             transformations,
             connections,
+            runs,
         })
     }
 
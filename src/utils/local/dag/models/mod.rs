@@ -7,15 +7,21 @@ use crate::utils::errors::Error::{
 };
 use crate::utils::interplanetary::kinds::selector::Selector;
 use crate::utils::local::context::helpers::{
-    build_connection_id, build_node_typed_name, build_portation_id, db_key_to_str, get_node_data,
+    build_connection_id, build_node_typed_name, db_key_to_str, get_node_data, get_node_portations,
     parse_connection_id, parse_node_typed_name, store_node_output, NodeType,
     PortationDirectionType,
 };
 use crate::utils::local::context::LocalContext;
+use crate::utils::local::dag::provenance::{data_cid, portation_provenance};
+use crate::utils::local::helpers::selector::resolve_selector_fields;
 use crate::utils::local::models::connection::Connection;
 use crate::utils::local::models::data::HoliumCbor;
+use crate::utils::local::models::run::{now_millis, Run, RunConnection, RunNode};
 use crate::utils::local::models::transformation::Transformation;
 use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::models::portation::Portation;
+use crate::utils::repo::ports::helpers::{get_node_json_schema, validate_import_portations};
+use crate::utils::repo::ports::partial::partial_import_details;
 use crate::utils::run::runtime::Runtime;
 use anyhow::{anyhow, Context, Result};
 use bimap::BiMap;
@@ -24,6 +30,7 @@ use petgraph::graph::{DiGraph, EdgeReference, NodeIndex};
 use petgraph::prelude::EdgeRef;
 use petgraph::{algo, Direction};
 
+use serde_json::Value as JsonValue;
 use std::convert::TryFrom;
 
 #[derive(thiserror::Error, Debug)]
@@ -46,6 +53,10 @@ pub(crate) enum Error {
     TransformationRunFailed(String),
     #[error("could not collect connections details for node: {0}")]
     ConnectionsDetailsCollectionFailed(String),
+    #[error("could not resolve selector fields for connection: {0}")]
+    SelectorFieldsResolutionFailed(String),
+    #[error("could not import data with partial portation: {0}")]
+    PartialPortationImportFailed(String),
 }
 
 /// Structure holing information useful to the management of a transformation pipeline as a DAG
@@ -118,20 +129,23 @@ impl PipelineDag {
     }
 
     /// Check the a [PipelineDg] is healthy then runs the ordered list of node that it contains. It
-    /// returns a vector of tuples containing the node typed name and the writen file path of nodes
-    /// that had some export from Holium portation attached to them.
+    /// returns the provenance record of the run, holding for each node the cids of its data, the
+    /// connections and portations it used and the files written by its export from Holium
+    /// portations.
     pub fn run(
         runtime: &mut Runtime,
         local_context: &LocalContext,
         repo_context: &RepositoryContext,
-    ) -> Result<Vec<(String, String)>> {
+    ) -> Result<Run> {
+        let started_at = now_millis();
+        let hash_function = &repo_context.config.hash_function;
         // create pipeline dag
         let dag = PipelineDag::from_local_context(local_context)?;
         // check if the dag is healthy for export
         let ordered_node_list = dag.is_valid_pipeline()?;
 
-        // Initialize Vec to return with node typed name and portation file path
-        let mut node_portation_pairs: Vec<(String, String)> = Vec::new();
+        // Initialize Vec to return with the provenance of each node
+        let mut run_nodes: Vec<RunNode> = Vec::new();
 
         for node_index in ordered_node_list.into_iter() {
             let node_typed_name = dag.node_typed_name(&node_index)?;
@@ -139,13 +153,12 @@ impl PipelineDag {
 
             // Check that if the node input is connected to no head selector then there are either
             // a portation or some data in local context. Otherwise error.
-            if repo_context
-                .portations
-                .get(&build_portation_id(
-                    &PortationDirectionType::toHolium,
-                    &node_typed_name,
-                ))
-                .is_none()
+            if get_node_portations(
+                repo_context,
+                &PortationDirectionType::toHolium,
+                node_typed_name,
+            )?
+            .is_empty()
                 && local_context
                     .data
                     .get(node_typed_name)
@@ -161,23 +174,52 @@ impl PipelineDag {
                 return Err(NoDataForNodeInput(node_typed_name.to_string()).into());
             }
 
-            // Initialize data for head connected node
+            // Initialize data for head connected node, and its provenance
             let mut data: HoliumCbor = Vec::new();
+            let mut run_node = RunNode {
+                node_typed_name: node_typed_name.clone(),
+                ..RunNode::default()
+            };
+
+            // Partial portations import part of the node data, alongside connections, and can not
+            // be combined with full import portations
+            let import_portations = get_node_portations(
+                repo_context,
+                &PortationDirectionType::toHolium,
+                node_typed_name,
+            )?;
+            validate_import_portations(node_typed_name, &import_portations)?;
+            let partial_portations: Vec<(&Portation, &str)> = import_portations
+                .into_iter()
+                .filter_map(|portation| Some((portation, portation.selector.as_deref()?)))
+                .collect();
 
-            // If we have no incoming connection then we set selected data as either the one in local
-            // context or as the one coming from a portation
+            // If we have no incoming connection nor partial portation then we set selected data as
+            // either the one in local context or as the one coming from a portation
             if dag
                 .graph
                 .edges_directed(node_index, Direction::Incoming)
                 .collect::<Vec<EdgeReference<_, _>>>()
                 .len()
                 == 0usize
+                && partial_portations.is_empty()
             {
                 data = get_node_data(local_context, repo_context, node_typed_name)?;
+                for portation in get_node_portations(
+                    repo_context,
+                    &PortationDirectionType::toHolium,
+                    node_typed_name,
+                )? {
+                    run_node.imports.push(portation_provenance(
+                        local_context,
+                        portation,
+                        hash_function,
+                    )?);
+                }
             } else {
                 // Retrieve all information about connections so that we are able to form our selected
                 // data
-                let connections_details = dag
+                let mut connections_details = dag
                     .graph
                     .edges_directed(node_index, Direction::Incoming)
                     .map(|edge_reference| {
@@ -185,6 +227,39 @@ impl PipelineDag {
                     })
                     .collect::<Result<Vec<(String, HoliumCbor, Selector, Selector)>>>()
                     .context(Error::ConnectionsDetailsCollectionFailed(node_name.clone()))?;
+                run_node.connections = connections_details
+                    .iter()
+                    .map(|(id, _, tail_selector, head_selector)| RunConnection {
+                        id: id.clone(),
+                        tail_selector: JsonValue::from(tail_selector.clone()).to_string(),
+                        head_selector: JsonValue::from(head_selector.clone()).to_string(),
+                    })
+                    .collect();
+
+                // Add data imported by partial portations, written in the same way
+                if !partial_portations.is_empty() {
+                    let json_schema = get_node_json_schema(
+                        local_context,
+                        node_typed_name,
+                        &PortationDirectionType::toHolium,
+                    )?;
+                    for (portation, selector_literal) in partial_portations {
+                        connections_details.push(
+                            partial_import_details(
+                                local_context,
+                                portation,
+                                &json_schema,
+                                selector_literal,
+                            )
+                            .context(Error::PartialPortationImportFailed(portation.id.clone()))?,
+                        );
+                        run_node.imports.push(portation_provenance(
+                            local_context,
+                            portation,
+                            hash_function,
+                        )?);
+                    }
+                }
 
                 // Select data
                 let res_copy = data.copy_cbor(&connections_details);
@@ -203,6 +278,9 @@ impl PipelineDag {
                 };
             }
 
+            run_node.input_cid = data_cid(&data, hash_function)?;
+            run_node.input_size = data.len() as u64;
+
             // If transformation then execute bytecode otherwise do nothing
             match node_type {
                 NodeType::transformation => {
@@ -220,23 +298,40 @@ impl PipelineDag {
                         .instantiate(&decoded_transformation.bytecode)
                         .context(Error::TransformationInstantiationFailed(node_name.clone()))?;
 
+                    run_node.bytecode_cid =
+                        Some(data_cid(&decoded_transformation.bytecode, hash_function)?);
+                    run_node.handle = Some(decoded_transformation.handle.clone());
+
                     // run transformation
                     data = runtime
                         .run(&decoded_transformation.handle, &data)
                         .context(Error::TransformationRunFailed(node_name.clone()))?;
+                    run_node.metrics = Some(runtime.metrics().clone());
                 }
                 _ => {}
             }
 
-            // Store data in local context and execute *to-holium* portation if any.
-            let portation_file_path =
-                store_node_output(local_context, repo_context, node_typed_name, &data)?;
-            if let Some(file_path) = portation_file_path {
-                node_portation_pairs.push((node_typed_name.clone(), file_path.clone()));
+            run_node.output_cid = data_cid(&data, hash_function)?;
+            run_node.output_size = data.len() as u64;
+
+            // Store data in local context and execute *from-holium* portations if any.
+            for portation in store_node_output(local_context, repo_context, node_typed_name, &data)?
+            {
+                run_node.exports.push(portation_provenance(
+                    local_context,
+                    portation,
+                    hash_function,
+                )?);
             }
+            run_nodes.push(run_node);
         }
 
-        Ok(node_portation_pairs)
+        Ok(Run {
+            started_at,
+            finished_at: now_millis(),
+            nodes: run_nodes,
+            ..Run::default()
+        })
     }
 
     fn node_typed_name(&self, index: &NodeIndex) -> Result<&String> {
@@ -260,23 +355,73 @@ impl PipelineDag {
 
         // Build connection id
         let connection_id = build_connection_id(tail_typed_name, head_typed_name);
-        // Retrieve connection object
-        let encoded_connection = local_context
-            .connections
-            .get(&connection_id)
-            .context(DbOperationFailed)?
-            .ok_or(NoObjectForGivenKey(connection_id.clone()))?;
-        let decoded_connection: Connection = bincode::deserialize(&encoded_connection[..])
-            .ok()
-            .context(BinCodeDeserializeFailed)?;
-
-        // Build selectors
-        let tail_selector = Selector::try_from(decoded_connection.tail_selector.as_str())?;
-        let head_selector = Selector::try_from(decoded_connection.head_selector.as_str())?;
-
-        // Arrange data to fit head selector
-        let data_at_tail = get_node_data(local_context, repo_context, tail_typed_name)?;
-
-        Ok((connection_id, data_at_tail, tail_selector, head_selector))
+
+        connection_details(local_context, repo_context, &connection_id)
+    }
+}
+
+/// [connection_details] returns, for a connection id, a quartet containing the connection id, data
+/// at its tail, the selector for the said data and the selector to re organize data for the head
+/// node. Fields addressed in selectors are resolved against the schemas of the connected nodes.
+pub(crate) fn connection_details(
+    local_context: &LocalContext,
+    repo_context: &RepositoryContext,
+    connection_id: &str,
+) -> Result<(String, HoliumCbor, Selector, Selector)> {
+    let (tail_typed_name, head_typed_name) = parse_connection_id(connection_id)?;
+    // Retrieve connection object
+    let encoded_connection = local_context
+        .connections
+        .get(connection_id)
+        .context(DbOperationFailed)?
+        .ok_or(NoObjectForGivenKey(connection_id.to_string()))?;
+    let decoded_connection: Connection = bincode::deserialize(&encoded_connection[..])
+        .ok()
+        .context(BinCodeDeserializeFailed)?;
+
+    // Build selectors
+    let tail_selector = node_selector(
+        local_context,
+        tail_typed_name,
+        &PortationDirectionType::fromHolium,
+        &decoded_connection.tail_selector,
+    )
+    .context(Error::SelectorFieldsResolutionFailed(
+        connection_id.to_string(),
+    ))?;
+    let head_selector = node_selector(
+        local_context,
+        head_typed_name,
+        &PortationDirectionType::toHolium,
+        &decoded_connection.head_selector,
+    )
+    .context(Error::SelectorFieldsResolutionFailed(
+        connection_id.to_string(),
+    ))?;
+
+    // Arrange data to fit head selector
+    let data_at_tail = get_node_data(local_context, repo_context, tail_typed_name)?;
+
+    Ok((
+        connection_id.to_string(),
+        data_at_tail,
+        tail_selector,
+        head_selector,
+    ))
+}
+
+/// Build a selector applied to the data leaving (*from Holium*) or entering (*to Holium*) a node,
+/// resolving fields against the node schema if needed.
+pub(crate) fn node_selector(
+    local_context: &LocalContext,
+    node_typed_name: &str,
+    direction: &PortationDirectionType,
+    selector_literal: &str,
+) -> Result<Selector> {
+    let selector = Selector::try_from(selector_literal)?;
+    if !selector.has_fields() {
+        return Ok(selector);
     }
+    let json_schema = get_node_json_schema(local_context, node_typed_name, direction)?;
+    resolve_selector_fields(&selector, &json_schema)
 }
@@ -0,0 +1,198 @@
+//! Provenance records of pipeline runs, tracing the data, bytecode and ported files of each node.
+//!
+//! Cids are computed on raw content, with the hash function of the project, so that any exported
+//! file can be matched against the record of the run that produced it.
+//!
+//! Records are stored as JSON, so that fields added to them later can be given default values.
+
+use crate::utils::errors::Error::{DbOperationFailed, NoObjectForGivenKey};
+use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
+use crate::utils::interplanetary::fs::traits::as_ip_block::AsInterplanetaryBlock;
+use crate::utils::interplanetary::kinds::pipeline::Pipeline;
+use crate::utils::interplanetary::kinds::run_record::RunRecord;
+use crate::utils::interplanetary::multiformats::{compute_cid, HashFunction, DEFAULT_MULTIBASE};
+use crate::utils::local::context::helpers::db_key_to_str;
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::export::export_project_with_runs;
+use crate::utils::local::import::find_pipeline_blocks;
+use crate::utils::local::models::run::{Run, RunPortation};
+use crate::utils::repo::models::portation::Portation;
+
+use anyhow::{Context, Result};
+use cid::Cid;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read, Seek};
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to compute cid of ported file: {0}")]
+    FailedToHashPortedFile(String),
+    #[error("failed to store provenance record of the run")]
+    FailedToStoreRun,
+    #[error("failed to encode provenance record of the run")]
+    FailedToEncodeRun,
+    #[error("failed to decode provenance record of the run")]
+    FailedToDecodeRun,
+    #[error("failed to replace previously exported pipeline")]
+    CannotReplacePipeline,
+}
+
+/// Compute the cid of some raw content, and return it as a string.
+fn content_cid<T: Read + Seek>(content: T, hash_function: &HashFunction) -> Result<String> {
+    let cid = compute_cid(content, &BlockMulticodec::Raw, hash_function)?;
+    Ok(cid.to_string_of_base(DEFAULT_MULTIBASE)?)
+}
+
+/// Compute the cid of the data of a node, or of the bytecode of a transformation.
+pub fn data_cid(data: &[u8], hash_function: &HashFunction) -> Result<String> {
+    content_cid(Cursor::new(data), hash_function)
+}
+
+/// Record a portation used during a run, along with the cid of the ported file. Directory
+/// portations port several files and have no cid.
+pub fn portation_provenance(
+    local_context: &LocalContext,
+    portation: &Portation,
+    hash_function: &HashFunction,
+) -> Result<RunPortation> {
+    let file_cid = match portation.file_pattern {
+        Some(_) => None,
+        None => {
+            let path = local_context.root_path.join(&portation.file_path);
+            let file =
+                File::open(&path).context(Error::FailedToHashPortedFile(portation.id.clone()))?;
+            Some(
+                content_cid(BufReader::new(file), hash_function)
+                    .context(Error::FailedToHashPortedFile(portation.id.clone()))?,
+            )
+        }
+    };
+    Ok(RunPortation {
+        id: portation.id.clone(),
+        file_path: portation.file_path.clone(),
+        selector: portation.selector.clone(),
+        file_cid,
+    })
+}
+
+/// Decode a provenance record stored in the local area.
+fn decode_run(encoded: &[u8]) -> Result<Run> {
+    serde_json::from_slice(encoded).context(Error::FailedToDecodeRun)
+}
+
+/// Store the provenance record of a run in the local area, generating its id if needed.
+pub fn store_run(local_context: &LocalContext, run: &mut Run) -> Result<()> {
+    if run.id.is_empty() {
+        run.id = local_context
+            .db
+            .generate_id()
+            .context(Error::FailedToStoreRun)?
+            .to_string();
+    }
+    let encoded = serde_json::to_vec(run).context(Error::FailedToEncodeRun)?;
+    local_context
+        .runs
+        .insert(&run.id, encoded)
+        .context(DbOperationFailed)?;
+    Ok(())
+}
+
+/// Get the provenance record of a run from the local area.
+pub fn get_run(local_context: &LocalContext, id: &str) -> Result<Run> {
+    let encoded = local_context
+        .runs
+        .get(id)
+        .context(DbOperationFailed)?
+        .ok_or_else(|| NoObjectForGivenKey(id.to_string()))?;
+    let mut decoded = decode_run(&encoded[..])?;
+    decoded.id = id.to_string();
+    Ok(decoded)
+}
+
+/// List the provenance records of all runs stored in the local area, from the oldest to the
+/// newest.
+pub fn list_runs(local_context: &LocalContext) -> Result<Vec<Run>> {
+    let mut runs = local_context
+        .runs
+        .iter()
+        .map(|o| -> Result<Run> {
+            let (id_vec, encoded) = o.context(DbOperationFailed)?;
+            let id = db_key_to_str(id_vec)?;
+            let mut decoded = decode_run(&encoded[..])?;
+            decoded.id = id;
+            Ok(decoded)
+        })
+        .collect::<Result<Vec<Run>>>()?;
+    // ids are generated in increasing order, but stored as strings
+    runs.sort_by_key(|run| run.id.parse::<u64>().unwrap_or_default());
+    Ok(runs)
+}
+
+/// Export a block holding the provenance record of a run to the interplanetary area, along with
+/// the project, its pipeline linking to the record. Blocks already in the area are kept, and runs
+/// linked from previously exported pipelines stay linked from the new one, which replaces them.
+/// Cids of both blocks are saved in the record, and the cid of the run block is returned.
+pub fn export_run(
+    local_context: &LocalContext,
+    ip_context: &InterplanetaryContext,
+    run: &mut Run,
+) -> Result<Cid> {
+    let block: sk_cbor::Value = RunRecord::new(run.clone()).into();
+    let block_cid = block.write_to_ip_area(ip_context)?;
+    // gather runs linked from previously exported pipelines
+    let previous_pipeline_cids = find_pipeline_blocks(ip_context)?;
+    let mut runs = Vec::new();
+    for cid in previous_pipeline_cids.iter() {
+        let pipeline_block = sk_cbor::Value::read_from_ip_area(cid, ip_context)?;
+        let pipeline = Pipeline::try_from(*pipeline_block)?;
+        runs.extend(pipeline.runs);
+    }
+    if !runs.contains(&block_cid) {
+        runs.push(block_cid);
+    }
+    // export the project, and replace previous pipelines with the new one
+    let pipeline_cid = export_project_with_runs(local_context, ip_context, runs)?;
+    for cid in previous_pipeline_cids
+        .iter()
+        .filter(|cid| **cid != pipeline_cid)
+    {
+        ip_context
+            .block_store
+            .delete(cid)
+            .context(Error::CannotReplacePipeline)?;
+    }
+    run.pipeline_cid = Some(pipeline_cid.to_string_of_base(DEFAULT_MULTIBASE)?);
+    run.block_cid = Some(block_cid.to_string_of_base(DEFAULT_MULTIBASE)?);
+    store_run(local_context, run)?;
+    Ok(block_cid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::local::models::run::RunNode;
+
+    #[test]
+    fn can_store_and_read_run_records() {
+        let (local_context, _tmp_dir) = LocalContext::new_tmp().unwrap();
+        let mut run = Run {
+            started_at: 1,
+            finished_at: 2,
+            nodes: vec![RunNode {
+                node_typed_name: "source:source".to_string(),
+                output_size: 42,
+                ..RunNode::default()
+            }],
+            ..Run::default()
+        };
+        store_run(&local_context, &mut run).unwrap();
+
+        let runs = list_runs(&local_context).unwrap();
+
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].id, run.id);
+        assert_eq!(runs[0].nodes[0].output_size, 42);
+    }
+}
@@ -37,6 +37,16 @@ pub type VerticesKeyMap = HashMap<String, u64>;
 pub fn export_project(
     local_context: &LocalContext,
     ip_context: &InterplanetaryContext,
+) -> Result<Cid> {
+    export_project_with_runs(local_context, ip_context, vec![])
+}
+
+/// Export the project, the pipeline linking to the provenance records of some of its runs, already
+/// stored in the interplanetary area.
+pub fn export_project_with_runs(
+    local_context: &LocalContext,
+    ip_context: &InterplanetaryContext,
+    runs: Vec<Cid>,
 ) -> Result<Cid> {
     // initialize an object to store the content of the graph nodes
     let mut vertices_content = VerticesContentMap::new();
@@ -49,7 +59,13 @@ pub fn export_project(
     // export connections
     let (edges, vertices_key_mapping) = export_connections(&local_context, &ip_context)?;
     // export the pipeline itself, and return its cid
-    export_pipeline(&ip_context, &vertices_key_mapping, &vertices_content, edges)
+    export_pipeline(
+        &ip_context,
+        &vertices_key_mapping,
+        &vertices_content,
+        edges,
+        runs,
+    )
 }
 
 fn export_dry_transformations(
@@ -66,7 +82,7 @@ fn export_dry_transformations(
             .context(BinCodeDeserializeFailed)?;
         // store the bytecode
         let module_bytecode = ModuleBytecode::new(decoded.bytecode);
-        let module_bytecode_cid = module_bytecode.write_to_ip_area(&ip_context)?;
+        let module_bytecode_cid = module_bytecode.write_chunked_to_ip_area(&ip_context)?;
         // store the module bytecode envelope
         let module_bytecode_envelope = ModuleBytecodeEnvelope::new(module_bytecode_cid);
         let module_bytecode_envelope_cid =
@@ -188,9 +204,10 @@ fn export_pipeline(
     vertices_key_mapping: &VerticesKeyMap,
     vertices_content: &VerticesContentMap,
     edges: Vec<PipelineEdge>,
+    runs: Vec<Cid>,
 ) -> Result<Cid> {
     // create the pipeline object
-    let pipeline = Pipeline::new(&vertices_key_mapping, &vertices_content, edges)?;
+    let pipeline = Pipeline::new(&vertices_key_mapping, &vertices_content, edges, runs)?;
     // store it and return its cid
     Value::from(pipeline).write_to_ip_area(&ip_context)
 }
@@ -0,0 +1,82 @@
+//! Helpers to read the values of command line arguments holding literals, such as JSON Schemata
+//! or selectors, that may also be given through files or the standard input.
+
+use crate::utils::repo::helpers::{get_root_path, to_relative_path_to_project_root};
+use anyhow::{Context, Result};
+use clap::ArgMatches;
+use std::fs;
+use std::io::{self, Read};
+
+/// Prefix of literal arguments pointing to a file holding the actual literal.
+const FILE_ARGUMENT_PREFIX: char = '@';
+/// Literal argument standing for the standard input.
+const STDIN_ARGUMENT: &str = "-";
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to read literal from file: {0}")]
+    FailedToReadFile(String),
+    #[error("failed to read literal from the standard input")]
+    FailedToReadStdin,
+    #[error("standard input may only be used by one argument at a time: {0}")]
+    StdinUsedMoreThanOnce(String),
+}
+
+/// Read the value of a literal argument, given either inline, as `@path/to/file` with a path that
+/// should be part of the project, or as `-` to read it from the standard input.
+pub fn read_literal_argument(argument: &str) -> Result<String> {
+    if argument == STDIN_ARGUMENT {
+        let mut literal = String::new();
+        io::stdin()
+            .read_to_string(&mut literal)
+            .context(Error::FailedToReadStdin)?;
+        return Ok(literal.trim_end().to_string());
+    }
+    if let Some(path_os_str) = argument.strip_prefix(FILE_ARGUMENT_PREFIX) {
+        let relative_path = to_relative_path_to_project_root(path_os_str)?;
+        let literal = fs::read_to_string(get_root_path()?.join(&relative_path))
+            .context(Error::FailedToReadFile(relative_path))?;
+        return Ok(literal.trim_end().to_string());
+    }
+    Ok(argument.to_string())
+}
+
+/// Read the values of optional literal arguments from command line matches, checking that at most
+/// one of them is read from the standard input.
+pub fn read_literal_arguments<const N: usize>(
+    matches: &ArgMatches,
+    names: [&str; N],
+) -> Result<[Option<String>; N]> {
+    let stdin_names: Vec<&str> = names
+        .iter()
+        .filter(|name| matches.value_of(name) == Some(STDIN_ARGUMENT))
+        .cloned()
+        .collect();
+    if stdin_names.len() > 1 {
+        return Err(Error::StdinUsedMoreThanOnce(stdin_names.join(", ")).into());
+    }
+    let mut literals = [(); N].map(|_| None);
+    for (literal, name) in literals.iter_mut().zip(names.iter()) {
+        *literal = matches
+            .value_of(name)
+            .map(read_literal_argument)
+            .transpose()?;
+    }
+    Ok(literals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inline_literal_is_kept_as_is() {
+        let literal = "{\"type\": \"string\"}";
+        assert_eq!(read_literal_argument(literal).unwrap(), literal);
+    }
+
+    #[test]
+    fn file_literal_outside_of_a_project_is_rejected() {
+        assert!(read_literal_argument("@/schema.json").is_err());
+    }
+}
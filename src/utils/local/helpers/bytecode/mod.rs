@@ -7,9 +7,10 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 use thiserror::Error;
+use wasmer::{Module, Store};
 
 use crate::utils::local::helpers::bytecode::Error::{
-    FailedToReadImportFile, MissingWasmMagicNumber,
+    FailedToReadImportFile, InvalidWasmModule, MissingFunctionExport, MissingWasmMagicNumber,
 };
 
 #[derive(Error, Debug)]
@@ -21,6 +22,12 @@ pub enum Error {
     /// Thrown when WebAssembly 4-byte magic number could not be found in expected bytecode
     #[error("invalid WebAssembly bytecode (4-byte magic number could not be found)")]
     MissingWasmMagicNumber,
+    /// Thrown when some bytecode can not be compiled into a Wasm module
+    #[error("invalid WebAssembly module")]
+    InvalidWasmModule,
+    /// Thrown when a Wasm module does not export a function expected to be used as a handle
+    #[error("no function exported by the Wasm module with handle: {0}")]
+    MissingFunctionExport(String),
 }
 
 /// WebAssembly 4-byte magic number
@@ -51,6 +58,28 @@ pub fn read_all_wasm_module(path: &PathBuf) -> Result<Vec<u8>> {
     Ok(buffer)
 }
 
+/// Validate that some bytecode starts with the Wasm magic number
+pub fn validate_wasm_magic_number(bytecode: &[u8]) -> Result<()> {
+    if !bytecode.starts_with(WASM_MAGIC_NUMBER) {
+        return Err(MissingWasmMagicNumber.into());
+    }
+    Ok(())
+}
+
+/// Validate that a Wasm module exports a function with a given handle
+pub fn validate_wasm_function_export(bytecode: &[u8], handle: &str) -> Result<()> {
+    let store = Store::default();
+    let module = Module::new(&store, bytecode).context(InvalidWasmModule)?;
+    if !module
+        .exports()
+        .functions()
+        .any(|function| function.name() == handle)
+    {
+        return Err(MissingFunctionExport(handle.to_string()).into());
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -107,4 +136,26 @@ mod test {
 
         assert_eq!(expected_data, wasm_module);
     }
+
+    #[test]
+    fn can_validate_function_export() {
+        // fetch wasm module
+        let wasm_module = read_all_wasm_module(&wasm_path("import.wasm")).unwrap();
+
+        assert!(validate_wasm_function_export(&wasm_module, "helloWorld").is_ok());
+    }
+
+    #[test]
+    fn cannot_validate_missing_function_export() {
+        // fetch wasm module
+        let wasm_module = read_all_wasm_module(&wasm_path("import.wasm")).unwrap();
+
+        let res = validate_wasm_function_export(&wasm_module, "non_existent_handle");
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("no function exported by the Wasm module"));
+    }
 }
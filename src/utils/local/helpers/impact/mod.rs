@@ -0,0 +1,282 @@
+//! Helper methods assessing the impact of the update of a transformation on the rest of the
+//! pipeline.
+
+use std::convert::TryFrom;
+use std::io::Cursor;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::utils::errors::Error::{
+    BinCodeDeserializeFailed, DbOperationFailed, NoObjectForGivenKey,
+};
+use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
+use crate::utils::interplanetary::kinds::selector::Selector;
+use crate::utils::interplanetary::multiformats::compute_cid;
+use crate::utils::local::context::helpers::{
+    build_node_typed_name, db_key_to_str, parse_connection_id, parse_portation_id, NodeType,
+    PortationDirectionType,
+};
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::jsonschema::{parse_root_json_schema, HoliumJsonSchema};
+use crate::utils::local::helpers::selector::{resolve_selector_fields, validate_selector_indices};
+use crate::utils::local::models::connection::Connection;
+use crate::utils::local::models::transformation::Transformation;
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::ports::helpers::get_node_json_schema;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("update of transformation {0} would break {1} connection(s)")]
+    BreakingTransformationUpdate(String, usize),
+}
+
+/// Change of a single field of a transformation
+#[derive(Serialize, Debug, PartialEq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+/// Connection or portation attached to an updated transformation, on a side that has changed.
+/// `issue` is set when the object would not be valid anymore after the update.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct ImpactedLink {
+    pub object_type: &'static str,
+    pub id: String,
+    pub issue: Option<String>,
+}
+
+/// Report of the changes brought by the update of a transformation and of their impact
+#[derive(Serialize, Debug, PartialEq)]
+pub struct TransformationUpdateReport {
+    pub changes: Vec<FieldChange>,
+    pub impacted: Vec<ImpactedLink>,
+}
+
+impl TransformationUpdateReport {
+    /// Return an error if some impacted connection would not be valid anymore after the update.
+    pub fn validate(&self, transformation_name: &str) -> Result<()> {
+        let nb_issues = self
+            .impacted
+            .iter()
+            .filter(|link| link.issue.is_some())
+            .count();
+        if nb_issues > 0 {
+            return Err(Error::BreakingTransformationUpdate(
+                transformation_name.to_string(),
+                nb_issues,
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Diff two versions of a transformation and list connections and portations impacted by the
+/// changes. Selectors of impacted connections are checked against the JSON schemata of both their
+/// tail and head nodes, the new ones being used for the updated transformation.
+pub fn transformation_update_report(
+    local_context: &LocalContext,
+    repo_context: &RepositoryContext,
+    old: &Transformation,
+    new: &Transformation,
+) -> Result<TransformationUpdateReport> {
+    // diff fields
+    let mut changes = Vec::new();
+    let old_bytecode_cid = bytecode_cid(repo_context, &old.bytecode)?;
+    let new_bytecode_cid = bytecode_cid(repo_context, &new.bytecode)?;
+    if old_bytecode_cid != new_bytecode_cid {
+        changes.push(FieldChange {
+            field: "bytecode",
+            old: old_bytecode_cid,
+            new: new_bytecode_cid,
+        });
+    }
+    if old.handle != new.handle {
+        changes.push(FieldChange {
+            field: "handle",
+            old: old.handle.clone(),
+            new: new.handle.clone(),
+        });
+    }
+    let is_schema_in_changed = !same_json_literals(&old.json_schema_in, &new.json_schema_in);
+    if is_schema_in_changed {
+        changes.push(FieldChange {
+            field: "json_schema_in",
+            old: old.json_schema_in.clone(),
+            new: new.json_schema_in.clone(),
+        });
+    }
+    let is_schema_out_changed = !same_json_literals(&old.json_schema_out, &new.json_schema_out);
+    if is_schema_out_changed {
+        changes.push(FieldChange {
+            field: "json_schema_out",
+            old: old.json_schema_out.clone(),
+            new: new.json_schema_out.clone(),
+        });
+    }
+    // data leaving the transformation may change as soon as its function does
+    let is_input_changed = is_schema_in_changed;
+    let is_output_changed = changes
+        .iter()
+        .any(|change| change.field != "json_schema_in");
+    // list impacted connections
+    let typed_name = build_node_typed_name(&NodeType::transformation, &new.name);
+    let mut impacted = Vec::new();
+    for k in local_context.connections.iter().keys() {
+        let id = db_key_to_str(k.context(DbOperationFailed)?)?;
+        let (tail_typed_name, head_typed_name) = parse_connection_id(&id)?;
+        let is_impacted = (head_typed_name == typed_name && is_input_changed)
+            || (tail_typed_name == typed_name && is_output_changed);
+        if !is_impacted {
+            continue;
+        }
+        let encoded = local_context
+            .connections
+            .get(&id)
+            .context(DbOperationFailed)?
+            .ok_or(NoObjectForGivenKey(id.clone()))?;
+        let connection: Connection = bincode::deserialize(&encoded[..])
+            .ok()
+            .context(BinCodeDeserializeFailed)?;
+        // get the schemata of data leaving the tail node and entering the head node
+        let tail_json_schema = if tail_typed_name == typed_name {
+            parse_json_schema_literal(&new.json_schema_out)
+        } else {
+            get_node_json_schema(
+                local_context,
+                tail_typed_name,
+                &PortationDirectionType::fromHolium,
+            )
+        };
+        let head_json_schema = if head_typed_name == typed_name {
+            parse_json_schema_literal(&new.json_schema_in)
+        } else {
+            get_node_json_schema(
+                local_context,
+                head_typed_name,
+                &PortationDirectionType::toHolium,
+            )
+        };
+        let issue = selector_issue(&connection.tail_selector, tail_json_schema)
+            .map(|issue| format!("tail selector: {}", issue))
+            .or_else(|| {
+                selector_issue(&connection.head_selector, head_json_schema)
+                    .map(|issue| format!("head selector: {}", issue))
+            });
+        impacted.push(ImpactedLink {
+            object_type: "connection",
+            id,
+            issue,
+        });
+    }
+    // list impacted portations
+    let mut portation_ids: Vec<&String> = repo_context
+        .portations
+        .values()
+        .map(|portation| &portation.id)
+        .collect();
+    portation_ids.sort();
+    for id in portation_ids {
+        let (direction, node_typed_name) = parse_portation_id(id)?;
+        let is_impacted = node_typed_name == typed_name
+            && match direction {
+                PortationDirectionType::toHolium => is_input_changed,
+                PortationDirectionType::fromHolium => is_output_changed,
+            };
+        if is_impacted {
+            impacted.push(ImpactedLink {
+                object_type: "portation",
+                id: id.clone(),
+                issue: None,
+            });
+        }
+    }
+    Ok(TransformationUpdateReport { changes, impacted })
+}
+
+/// Compute the CID of some raw bytecode, as a string
+fn bytecode_cid(repo_context: &RepositoryContext, bytecode: &[u8]) -> Result<String> {
+    let cid = compute_cid(
+        Cursor::new(bytecode),
+        &BlockMulticodec::Raw,
+        &repo_context.config.hash_function,
+    )?;
+    Ok(cid.to_string())
+}
+
+/// Compare two JSON literals, regardless of their formatting
+fn same_json_literals(a: &str, b: &str) -> bool {
+    match (
+        serde_json::from_str::<Value>(a),
+        serde_json::from_str::<Value>(b),
+    ) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Parse a JSON schema literal into a HoliumJsonSchema
+fn parse_json_schema_literal(literal: &str) -> Result<HoliumJsonSchema> {
+    parse_root_json_schema(&serde_json::from_str(literal)?)
+}
+
+/// Check that a selector can still be applied on data validated by a JSON schema, its fields being
+/// resolved and its indices addressing existing elements, returning a description of the issue
+/// otherwise.
+fn selector_issue(selector_literal: &str, json_schema: Result<HoliumJsonSchema>) -> Option<String> {
+    let validation = || -> Result<()> {
+        let json_schema = json_schema?;
+        let selector = Selector::try_from(selector_literal)?;
+        let selector = resolve_selector_fields(&selector, &json_schema)?;
+        validate_selector_indices(&selector, &json_schema)
+    };
+    validation().err().map(|err| format!("{:#}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_literals_are_compared_regardless_of_formatting() {
+        assert!(same_json_literals(
+            r#"{ "type" : "array" }"#,
+            r#"{"type":"array"}"#
+        ));
+        assert!(!same_json_literals(
+            r#"{ "type" : "array" }"#,
+            r#"{ "type" : "object" }"#
+        ));
+    }
+
+    #[test]
+    fn selector_with_unknown_field_raises_an_issue() {
+        let json_schema = r#"{ "type" : "array", "prefixItems" : [ {"type" : "string"} ] }"#;
+        assert!(selector_issue(r#"{".": {}}"#, parse_json_schema_literal(json_schema)).is_none());
+        assert!(selector_issue(
+            r#"{"i": {"i": 0, ">": {"f": {"f>": {"name": {".": {}}}}}}}"#,
+            parse_json_schema_literal(json_schema)
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn selector_out_of_shrunk_tuple_raises_an_issue() {
+        let old_json_schema =
+            r#"{ "type" : "array", "prefixItems" : [ {"type" : "string"}, {"type" : "string"} ] }"#;
+        let new_json_schema = r#"{ "type" : "array", "prefixItems" : [ {"type" : "string"} ] }"#;
+        for selector in [
+            r#"{"i": {"i": 1, ">": {".": {}}}}"#,
+            r#"{"r": {"^": 0, "$": 2, ">": {".": {}}}}"#,
+        ]
+        .iter()
+        {
+            assert!(selector_issue(selector, parse_json_schema_literal(old_json_schema)).is_none());
+            assert!(selector_issue(selector, parse_json_schema_literal(new_json_schema)).is_some());
+        }
+    }
+}
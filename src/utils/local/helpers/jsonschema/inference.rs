@@ -0,0 +1,414 @@
+//! Infer the JSON schema of pipeline nodes from sample data files.
+//!
+//! JSON and CBOR samples should hold an array at their root, each of its elements being described
+//! by an element of the root tuple, so that the sample itself can be ported to the node. Arrays
+//! nested in the sample are arrays of items, whose schema is shared by all of them, objects keep
+//! their properties in order of appearance and only require the ones present in all of them, and
+//! byte strings are described as base64 encoded strings.
+//!
+//! CSV samples are described by a tuple holding a single array of objects, one per row, whose
+//! properties are named after the header row. Columns are typed from all their non-empty cells, as
+//! integers, booleans or strings otherwise, and empty cells are left out of their row.
+//!
+//! As HoliumCBOR data does not handle floats yet, samples holding decimal numbers are rejected.
+
+use crate::utils::local::helpers::jsonschema::validate_pipeline_node_json_schema;
+use crate::utils::repo::models::portation::PortationFileFormat;
+use crate::utils::repo::ports::formats::value::PortableValue;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::io::Read;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("json schemas can only be inferred from json, csv or cbor samples, not: {0}")]
+    UnsupportedSampleFormat(String),
+    #[error("failed to read sample data")]
+    FailedToReadSample,
+    #[error(
+        "sample data should hold an array at its root, holding the elements of the node tuple"
+    )]
+    NoArrayAtSampleRoot,
+    #[error("inconsistent types in sample data at: {0}")]
+    InconsistentTypes(String),
+    #[error("cannot infer the type of items of arrays that are always empty, at: {0}")]
+    NoSampleItems(String),
+    #[error("float values are not handled in HoliumCBOR data, found in sample data at: {0}")]
+    FloatInSample(String),
+    #[error("inferred json schema is not valid for a pipeline node")]
+    InvalidInferredJsonSchema,
+}
+
+/// Formats of the sample files JSON schemas can be inferred from
+pub const SAMPLE_FORMATS: [&str; 3] = ["json", "csv", "cbor"];
+
+/// Type inferred for a value, or for all the values found at the same place in a sample
+#[derive(Debug, PartialEq)]
+enum InferredType {
+    /// Type of the items of arrays that are empty in the sample
+    Unknown,
+    Null,
+    Boolean,
+    Number,
+    Text,
+    Bytes,
+    Array(Box<InferredType>),
+    /// Properties of objects, with their type and whether they are present in all objects
+    Object(Vec<(String, InferredType, bool)>),
+}
+
+impl InferredType {
+    /// Infer the type of a value.
+    fn of(value: &PortableValue, path: &str) -> Result<Self> {
+        Ok(match value {
+            PortableValue::Null => InferredType::Null,
+            PortableValue::Boolean(_) => InferredType::Boolean,
+            PortableValue::Unsigned(_) | PortableValue::Negative(_) => InferredType::Number,
+            PortableValue::Float(_) => return Err(Error::FloatInSample(path.to_string()).into()),
+            PortableValue::Text(_) => InferredType::Text,
+            PortableValue::Bytes(_) => InferredType::Bytes,
+            PortableValue::Array(values) => {
+                let mut items_type = InferredType::Unknown;
+                for item in values.iter() {
+                    let item_path = format!("{}/*", path);
+                    items_type =
+                        items_type.merge(InferredType::of(item, &item_path)?, &item_path)?;
+                }
+                InferredType::Array(Box::new(items_type))
+            }
+            PortableValue::Map(entries) => InferredType::Object(
+                entries
+                    .iter()
+                    .map(|(key, value)| {
+                        Ok((
+                            key.clone(),
+                            InferredType::of(value, &format!("{}/{}", path, key))?,
+                            true,
+                        ))
+                    })
+                    .collect::<Result<Vec<(String, InferredType, bool)>>>()?,
+            ),
+        })
+    }
+
+    /// Merge the types of two values found at the same place in a sample. Objects hold the
+    /// properties of both, only required if required in both, and null values take the type of
+    /// other values.
+    fn merge(self, other: InferredType, path: &str) -> Result<Self> {
+        Ok(match (self, other) {
+            (InferredType::Unknown, other) | (other, InferredType::Unknown) => other,
+            (InferredType::Null, other) | (other, InferredType::Null) => other,
+            (InferredType::Array(items_type), InferredType::Array(other_items_type)) => {
+                InferredType::Array(Box::new(
+                    items_type.merge(*other_items_type, &format!("{}/*", path))?,
+                ))
+            }
+            (InferredType::Object(mut properties), InferredType::Object(other_properties)) => {
+                // properties missing from the other object are not required anymore
+                for (key, _, is_required) in properties.iter_mut() {
+                    *is_required &= other_properties.iter().any(|(k, _, _)| k == key);
+                }
+                for (key, other_type, other_is_required) in other_properties {
+                    match properties.iter().position(|(k, _, _)| k == &key) {
+                        Some(index) => {
+                            let (_, property_type, is_required) = properties.remove(index);
+                            let property_path = format!("{}/{}", path, key);
+                            properties.insert(
+                                index,
+                                (
+                                    key,
+                                    property_type.merge(other_type, &property_path)?,
+                                    is_required && other_is_required,
+                                ),
+                            );
+                        }
+                        None => properties.push((key, other_type, false)),
+                    }
+                }
+                InferredType::Object(properties)
+            }
+            (inferred_type, other) if inferred_type == other => inferred_type,
+            _ => return Err(Error::InconsistentTypes(path.to_string()).into()),
+        })
+    }
+
+    /// Build the JSON schema describing values of the inferred type.
+    fn to_json_schema(&self, path: &str) -> Result<Value> {
+        Ok(match self {
+            InferredType::Unknown => return Err(Error::NoSampleItems(path.to_string()).into()),
+            InferredType::Null => json!({ "type": "null" }),
+            InferredType::Boolean => json!({ "type": "boolean" }),
+            InferredType::Number => json!({ "type": "number" }),
+            InferredType::Text => json!({ "type": "string" }),
+            InferredType::Bytes => json!({ "type": "string", "contentEncoding": "base64" }),
+            InferredType::Array(items_type) => json!({
+                "type": "array",
+                "items": items_type.to_json_schema(&format!("{}/*", path))?
+            }),
+            InferredType::Object(properties) => {
+                let mut properties_schemata = Map::new();
+                for (key, property_type, _) in properties.iter() {
+                    properties_schemata.insert(
+                        key.clone(),
+                        property_type.to_json_schema(&format!("{}/{}", path, key))?,
+                    );
+                }
+                let required: Vec<&String> = properties
+                    .iter()
+                    .filter(|(_, _, is_required)| *is_required)
+                    .map(|(key, _, _)| key)
+                    .collect();
+                json!({ "type": "object", "properties": properties_schemata, "required": required })
+            }
+        })
+    }
+}
+
+/// Infer the JSON schema of a pipeline node from sample data, and return its JSON literal.
+pub fn infer_json_schema<R: Read>(format: &PortationFileFormat, reader: &mut R) -> Result<String> {
+    let element_types = match format {
+        PortationFileFormat::json => {
+            let mut deserializer = serde_json::Deserializer::from_reader(reader);
+            root_element_types(
+                &PortableValue::deserialize(&mut deserializer)
+                    .context(Error::FailedToReadSample)?,
+            )?
+        }
+        PortationFileFormat::csv => root_element_types(&read_csv_sample(reader)?)?,
+        PortationFileFormat::cbor => root_element_types(
+            &serde_cbor::from_reader(reader).context(Error::FailedToReadSample)?,
+        )?,
+        _ => return Err(Error::UnsupportedSampleFormat(format.to_string()).into()),
+    };
+    let prefix_items = element_types
+        .iter()
+        .enumerate()
+        .map(|(index, element_type)| element_type.to_json_schema(&index.to_string()))
+        .collect::<Result<Vec<Value>>>()?;
+    let literal = json!({ "type": "array", "prefixItems": prefix_items }).to_string();
+    validate_pipeline_node_json_schema(&literal).context(Error::InvalidInferredJsonSchema)?;
+    Ok(literal)
+}
+
+/// Type of the values of a column of a CSV sample
+#[derive(Clone, Copy)]
+enum CsvColumnType {
+    Number,
+    Boolean,
+    Text,
+}
+
+impl CsvColumnType {
+    /// Infer the type of a column from its non-empty cells.
+    fn of(header: &str, cells: &[&str]) -> Result<Self> {
+        if cells
+            .iter()
+            .all(|cell| cell.parse::<u64>().is_ok() || cell.parse::<i64>().is_ok())
+        {
+            return Ok(CsvColumnType::Number);
+        }
+        // textual values such as `nan` or `inf` are not taken as floats
+        if cells
+            .iter()
+            .all(|cell| cell.parse::<f64>().is_ok() && cell.chars().any(|c| c.is_ascii_digit()))
+        {
+            return Err(Error::FloatInSample(format!("0/*/{}", header)).into());
+        }
+        if cells.iter().all(|cell| *cell == "true" || *cell == "false") {
+            return Ok(CsvColumnType::Boolean);
+        }
+        Ok(CsvColumnType::Text)
+    }
+
+    /// Convert a non-empty cell of a column of this type.
+    fn value(&self, cell: &str) -> PortableValue {
+        match self {
+            CsvColumnType::Number => match cell.parse::<u64>() {
+                Ok(unsigned) => PortableValue::Unsigned(unsigned),
+                Err(_) => PortableValue::Negative(cell.parse::<i64>().unwrap_or_default()),
+            },
+            CsvColumnType::Boolean => PortableValue::Boolean(cell == "true"),
+            CsvColumnType::Text => PortableValue::Text(cell.to_string()),
+        }
+    }
+}
+
+/// Read a CSV sample as a tuple holding a single array, with one object per row.
+fn read_csv_sample<R: Read>(reader: R) -> Result<PortableValue> {
+    let mut csv_reader = csv::Reader::from_reader(reader);
+    let headers: Vec<String> = csv_reader
+        .headers()
+        .context(Error::FailedToReadSample)?
+        .iter()
+        .map(str::to_string)
+        .collect();
+    let records = csv_reader
+        .records()
+        .collect::<std::result::Result<Vec<csv::StringRecord>, csv::Error>>()
+        .context(Error::FailedToReadSample)?;
+    let column_types = headers
+        .iter()
+        .enumerate()
+        .map(|(column, header)| {
+            let cells: Vec<&str> = records
+                .iter()
+                .filter_map(|record| record.get(column))
+                .filter(|cell| !cell.is_empty())
+                .collect();
+            CsvColumnType::of(header, &cells)
+        })
+        .collect::<Result<Vec<CsvColumnType>>>()?;
+    let rows = records
+        .iter()
+        .map(|record| {
+            PortableValue::Map(
+                headers
+                    .iter()
+                    .zip(column_types.iter())
+                    .zip(record.iter())
+                    .filter(|(_, cell)| !cell.is_empty())
+                    .map(|((header, column_type), cell)| (header.clone(), column_type.value(cell)))
+                    .collect(),
+            )
+        })
+        .collect();
+    Ok(PortableValue::Array(vec![PortableValue::Array(rows)]))
+}
+
+/// Infer the types of the elements of the array at the root of a sample.
+fn root_element_types(root: &PortableValue) -> Result<Vec<InferredType>> {
+    match root {
+        PortableValue::Array(elements) => elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| InferredType::of(element, &index.to_string()))
+            .collect(),
+        _ => Err(Error::NoArrayAtSampleRoot.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn can_infer_json_schema_from_json_sample() {
+        let sample =
+            r#"["report", [{"id": 1, "tags": ["a"]}, {"id": 2, "tags": [], "note": null}]]"#;
+        let literal =
+            infer_json_schema(&PortationFileFormat::json, &mut Cursor::new(sample)).unwrap();
+        let json_schema: Value = serde_json::from_str(&literal).unwrap();
+        assert_eq!(
+            json_schema,
+            json!({
+                "type": "array",
+                "prefixItems": [
+                    { "type": "string" },
+                    {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "id": { "type": "number" },
+                                "tags": { "type": "array", "items": { "type": "string" } },
+                                "note": { "type": "null" }
+                            },
+                            "required": ["id", "tags"]
+                        }
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn can_infer_json_schema_from_cbor_sample_with_bytes() {
+        let sample = serde_cbor::to_vec(&(serde_cbor::Value::Bytes(vec![0, 1, 2]), 3)).unwrap();
+        let literal =
+            infer_json_schema(&PortationFileFormat::cbor, &mut Cursor::new(sample)).unwrap();
+        let json_schema: Value = serde_json::from_str(&literal).unwrap();
+        assert_eq!(
+            json_schema["prefixItems"][0],
+            json!({ "type": "string", "contentEncoding": "base64" })
+        );
+    }
+
+    #[test]
+    fn can_infer_json_schema_from_csv_sample() {
+        let sample = "name,size,active\nfoo,1,true\nbar,,false\nbaz,-2,true\n";
+        let literal =
+            infer_json_schema(&PortationFileFormat::csv, &mut Cursor::new(sample)).unwrap();
+        let json_schema: Value = serde_json::from_str(&literal).unwrap();
+        assert_eq!(
+            json_schema,
+            json!({
+                "type": "array",
+                "prefixItems": [
+                    {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "size": { "type": "number" },
+                                "active": { "type": "boolean" }
+                            },
+                            "required": ["name", "active"]
+                        }
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn cannot_infer_json_schema_from_csv_sample_with_decimals() {
+        let sample = "name,size\nfoo,1\nbar,1.5\n";
+        let res = infer_json_schema(&PortationFileFormat::csv, &mut Cursor::new(sample));
+        assert!(res.is_err());
+        assert!(res.err().unwrap().to_string().contains(
+            "float values are not handled in HoliumCBOR data, found in sample data at: 0/*/size"
+        ));
+    }
+
+    #[test]
+    fn cannot_infer_json_schema_from_yaml_sample() {
+        let res = infer_json_schema(&PortationFileFormat::yaml, &mut Cursor::new("- 1\n"));
+        assert!(res.is_err());
+        assert!(res.err().unwrap().to_string().contains(
+            "json schemas can only be inferred from json, csv or cbor samples, not: yaml"
+        ));
+    }
+
+    #[test]
+    fn cannot_infer_json_schema_from_sample_with_decimals() {
+        let sample = r#"[[{"size": 1}, {"size": 1.5}]]"#;
+        let res = infer_json_schema(&PortationFileFormat::json, &mut Cursor::new(sample));
+        assert!(res.is_err());
+        assert!(res.err().unwrap().to_string().contains(
+            "float values are not handled in HoliumCBOR data, found in sample data at: 0/*/size"
+        ));
+    }
+
+    #[test]
+    fn cannot_infer_json_schema_from_inconsistent_sample() {
+        let sample = r#"[[1, "two"]]"#;
+        let res = infer_json_schema(&PortationFileFormat::json, &mut Cursor::new(sample));
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("inconsistent types in sample data at: 0/*"));
+    }
+
+    #[test]
+    fn cannot_infer_json_schema_without_root_array() {
+        let sample = r#"{"id": 1}"#;
+        let res = infer_json_schema(&PortationFileFormat::json, &mut Cursor::new(sample));
+        assert!(res.is_err());
+    }
+}
@@ -1,5 +1,7 @@
 //! Helper methods related to JSON schema fields of local Holium objects.
 
+pub mod inference;
+
 use anyhow::{Context, Result};
 
 use jsonschema::JSONSchema;
@@ -43,10 +45,13 @@ enum Error {
     InvalidSchemaForPipelineNode,
 }
 
+#[derive(Clone, PartialEq)]
 pub struct HoliumJsonSchema(pub HoliumJsonSchemaName, pub Box<HoliumJsonSchemaType>);
 
+#[derive(Clone, PartialEq)]
 pub struct HoliumJsonSchemaName(pub Option<String>);
 
+#[derive(Clone, PartialEq)]
 pub enum HoliumJsonSchemaType {
     Object(Vec<HoliumJsonSchema>),
     TupleArray(Vec<HoliumJsonSchema>),
@@ -1,10 +1,21 @@
 use anyhow::Result;
 use mime_guess;
 use mime_guess::mime;
+use std::ffi::OsStr;
+use std::path::Path;
 use thiserror;
 
 use crate::utils::repo::models::portation::PortationFileFormat;
 
+/// Extensions of MessagePack files
+const MSGPACK_EXTENSIONS: &[&str] = &["msgpack", "mpk"];
+/// Extensions of newline-delimited JSON files
+const NDJSON_EXTENSIONS: &[&str] = &["ndjson", "jsonl"];
+/// Extensions of Apache Parquet files
+const PARQUET_EXTENSIONS: &[&str] = &["parquet"];
+/// Extensions of YAML files
+const YAML_EXTENSIONS: &[&str] = &["yaml", "yml"];
+
 #[derive(thiserror::Error, Debug)]
 enum Error {
     #[error("file extension incompatible with selected file format")]
@@ -48,6 +59,20 @@ pub fn validate_mimetype_coherence(
                 Err(Error::IncompatibleMediaTypeAndPortationFormat.into())
             }
         }
+        // no media type is commonly registered for the following formats, rely on file extensions
+        PortationFileFormat::msgpack => validate_extension(file_path, MSGPACK_EXTENSIONS),
+        PortationFileFormat::ndjson => validate_extension(file_path, NDJSON_EXTENSIONS),
+        PortationFileFormat::parquet => validate_extension(file_path, PARQUET_EXTENSIONS),
+        PortationFileFormat::yaml => validate_extension(file_path, YAML_EXTENSIONS),
+    }
+}
+
+/// Validate that a file path has one of the given extensions.
+fn validate_extension(file_path: &str, extensions: &[&str]) -> Result<()> {
+    let extension = Path::new(file_path).extension().and_then(OsStr::to_str);
+    match extension {
+        Some(extension) if extensions.contains(&extension) => Ok(()),
+        _ => Err(Error::IncompatibleMediaTypeAndPortationFormat.into()),
     }
 }
 
@@ -1,4 +1,6 @@
+pub mod arguments;
 pub mod bytecode;
+pub mod impact;
 pub mod jsonschema;
 pub mod media_type;
 pub mod prints;
@@ -1,6 +1,14 @@
+use crate::utils::interplanetary::diff::{ElementChange, PipelineDiff};
+use crate::utils::interplanetary::fs::helpers::check_ip_area::BlockReport;
 use crate::utils::interplanetary::multiformats::DEFAULT_MULTIBASE;
+use crate::utils::local::helpers::impact::TransformationUpdateReport;
+use crate::utils::local::manifest::{ChangeKind, ManifestChange};
+use crate::utils::local::models::run::Run;
+use crate::utils::repo::models::config::ProjectConfig;
 use cid::Cid;
 use console::style;
+use ellipse::Ellipse;
+use serde_json::Value;
 
 /*
 Success messages
@@ -48,6 +56,66 @@ pub fn print_interplanetary_health_success() {
     )
 }
 
+/// Print success message for methods checking the integrity of all blocks of the interplanetary
+/// area.
+pub fn print_ip_area_integrity_success(nb_blocks: usize) {
+    println!(
+        "{}",
+        style(format!(
+            "{} block(s) checked, interplanetary area is healthy",
+            nb_blocks
+        ))
+        .green()
+    )
+}
+
+/// Print the issues found while checking the integrity of the interplanetary area. Parameter is
+/// a vector of tuples containing the report and, for quarantined blocks, their new path.
+pub fn print_ip_area_integrity_issues(issues: &[(&BlockReport, Option<String>)]) {
+    println!(
+        "{}",
+        style(format!(
+            "{} issue(s) found in the interplanetary area:",
+            issues.len()
+        ))
+        .yellow()
+    );
+    for (report, quarantine_path) in issues.iter() {
+        match quarantine_path {
+            Some(path) => println!(
+                "{}",
+                style(format!("{} (quarantined to {})", report, path)).yellow()
+            ),
+            None => println!("{}", style(format!("{}", report)).yellow()),
+        }
+    }
+}
+
+/// Print interplanetary area MIGRATION success message.
+pub fn print_ip_area_migration_success(
+    nb_blocks: usize,
+    config: &ProjectConfig,
+    pipeline_cid: Option<&Cid>,
+) {
+    println!(
+        "{}",
+        style(format!(
+            "{} block(s) of the interplanetary area migrated to {} hash function and {} block store",
+            nb_blocks, config.hash_function, config.block_store
+        ))
+        .green()
+    );
+    if let Some(cid) = pipeline_cid {
+        let cid_str = cid
+            .to_string_of_base(DEFAULT_MULTIBASE)
+            .unwrap_or("".to_string());
+        println!(
+            "{}",
+            style(format!("new pipeline cid: {}", style(cid_str).bold())).green()
+        )
+    }
+}
+
 /// Print project EXPORT success message.
 pub fn print_project_export_success(cid: &Cid) {
     let cid_str = cid
@@ -63,6 +131,168 @@ pub fn print_project_export_success(cid: &Cid) {
     )
 }
 
+/// Print project DIFF result, in a human readable form.
+pub fn print_pipeline_diff(diff: &PipelineDiff) {
+    if diff.is_empty() {
+        println!("{}", style("no difference between pipelines").green());
+        return;
+    }
+    print_pipeline_elements_diff(
+        "node",
+        &diff.added_nodes,
+        &diff.removed_nodes,
+        &diff.changed_nodes,
+    );
+    print_pipeline_elements_diff(
+        "connection",
+        &diff.added_connections,
+        &diff.removed_connections,
+        &diff.changed_connections,
+    );
+}
+
+/// Print added, removed and changed elements of a pipeline diff.
+fn print_pipeline_elements_diff(
+    element_type: &str,
+    added: &[String],
+    removed: &[String],
+    changed: &[ElementChange],
+) {
+    for id in added.iter() {
+        println!("{}", style(format!("+ {} {}", element_type, id)).green());
+    }
+    for id in removed.iter() {
+        println!("{}", style(format!("- {} {}", element_type, id)).red());
+    }
+    for element in changed.iter() {
+        println!(
+            "{}",
+            style(format!("~ {} {}", element_type, element.id)).yellow()
+        );
+        for change in element.changes.iter() {
+            let shorten = |value: &Option<String>| match value {
+                Some(value) => value.as_str().truncate_ellipse(64).to_string(),
+                None => "none".to_string(),
+            };
+            println!(
+                "    {}: {} → {}",
+                style(&change.field).bold(),
+                shorten(&change.old),
+                shorten(&change.new)
+            );
+        }
+    }
+}
+
+/// Print the changes brought by the update of a transformation, and connections and portations
+/// impacted by them.
+pub fn print_transformation_update_report(name: &str, report: &TransformationUpdateReport) {
+    if report.changes.is_empty() {
+        println!("{}", style("no change to the transformation").green());
+        return;
+    }
+    println!("{}", style(format!("~ transformation {}", name)).yellow());
+    for change in report.changes.iter() {
+        println!(
+            "    {}: {} → {}",
+            style(change.field).bold(),
+            change.old.as_str().truncate_ellipse(64),
+            change.new.as_str().truncate_ellipse(64)
+        );
+    }
+    for link in report.impacted.iter() {
+        match &link.issue {
+            Some(issue) => println!(
+                "{}",
+                style(format!("! {} {}: {}", link.object_type, link.id, issue)).red()
+            ),
+            None => println!(
+                "{}",
+                style(format!("* {} {}", link.object_type, link.id)).yellow()
+            ),
+        }
+    }
+}
+
+/// Print the changes reconciling the local area with a manifest, either applied or planned.
+pub fn print_manifest_plan(changes: &[ManifestChange], dry_run: bool) {
+    if changes.is_empty() {
+        println!(
+            "{}",
+            style("local area already matches the manifest").green()
+        );
+        return;
+    }
+    for change in changes.iter() {
+        let line = format!("{} {}", change.object_type, change.key);
+        match change.kind {
+            ChangeKind::Create => println!("{}", style(format!("+ {}", line)).green()),
+            ChangeKind::Update => println!("{}", style(format!("~ {}", line)).yellow()),
+            ChangeKind::Delete => println!("{}", style(format!("- {}", line)).red()),
+        }
+    }
+    let summary = if dry_run {
+        format!("{} change(s) to apply", changes.len())
+    } else {
+        format!("{} change(s) applied", changes.len())
+    };
+    println!("{}", style(summary).green());
+}
+
+/// Print project DUMP success message.
+pub fn print_manifest_dump_success(path: &str) {
+    println!(
+        "{}",
+        style(format!("manifest written: {}", style(path).bold())).green()
+    )
+}
+
+/// Print data sets selected by a selector and, if any, the value they build at the head of a
+/// connection.
+pub fn print_selection_preview(
+    data_sets: &[Vec<Value>],
+    head_value: Option<&std::result::Result<Value, String>>,
+) {
+    println!(
+        "{}",
+        style(format!("{} data set(s) selected:", data_sets.len())).green()
+    );
+    for (i, data_set) in data_sets.iter().enumerate() {
+        println!("  [{}] {}", i, Value::Array(data_set.clone()).to_string());
+    }
+    match head_value {
+        Some(Ok(value)) => {
+            println!("{}", style("value at the head of the connection:").green());
+            println!(
+                "{}",
+                serde_json::to_string_pretty(value).unwrap_or_default()
+            );
+        }
+        Some(Err(err)) => println!(
+            "{}",
+            style(format!(
+                "value at the head of the connection could not be built: {}",
+                err
+            ))
+            .yellow()
+        ),
+        None => {}
+    }
+}
+
+/// Print a JSON schema inferred from sample data, for review.
+pub fn print_inferred_json_schema(literal: &str) {
+    let value: Value = serde_json::from_str(literal).unwrap_or_default();
+    println!(
+        "{}",
+        style("json schema inferred from sample data:").green()
+    );
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&value).unwrap_or_default()
+    );
+}
+
 /// Print project IMPORT success message.
 pub fn print_project_import_success() {
     println!(
@@ -79,6 +309,28 @@ pub fn print_pipeline_run_success() {
     )
 }
 
+/// Print the id of the provenance record of a RUN, and the cid of its block if exported.
+pub fn print_run_provenance_success(run: &Run) {
+    println!(
+        "{}",
+        style(format!(
+            "provenance recorded for run: {}",
+            style(&run.id).bold()
+        ))
+        .green()
+    );
+    if let Some(block_cid) = &run.block_cid {
+        println!(
+            "{}",
+            style(format!(
+                "provenance exported with cid: {}",
+                style(block_cid).bold()
+            ))
+            .green()
+        )
+    }
+}
+
 /// Print project RUN export success message. Parameter is a vector of tuples containing the node
 /// type name and the file written.
 pub fn print_pipeline_export_success(node_exports: &[(String, String)]) {
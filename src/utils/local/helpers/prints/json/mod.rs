@@ -17,6 +17,13 @@ pub fn shorten_prettify_json_literal(json_string: &str) -> String {
     }
 }
 
+/// Parse a JSON literal into a value to be embedded in machine-readable outputs, keeping it as a
+/// string if it is not valid JSON
+pub fn json_literal_to_value(json_string: &str) -> serde_json::Value {
+    serde_json::from_str(json_string)
+        .unwrap_or_else(|_| serde_json::Value::String(json_string.to_string()))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
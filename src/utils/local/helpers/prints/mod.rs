@@ -3,4 +3,5 @@
 pub mod commands_outputs;
 pub mod errors;
 pub mod json;
+pub mod output;
 pub mod printable_model;
@@ -0,0 +1,60 @@
+//! Helpers related to the format of command outputs.
+
+use anyhow::{Context, Error as AnyhowError, Result};
+use clap::{arg_enum, Arg, ArgMatches};
+use serde::Serialize;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("failed to serialize command output")]
+    FailedToSerializeOutput,
+}
+
+arg_enum! {
+    #[derive(PartialEq, Debug)]
+    /// Variants of the CLI argument selecting the format in which command results are printed.
+    pub enum OutputFormat {
+        human,
+        json,
+        yaml,
+    }
+}
+
+/// Build the CLI argument selecting the format in which command results are printed.
+pub fn output_format_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("output")
+        .help("Format of the output")
+        .takes_value(true)
+        .possible_values(&OutputFormat::variants())
+        .case_insensitive(true)
+        .default_value("human")
+        .value_name("FORMAT")
+        .short("o")
+        .long("output")
+}
+
+/// Parse the value of the CLI argument built by [output_format_arg].
+pub fn parse_output_format(matches: &ArgMatches) -> Result<OutputFormat> {
+    matches
+        .value_of("output")
+        .unwrap_or_default()
+        .parse::<OutputFormat>()
+        .map_err(AnyhowError::msg)
+}
+
+/// Print a serializable value in a machine-readable format. Nothing is printed in the human
+/// format, for which commands have their own messages.
+pub fn print_structured<T: Serialize>(value: &T, output_format: &OutputFormat) -> Result<()> {
+    match output_format {
+        OutputFormat::human => {}
+        OutputFormat::json => println!(
+            "{}",
+            serde_json::to_string_pretty(value).context(Error::FailedToSerializeOutput)?
+        ),
+        OutputFormat::yaml => print!(
+            "{}",
+            serde_yaml::to_string(value).context(Error::FailedToSerializeOutput)?
+        ),
+    }
+    Ok(())
+}
@@ -1,7 +1,10 @@
 //! Trait to implement to print formatted objects in a table format.
 
+use crate::utils::local::helpers::prints::output::{print_structured, OutputFormat};
+use anyhow::Result;
 use console::style;
 use prettytable::{format, Row, Table};
+use serde_json::Value;
 
 /// PrintableModel can be implemented to print objects in a table format
 pub trait PrintableModel: Sized {
@@ -9,24 +12,35 @@ pub trait PrintableModel: Sized {
     ///
     /// # Example
     ///
-    /// ```
+    /// ```ignore
     /// fn title_row() -> Row {
     ///     row![b->"NAME", "HANDLE"]
     /// }
-    /// ```
+    /// ```ignore
     fn title_row() -> Row;
 
     /// Defines the default content of a table body row.
     ///
     /// # Example
     ///
-    /// ```
+    /// ```ignore
     /// fn object_to_row(&self) -> Row {
     ///     row![b->self.name, self.handle]
     /// }
-    /// ```
+    /// ```ignore
     fn object_to_row(&self) -> Row;
 
+    /// Defines the full, untruncated content of an object in machine-readable outputs.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// fn object_to_value(&self) -> Value {
+    ///     json!({ "name": self.name, "handle": self.handle })
+    /// }
+    /// ```ignore
+    fn object_to_value(&self) -> Value;
+
     /// Print a list of objects into a table.
     fn table_print(objects: Vec<&Self>) {
         if objects.len() < 1 {
@@ -41,4 +55,25 @@ pub trait PrintableModel: Sized {
         }
         table.printstd();
     }
+
+    /// Print a list of objects, as a table or as a machine-readable array.
+    fn list_print(objects: Vec<&Self>, output_format: &OutputFormat) -> Result<()> {
+        match output_format {
+            OutputFormat::human => Self::table_print(objects),
+            _ => print_structured(
+                &Value::Array(objects.iter().map(|o| o.object_to_value()).collect()),
+                output_format,
+            )?,
+        }
+        Ok(())
+    }
+
+    /// Print a single object, as a table or as a machine-readable value.
+    fn object_print(&self, output_format: &OutputFormat) -> Result<()> {
+        match output_format {
+            OutputFormat::human => Self::table_print(vec![self]),
+            _ => print_structured(&self.object_to_value(), output_format)?,
+        }
+        Ok(())
+    }
 }
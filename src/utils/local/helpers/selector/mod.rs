@@ -1,10 +1,20 @@
 //! Helper methods related to selectors associated to local Holium connection objects.
 
-use crate::utils::interplanetary::kinds::selector::SelectorEnvelope;
+pub mod path;
+
+use crate::utils::interplanetary::kinds::selector::{
+    ExploreAll, ExploreConditional, ExploreIndex, ExploreRange, ExploreRecursive, ExploreUnion,
+    RecursionLimit, Selector, SelectorEnvelope,
+};
+use crate::utils::local::helpers::jsonschema::{HoliumJsonSchema, HoliumJsonSchemaType};
+use crate::utils::local::helpers::prints::json::shorten_prettify_json_literal;
+use crate::utils::local::helpers::selector::path::{parse_selector_path, selector_to_path};
+use crate::utils::repo::ports::formats::json::{cbor_to_json, holium_cbor_to_json};
 use anyhow::{Context, Result};
 
 use jsonschema::JSONSchema;
 use serde_json::value::Value;
+use std::convert::TryFrom;
 
 lazy_static::lazy_static! {
     static ref HOLIUM_SELECTOR_SCHEMA: JSONSchema = {
@@ -19,8 +29,39 @@ lazy_static::lazy_static! {
 enum Error {
     #[error("invalid string can not be parsed to json")]
     StringNotParsableToJSON,
+    #[error("invalid string can not be parsed to json nor to a selector path")]
+    StringNotParsableToJSONNorPath,
     #[error("invalid holium selector")]
     InvalidHoliumSelector,
+    #[error("fields can only be selected on objects, failed to resolve: {0}")]
+    FieldsOnNonObject(String),
+    #[error("unknown field in json schema: {0}")]
+    UnknownField(String),
+    #[error("no json schema for the element at index: {0}")]
+    NoSchemaAtIndex(u64),
+    #[error("fields can not be resolved identically for all explored elements")]
+    AmbiguousFieldsResolution,
+    #[error("tail selector produces {0} data set(s) while head selector expects {1}")]
+    DataSetsCountMismatch(usize, usize),
+}
+
+/// Parse a selector given as a command line argument, either as a JSON literal or in the compact
+/// path syntax, and return its JSON literal. Only JSON objects are taken as literals, so that paths
+/// such as `0` are not mistaken for JSON numbers.
+pub fn parse_selector_argument(argument: &str) -> Result<String> {
+    if let Ok(Value::Object(_)) = serde_json::from_str::<Value>(argument) {
+        return Ok(argument.to_string());
+    }
+    let selector = parse_selector_path(argument).context(Error::StringNotParsableToJSONNorPath)?;
+    Ok(Value::from(selector).to_string())
+}
+
+/// Format a selector JSON literal for display, in the compact path syntax whenever possible.
+pub fn display_selector(literal: &str) -> String {
+    Selector::try_from(literal)
+        .ok()
+        .and_then(|selector| selector_to_path(&selector))
+        .unwrap_or_else(|| shorten_prettify_json_literal(literal))
 }
 
 /// Validate a Holium selector JSON instance against the reference JSON Schema.
@@ -37,9 +78,394 @@ pub fn validate_selector(literal: &str) -> Result<()> {
     Ok(())
 }
 
+/// Validate that the data sets selected by a tail selector can be paired with the write paths of a
+/// head selector. Each union branch, at any depth, produces one data set at the tail and expects
+/// one at the head. Data sets are paired in the order their branches appear in the selectors.
+pub fn validate_selectors_pairing(tail_literal: &str, head_literal: &str) -> Result<()> {
+    let tail_count = Selector::try_from(tail_literal)?.data_sets_count();
+    let head_count = Selector::try_from(head_literal)?.data_sets_count();
+    if tail_count != head_count {
+        return Err(Error::DataSetsCountMismatch(tail_count, head_count).into());
+    }
+    Ok(())
+}
+
+/// Resolve property names addressed by [ExploreFields] selectors into the indices of the
+/// corresponding elements, based on the JSON schema of the explored data. A single field is
+/// resolved into an [ExploreIndex] selector while multiple fields are resolved into an
+/// [ExploreUnion] of such selectors, each field producing its own data set, in the order the
+/// fields are given.
+pub fn resolve_selector_fields(
+    selector: &Selector,
+    json_schema: &HoliumJsonSchema,
+) -> Result<Selector> {
+    resolve_fields(selector, json_schema, &mut vec![])
+}
+
+/// Resolve fields of a selector, collecting the JSON schemas of the nodes reached by recursion
+/// edges, where the sequence of the enclosing recursion is applied again.
+fn resolve_fields<'a>(
+    selector: &Selector,
+    json_schema: &'a HoliumJsonSchema,
+    edges: &mut Vec<&'a HoliumJsonSchema>,
+) -> Result<Selector> {
+    // fast path for selectors with no field to resolve nor recursion edge to follow
+    if !selector.has_fields() && !has_recursion_edge(selector) {
+        return Ok(selector.clone());
+    }
+    match selector {
+        Selector::Matcher(_) => Ok(selector.clone()),
+        Selector::ExploreRecursiveEdge(_) => {
+            edges.push(json_schema);
+            Ok(selector.clone())
+        }
+        Selector::ExploreIndex(explore_index) => {
+            let child_schema = child_json_schema(json_schema, explore_index.index)?;
+            Ok(Selector::ExploreIndex(Box::new(ExploreIndex {
+                index: explore_index.index,
+                next: Box::new(resolve_fields(&explore_index.next, child_schema, edges)?),
+            })))
+        }
+        Selector::ExploreRange(explore_range) => {
+            let next = resolve_children_fields(
+                &explore_range.next,
+                json_schema,
+                explore_range.start..explore_range.end,
+                edges,
+            )?;
+            Ok(Selector::ExploreRange(Box::new(ExploreRange {
+                start: explore_range.start,
+                end: explore_range.end,
+                next: Box::new(next),
+            })))
+        }
+        Selector::ExploreAll(explore_all) => {
+            let nb_children = match json_schema.1.as_ref() {
+                HoliumJsonSchemaType::Object(schemata)
+                | HoliumJsonSchemaType::TupleArray(schemata) => schemata.len() as u64,
+                _ => 1,
+            };
+            let next =
+                resolve_children_fields(&explore_all.next, json_schema, 0..nb_children, edges)?;
+            Ok(Selector::ExploreAll(Box::new(ExploreAll {
+                next: Box::new(next),
+            })))
+        }
+        Selector::ExploreFields(explore_fields) => {
+            let properties = match json_schema.1.as_ref() {
+                HoliumJsonSchemaType::Object(properties) => properties,
+                _ => return Err(Error::FieldsOnNonObject(explore_fields.field_names()).into()),
+            };
+            // find the index of each field in the object properties, keeping the order of fields
+            let mut resolved_fields = explore_fields
+                .fields
+                .iter()
+                .map(|(name, next)| {
+                    let index = properties
+                        .iter()
+                        .position(|property| property.0 .0.as_deref() == Some(name.as_str()))
+                        .ok_or(Error::UnknownField(name.clone()))?;
+                    Ok(Selector::ExploreIndex(Box::new(ExploreIndex {
+                        index: index as u64,
+                        next: Box::new(resolve_fields(next, &properties[index], edges)?),
+                    })))
+                })
+                .collect::<Result<Vec<Selector>>>()?;
+            if resolved_fields.len() == 1 {
+                return Ok(resolved_fields.remove(0));
+            }
+            Ok(Selector::ExploreUnion(Box::new(ExploreUnion(
+                resolved_fields,
+            ))))
+        }
+        Selector::ExploreRecursive(explore_recursive) => {
+            let sequence = resolve_recursive_sequence_fields(
+                &explore_recursive.sequence,
+                &explore_recursive.limit,
+                json_schema,
+            )?;
+            Ok(Selector::ExploreRecursive(Box::new(ExploreRecursive {
+                limit: explore_recursive.limit.clone(),
+                sequence: Box::new(sequence),
+                stop_at: explore_recursive.stop_at.clone(),
+            })))
+        }
+        Selector::ExploreUnion(explore_union) => {
+            Ok(Selector::ExploreUnion(Box::new(ExploreUnion(
+                explore_union
+                    .0
+                    .iter()
+                    .map(|s| resolve_fields(s, json_schema, edges))
+                    .collect::<Result<Vec<Selector>>>()?,
+            ))))
+        }
+        Selector::ExploreConditional(explore_conditional) => {
+            Ok(Selector::ExploreConditional(Box::new(ExploreConditional {
+                condition: explore_conditional.condition.clone(),
+                next: Box::new(resolve_fields(
+                    &explore_conditional.next,
+                    json_schema,
+                    edges,
+                )?),
+            })))
+        }
+    }
+}
+
+/// Check that the indices and ranges explored by a selector, whose fields have been resolved, address
+/// elements described by the JSON schema of the explored data. Only the first level of recursions is
+/// checked, and the length of arrays of items is not known from their JSON schema.
+pub fn validate_selector_indices(
+    selector: &Selector,
+    json_schema: &HoliumJsonSchema,
+) -> Result<()> {
+    match selector {
+        // fields are resolved into indices beforehand
+        Selector::Matcher(_) | Selector::ExploreRecursiveEdge(_) | Selector::ExploreFields(_) => {
+            Ok(())
+        }
+        Selector::ExploreIndex(explore_index) => validate_selector_indices(
+            &explore_index.next,
+            child_json_schema(json_schema, explore_index.index)?,
+        ),
+        Selector::ExploreRange(explore_range) => match json_schema.1.as_ref() {
+            HoliumJsonSchemaType::ItemsArray(items_schema) => {
+                validate_selector_indices(&explore_range.next, items_schema)
+            }
+            _ => (explore_range.start..explore_range.end).try_for_each(|index| {
+                validate_selector_indices(
+                    &explore_range.next,
+                    child_json_schema(json_schema, index)?,
+                )
+            }),
+        },
+        Selector::ExploreAll(explore_all) => match json_schema.1.as_ref() {
+            HoliumJsonSchemaType::Object(schemata) | HoliumJsonSchemaType::TupleArray(schemata) => {
+                schemata
+                    .iter()
+                    .try_for_each(|schema| validate_selector_indices(&explore_all.next, schema))
+            }
+            HoliumJsonSchemaType::ItemsArray(items_schema) => {
+                validate_selector_indices(&explore_all.next, items_schema)
+            }
+            _ => Ok(()),
+        },
+        Selector::ExploreRecursive(explore_recursive) => {
+            validate_selector_indices(&explore_recursive.sequence, json_schema)
+        }
+        Selector::ExploreUnion(explore_union) => explore_union
+            .0
+            .iter()
+            .try_for_each(|selector| validate_selector_indices(selector, json_schema)),
+        Selector::ExploreConditional(explore_conditional) => {
+            validate_selector_indices(&explore_conditional.next, json_schema)
+        }
+    }
+}
+
+/// Check if a selector contains an edge of the enclosing recursion. Edges of recursions nested in
+/// the selector belong to these recursions.
+fn has_recursion_edge(selector: &Selector) -> bool {
+    match selector {
+        Selector::Matcher(_) | Selector::ExploreRecursive(_) => false,
+        Selector::ExploreRecursiveEdge(_) => true,
+        Selector::ExploreFields(child) => child.fields.iter().any(|(_, s)| has_recursion_edge(s)),
+        Selector::ExploreAll(child) => has_recursion_edge(&child.next),
+        Selector::ExploreIndex(child) => has_recursion_edge(&child.next),
+        Selector::ExploreRange(child) => has_recursion_edge(&child.next),
+        Selector::ExploreUnion(child) => child.0.iter().any(has_recursion_edge),
+        Selector::ExploreConditional(child) => has_recursion_edge(&child.next),
+    }
+}
+
+/// Resolve fields of the sequence of a recursion. The sequence is applied again on every node
+/// reached by its recursion edges, within the recursion limit, so its resolution should be
+/// identical against the JSON schemas of all of these nodes. Nodes with a scalar JSON schema are
+/// skipped, as nothing can be explored in them.
+fn resolve_recursive_sequence_fields(
+    sequence: &Selector,
+    limit: &RecursionLimit,
+    json_schema: &HoliumJsonSchema,
+) -> Result<Selector> {
+    let mut edges = vec![];
+    let resolved = resolve_fields(sequence, json_schema, &mut edges)?;
+    // explore the reached nodes breadth first, level by level, each JSON schema only once
+    let mut visited: Vec<*const HoliumJsonSchema> = vec![json_schema];
+    let mut level = 1u64;
+    while !edges.is_empty() {
+        level += 1;
+        if let RecursionLimit::Depth(depth) = limit {
+            if level > *depth {
+                break;
+            }
+        }
+        let mut next_edges = vec![];
+        for edge_schema in edges {
+            let is_explorable = matches!(
+                edge_schema.1.as_ref(),
+                HoliumJsonSchemaType::Object(_)
+                    | HoliumJsonSchemaType::TupleArray(_)
+                    | HoliumJsonSchemaType::ItemsArray(_)
+            );
+            let edge_schema_ptr: *const HoliumJsonSchema = edge_schema;
+            if !is_explorable || visited.contains(&edge_schema_ptr) {
+                continue;
+            }
+            visited.push(edge_schema_ptr);
+            if resolve_fields(sequence, edge_schema, &mut next_edges)? != resolved {
+                return Err(Error::AmbiguousFieldsResolution.into());
+            }
+        }
+        edges = next_edges;
+    }
+    Ok(resolved)
+}
+
+/// Resolve fields of a selector applied to multiple children of a node. The resolution should be
+/// identical for all of them.
+fn resolve_children_fields<'a>(
+    selector: &Selector,
+    json_schema: &'a HoliumJsonSchema,
+    indices: std::ops::Range<u64>,
+    edges: &mut Vec<&'a HoliumJsonSchema>,
+) -> Result<Selector> {
+    let mut resolved: Option<Selector> = None;
+    for index in indices {
+        let child_resolved =
+            resolve_fields(selector, child_json_schema(json_schema, index)?, edges)?;
+        match &resolved {
+            Some(resolved) if resolved != &child_resolved => {
+                return Err(Error::AmbiguousFieldsResolution.into())
+            }
+            _ => resolved = Some(child_resolved),
+        }
+    }
+    Ok(resolved.unwrap_or_else(|| selector.clone()))
+}
+
+/// Render data sets selected by a resolved selector as JSON. Elements are rendered through the JSON
+/// schema of the explored data whenever the schema of the elements of their data set is known.
+pub fn selected_data_sets_to_json(
+    data_sets: &[Vec<Vec<u8>>],
+    selector: &Selector,
+    json_schema: &HoliumJsonSchema,
+) -> Result<Vec<Vec<Value>>> {
+    data_sets
+        .iter()
+        .zip(selector.branches().iter())
+        .map(|(data_set, branch)| {
+            let element_schema = selected_json_schema(branch, json_schema);
+            data_set
+                .iter()
+                .map(|data| match element_schema {
+                    Some(element_schema) => {
+                        holium_cbor_to_json(element_schema, data).or_else(|_| cbor_to_json(data))
+                    }
+                    None => cbor_to_json(data),
+                })
+                .collect::<Result<Vec<Value>>>()
+        })
+        .collect()
+}
+
+/// Get the JSON schema shared by all elements selected by a branch of a resolved selector, as
+/// given by [Selector::branches]. There is none for elements explored recursively or elements
+/// with different schemas.
+fn selected_json_schema<'a>(
+    branch: &Selector,
+    json_schema: &'a HoliumJsonSchema,
+) -> Option<&'a HoliumJsonSchema> {
+    match branch {
+        Selector::Matcher(_) => Some(json_schema),
+        Selector::ExploreIndex(explore_index) => selected_json_schema(
+            &explore_index.next,
+            child_json_schema(json_schema, explore_index.index).ok()?,
+        ),
+        Selector::ExploreRange(explore_range) => shared_selected_json_schema(
+            &explore_range.next,
+            json_schema,
+            explore_range.start..explore_range.end,
+        ),
+        Selector::ExploreAll(explore_all) => {
+            let nb_children = match json_schema.1.as_ref() {
+                HoliumJsonSchemaType::Object(schemata)
+                | HoliumJsonSchemaType::TupleArray(schemata) => schemata.len() as u64,
+                _ => 1,
+            };
+            shared_selected_json_schema(&explore_all.next, json_schema, 0..nb_children)
+        }
+        Selector::ExploreConditional(explore_conditional) => {
+            selected_json_schema(&explore_conditional.next, json_schema)
+        }
+        _ => None,
+    }
+}
+
+/// Get the JSON schema shared by all elements selected in multiple children of a node
+fn shared_selected_json_schema<'a>(
+    selector: &Selector,
+    json_schema: &'a HoliumJsonSchema,
+    indices: std::ops::Range<u64>,
+) -> Option<&'a HoliumJsonSchema> {
+    let mut shared: Option<&HoliumJsonSchema> = None;
+    for index in indices {
+        let selected = selected_json_schema(selector, child_json_schema(json_schema, index).ok()?)?;
+        match shared {
+            Some(shared) if shared != selected => return None,
+            _ => shared = Some(selected),
+        }
+    }
+    shared
+}
+
+/// Get the JSON schema of the element at a given index of a recursive type
+fn child_json_schema(json_schema: &HoliumJsonSchema, index: u64) -> Result<&HoliumJsonSchema> {
+    match json_schema.1.as_ref() {
+        HoliumJsonSchemaType::Object(schemata) | HoliumJsonSchemaType::TupleArray(schemata) => {
+            schemata
+                .get(index as usize)
+                .ok_or(Error::NoSchemaAtIndex(index).into())
+        }
+        HoliumJsonSchemaType::ItemsArray(items_schema) => Ok(items_schema),
+        _ => Err(Error::NoSchemaAtIndex(index).into()),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::utils::cbor::as_holium_cbor::AsHoliumCbor;
+    use crate::utils::local::helpers::jsonschema::parse_root_json_schema;
+    use crate::utils::repo::ports::formats::json::json_to_holium_cbor;
+    use serde_json::json;
+
+    #[test]
+    fn can_parse_selector_argument_as_bare_index_path() {
+        for (argument, expected) in [
+            ("0", r#"{"i": {"i": 0, ">": {".": {}}}}"#),
+            ("3", r#"{"i": {"i": 3, ">": {".": {}}}}"#),
+        ]
+        .iter()
+        {
+            let literal = parse_selector_argument(argument).unwrap();
+
+            assert_eq!(
+                Selector::try_from(*expected).unwrap(),
+                Selector::try_from(literal.as_str()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn can_parse_selector_argument_as_json_literal_or_path() {
+        let literal = r#"{"i": {"i": 0, ">": {"r": {"^": 2, "$": 5, ">": {".": {}}}}}}"#;
+
+        assert_eq!(literal, parse_selector_argument(literal).unwrap());
+        assert_eq!(
+            Selector::try_from(literal).unwrap(),
+            Selector::try_from(parse_selector_argument("0/2..5").unwrap().as_str()).unwrap()
+        );
+    }
 
     #[test]
     fn cannot_validate_non_json_string() {
@@ -68,4 +494,173 @@ mod test {
             .to_string()
             .contains("invalid holium selector"));
     }
+
+    #[test]
+    fn can_validate_recursive_and_conditional_selectors() {
+        let selector = r#"{"R": {"l": {"depth": 3}, ":>": {"a": {">": {"|": [{"&": {"&": {"k": "string"}, ">": {".": {}}}}, {"@": {}}]}}}}}"#;
+
+        validate_selector(selector).unwrap();
+    }
+
+    #[test]
+    fn can_resolve_fields_with_json_schema() {
+        let json_schema = parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string" },
+                    "name": { "type": "string" },
+                    "age": { "type": "number" }
+                }
+            }]
+        }))
+        .unwrap();
+        let selector = Selector::try_from(
+            r#"{"i": {"i": 0, ">": {"f": {"f>": {"age": {".": {}}, "id": {".": {}}}}}}}"#,
+        )
+        .unwrap();
+        let expected = Selector::try_from(
+            r#"{"i": {"i": 0, ">": {"|": [{"i": {"i": 2, ">": {".": {}}}}, {"i": {"i": 0, ">": {".": {}}}}]}}}"#,
+        )
+        .unwrap();
+
+        let resolved = resolve_selector_fields(&selector, &json_schema).unwrap();
+
+        assert_eq!(expected, resolved);
+    }
+
+    fn tree_json_schema(nested_properties: serde_json::Value) -> HoliumJsonSchema {
+        parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "children": {
+                        "type": "array",
+                        "items": { "type": "object", "properties": nested_properties }
+                    }
+                }
+            }]
+        }))
+        .unwrap()
+    }
+
+    const RECURSIVE_FIELDS_SELECTOR: &str = r#"{"i": {"i": 0, ">": {"R": {"l": {"depth": 3}, ":>": {"f": {"f>": {"children": {"a": {">": {"@": {}}}}, "name": {".": {}}}}}}}}}"#;
+
+    #[test]
+    fn can_resolve_fields_in_recursive_sequence() {
+        let json_schema = tree_json_schema(json!({
+            "name": { "type": "string" },
+            "children": { "type": "array", "items": { "type": "string" } }
+        }));
+        let selector = Selector::try_from(RECURSIVE_FIELDS_SELECTOR).unwrap();
+        let expected = Selector::try_from(
+            r#"{"i": {"i": 0, ">": {"R": {"l": {"depth": 3}, ":>": {"|": [{"i": {"i": 1, ">": {"a": {">": {"@": {}}}}}}, {"i": {"i": 0, ">": {".": {}}}}]}}}}}"#,
+        )
+        .unwrap();
+
+        let resolved = resolve_selector_fields(&selector, &json_schema).unwrap();
+
+        assert_eq!(expected, resolved);
+    }
+
+    #[test]
+    fn cannot_resolve_fields_differently_along_recursion() {
+        let json_schema = tree_json_schema(json!({
+            "children": { "type": "array", "items": { "type": "string" } },
+            "name": { "type": "string" }
+        }));
+        let selector = Selector::try_from(RECURSIVE_FIELDS_SELECTOR).unwrap();
+
+        let res = resolve_selector_fields(&selector, &json_schema);
+
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("fields can not be resolved identically for all explored elements"));
+        // nodes beyond the recursion limit are not explored
+        let selector = Selector::try_from(
+            RECURSIVE_FIELDS_SELECTOR
+                .replace(r#"{"depth": 3}"#, r#"{"depth": 1}"#)
+                .as_str(),
+        )
+        .unwrap();
+        resolve_selector_fields(&selector, &json_schema).unwrap();
+    }
+
+    #[test]
+    fn can_render_selected_data_with_json_schema() {
+        let json_schema = parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{
+                "type": "array",
+                "items": { "type": "object", "properties": { "id": { "type": "string" } } }
+            }]
+        }))
+        .unwrap();
+        let data =
+            json_to_holium_cbor(&json_schema, &json!([[{ "id": "a" }, { "id": "b" }]])).unwrap();
+        let selector =
+            Selector::try_from(parse_selector_argument("0/*").unwrap().as_str()).unwrap();
+
+        let data_sets = selected_data_sets_to_json(
+            &data.select_cbor(&selector).unwrap(),
+            &selector,
+            &json_schema,
+        )
+        .unwrap();
+
+        assert_eq!(
+            data_sets,
+            vec![vec![json!({ "id": "a" }), json!({ "id": "b" })]]
+        );
+    }
+
+    #[test]
+    fn cannot_resolve_unknown_field() {
+        let json_schema = parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{ "type": "object", "properties": { "id": { "type": "string" } } }]
+        }))
+        .unwrap();
+        let selector =
+            Selector::try_from(r#"{"i": {"i": 0, ">": {"f": {"f>": {"unknown": {".": {}}}}}}}"#)
+                .unwrap();
+
+        let res = resolve_selector_fields(&selector, &json_schema);
+
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("unknown field in json schema: unknown"));
+    }
+
+    #[test]
+    fn can_validate_pairing_of_nested_unions() {
+        let tail = r#"{"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {".": {}}}}]}}}]}"#;
+        let head = r#"{"i": {"i": 0, ">": {"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {".": {}}}}, {"i": {"i": 2, ">": {".": {}}}}]}}}"#;
+
+        validate_selectors_pairing(tail, head).unwrap();
+    }
+
+    #[test]
+    fn cannot_validate_pairing_of_unions_with_different_branches_count() {
+        let tail = r#"{"|": [{"i": {"i": 0, ">": {".": {}}}}, {"i": {"i": 1, ">": {".": {}}}}]}"#;
+        let head = r#"{".": {}}"#;
+
+        let res = validate_selectors_pairing(tail, head);
+
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("tail selector produces 2 data set(s) while head selector expects 1"));
+    }
 }
@@ -0,0 +1,269 @@
+//! Compact path syntax for Holium selectors.
+//!
+//! A path is a list of segments separated by `/`, applied from the root of a Holium data object:
+//! - `3` explores the element at index 3,
+//! - `2..5` explores elements from index 2 (included) to index 5 (excluded),
+//! - `*` explores all elements,
+//! - `name` explores the property `name` of an object, resolved through the node JSON schema,
+//! - `{a,b/c}` explores multiple sub-paths, each producing its own data set.
+//!
+//! The `.` path matches the root of the object itself.
+
+use crate::utils::interplanetary::kinds::selector::{
+    ExploreAll, ExploreFields, ExploreIndex, ExploreRange, ExploreUnion, Matcher, Selector,
+};
+use anyhow::Result;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("empty segment at position {0} in selector path")]
+    EmptySegment(usize),
+    #[error("unexpected character '{0}' at position {1} in selector path")]
+    UnexpectedCharacter(char, usize),
+    #[error("unclosed group in selector path")]
+    UnclosedGroup,
+    #[error("invalid range in selector path: {0}")]
+    InvalidRange(String),
+}
+
+/// Characters with a special meaning in selector paths
+const RESERVED_CHARACTERS: [char; 5] = ['/', ',', '{', '}', '*'];
+
+/// Segment of a selector path
+enum Segment {
+    Index(u64),
+    Range(u64, u64),
+    All,
+    Field(String),
+    Group(Vec<Vec<Segment>>),
+}
+
+/// Parse a selector path into a [Selector].
+pub fn parse_selector_path(path: &str) -> Result<Selector> {
+    let chars: Vec<char> = path.trim().chars().collect();
+    let mut position = 0usize;
+    let segments = parse_alternative(&chars, &mut position)?;
+    if let Some(c) = chars.get(position) {
+        return Err(Error::UnexpectedCharacter(*c, position).into());
+    }
+    compile_segments(&segments, Selector::Matcher(Matcher { label: None }))
+}
+
+/// Write a [Selector] in the path syntax, if it can be expressed with it.
+pub fn selector_to_path(selector: &Selector) -> Option<String> {
+    match selector {
+        Selector::Matcher(matcher) if matcher.label.is_none() => Some(".".to_string()),
+        Selector::ExploreIndex(explore_index) => Some(join_path(
+            &explore_index.index.to_string(),
+            &selector_to_path(&explore_index.next)?,
+        )),
+        Selector::ExploreRange(explore_range) => Some(join_path(
+            &format!("{}..{}", explore_range.start, explore_range.end),
+            &selector_to_path(&explore_range.next)?,
+        )),
+        Selector::ExploreAll(explore_all) => {
+            Some(join_path("*", &selector_to_path(&explore_all.next)?))
+        }
+        Selector::ExploreFields(explore_fields) => {
+            let paths = explore_fields
+                .fields
+                .iter()
+                .map(|(name, next)| {
+                    if !is_valid_field_name(name) {
+                        return None;
+                    }
+                    Some(join_path(name, &selector_to_path(next)?))
+                })
+                .collect::<Option<Vec<String>>>()?;
+            if paths.len() == 1 {
+                return paths.into_iter().next();
+            }
+            Some(format!("{{{}}}", paths.join(",")))
+        }
+        Selector::ExploreUnion(explore_union) => {
+            let paths = explore_union
+                .0
+                .iter()
+                .map(selector_to_path)
+                .collect::<Option<Vec<String>>>()?;
+            Some(format!("{{{}}}", paths.join(",")))
+        }
+        _ => None,
+    }
+}
+
+fn join_path(segment: &str, next_path: &str) -> String {
+    if next_path == "." {
+        segment.to_string()
+    } else {
+        format!("{}/{}", segment, next_path)
+    }
+}
+
+fn is_valid_field_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != "."
+        && !name.contains("..")
+        && name.parse::<u64>().is_err()
+        && !name.contains(&RESERVED_CHARACTERS[..])
+        && name.trim() == name
+}
+
+/// Parse segments separated by `/` until the end of the path, a `,` or a `}`
+fn parse_alternative(chars: &[char], position: &mut usize) -> Result<Vec<Segment>> {
+    // a lone `.` matches the current node
+    if chars.get(*position) == Some(&'.')
+        && matches!(chars.get(*position + 1), None | Some(',') | Some('}'))
+    {
+        *position += 1;
+        return Ok(vec![]);
+    }
+    let mut segments = vec![parse_segment(chars, position)?];
+    while chars.get(*position) == Some(&'/') {
+        *position += 1;
+        segments.push(parse_segment(chars, position)?);
+    }
+    Ok(segments)
+}
+
+fn parse_segment(chars: &[char], position: &mut usize) -> Result<Segment> {
+    match chars.get(*position) {
+        Some('{') => {
+            *position += 1;
+            let mut alternatives = vec![parse_alternative(chars, position)?];
+            loop {
+                match chars.get(*position) {
+                    Some(',') => {
+                        *position += 1;
+                        alternatives.push(parse_alternative(chars, position)?);
+                    }
+                    Some('}') => {
+                        *position += 1;
+                        return Ok(Segment::Group(alternatives));
+                    }
+                    Some(c) => return Err(Error::UnexpectedCharacter(*c, *position).into()),
+                    None => return Err(Error::UnclosedGroup.into()),
+                }
+            }
+        }
+        Some('*') => {
+            *position += 1;
+            Ok(Segment::All)
+        }
+        _ => {
+            let start = *position;
+            while let Some(c) = chars.get(*position) {
+                if RESERVED_CHARACTERS.contains(c) {
+                    break;
+                }
+                *position += 1;
+            }
+            let token: String = chars[start..*position].iter().collect();
+            let token = token.trim();
+            if token.is_empty() || token == "." {
+                return Err(Error::EmptySegment(start).into());
+            }
+            if let Some((range_start, range_end)) = token.split_once("..") {
+                let parse_bound = |bound: &str| {
+                    bound
+                        .trim()
+                        .parse::<u64>()
+                        .map_err(|_| Error::InvalidRange(token.to_string()))
+                };
+                let (range_start, range_end) = (parse_bound(range_start)?, parse_bound(range_end)?);
+                if range_start >= range_end {
+                    return Err(Error::InvalidRange(token.to_string()).into());
+                }
+                return Ok(Segment::Range(range_start, range_end));
+            }
+            if let Ok(index) = token.parse::<u64>() {
+                return Ok(Segment::Index(index));
+            }
+            Ok(Segment::Field(token.to_string()))
+        }
+    }
+}
+
+/// Compile segments into a selector, from the last one to the first one, `next` being the selector
+/// to apply after the last segment
+fn compile_segments(segments: &[Segment], next: Selector) -> Result<Selector> {
+    let mut selector = next;
+    for segment in segments.iter().rev() {
+        selector = match segment {
+            Segment::Index(index) => Selector::ExploreIndex(Box::new(ExploreIndex {
+                index: *index,
+                next: Box::new(selector),
+            })),
+            Segment::Range(start, end) => Selector::ExploreRange(Box::new(ExploreRange {
+                start: *start,
+                end: *end,
+                next: Box::new(selector),
+            })),
+            Segment::All => Selector::ExploreAll(Box::new(ExploreAll {
+                next: Box::new(selector),
+            })),
+            Segment::Field(name) => Selector::ExploreFields(Box::new(ExploreFields {
+                fields: vec![(name.clone(), selector)],
+            })),
+            Segment::Group(alternatives) => Selector::ExploreUnion(Box::new(ExploreUnion(
+                alternatives
+                    .iter()
+                    .map(|alternative| compile_segments(alternative, selector.clone()))
+                    .collect::<Result<Vec<Selector>>>()?,
+            ))),
+        };
+    }
+    Ok(selector)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn can_parse_selector_path() {
+        let expected = Selector::try_from(
+            r#"{"i": {"i": 0, ">": {"|": [{"i": {"i": 1, ">": {"r": {"^": 2, "$": 5, ">": {".": {}}}}}}, {"a": {">": {"f": {"f>": {"name": {".": {}}}}}}}]}}}"#,
+        )
+        .unwrap();
+
+        let selector = parse_selector_path("0/{1/2..5,*/name}").unwrap();
+
+        assert_eq!(expected, selector);
+    }
+
+    #[test]
+    fn can_write_selector_as_path() {
+        let path = "0/{1/2..5,*/name,.}";
+
+        let selector = parse_selector_path(path).unwrap();
+
+        assert_eq!(Some(path.to_string()), selector_to_path(&selector));
+    }
+
+    #[test]
+    fn can_parse_range_followed_by_segments() {
+        let expected = Selector::try_from(
+            r#"{"i": {"i": 0, ">": {"r": {"^": 2, "$": 5, ">": {"a": {">": {".": {}}}}}}}}"#,
+        )
+        .unwrap();
+
+        let selector = parse_selector_path("0/2..5/*").unwrap();
+
+        assert_eq!(expected, selector);
+        assert_eq!(Some("0/2..5/*".to_string()), selector_to_path(&selector));
+    }
+
+    #[test]
+    fn cannot_parse_unclosed_group() {
+        let res = parse_selector_path("0/{1,2");
+
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("unclosed group in selector path"));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::utils::errors::Error::{BinCodeSerializeFailed, DbOperationFailed};
 use crate::utils::interplanetary::context::InterplanetaryContext;
+use crate::utils::interplanetary::fs::constants::block_multicodec::BlockMulticodec;
 use crate::utils::interplanetary::fs::traits::as_ip_block::AsInterplanetaryBlock;
 use crate::utils::interplanetary::kinds::connection::Connection as ConnectionBlock;
 use crate::utils::interplanetary::kinds::dry_transformation::DryTransformation;
@@ -10,7 +11,6 @@ use crate::utils::interplanetary::kinds::module_bytecode_envelope::ModuleBytecod
 use crate::utils::interplanetary::kinds::pipeline::Pipeline;
 use crate::utils::interplanetary::kinds::pipeline_vertex::PipelineVertex;
 use crate::utils::interplanetary::kinds::selector::SelectorEnvelope;
-use crate::utils::interplanetary::multiformats::path_to_cid;
 use crate::utils::local::context::helpers::{build_connection_id, parse_node_typed_name, NodeType};
 use crate::utils::local::context::LocalContext;
 
@@ -24,10 +24,6 @@ use anyhow::{Context, Result};
 use cid::Cid;
 use std::collections::HashMap;
 use std::convert::TryFrom;
-use std::fs;
-use std::fs::File;
-use std::io::{Read, Seek, SeekFrom};
-use std::path::PathBuf;
 
 /// Ending bytes of any Pipeline interplanetary block
 const PIPELINE_BLOC_SUFFIX: &[u8; 18] =
@@ -84,48 +80,31 @@ fn import_pipeline(ip_context: &InterplanetaryContext) -> Result<Pipeline> {
     Pipeline::try_from(*pipeline_block)
 }
 
-/// Recognize a Pipeline interplanetary block.
-fn is_pipeline_block(path: &PathBuf) -> Result<bool> {
-    const SUFFIX_LEN: usize = PIPELINE_BLOC_SUFFIX.len();
-    // open the file
-    if !path.is_file() {
-        return Ok(false);
-    }
-    let mut f = File::open(path)?;
-    // read the last bytes
-    let len = f.seek(SeekFrom::End(0))?;
-    if len < SUFFIX_LEN as u64 {
-        return Ok(false);
-    }
-    f.seek(SeekFrom::End(0))?;
-    f.seek(SeekFrom::Current(-{ SUFFIX_LEN as i64 }))?;
-    let mut buffer = [0; SUFFIX_LEN];
-    f.read_exact(&mut buffer)?;
-    // compare it to Pipeline blocks suffix
-    Ok(buffer == *PIPELINE_BLOC_SUFFIX)
+/// Recognize a Pipeline interplanetary block from its content.
+fn is_pipeline_block(content: &[u8]) -> bool {
+    // only DAG-CBOR blocks ending like Pipeline blocks are considered
+    content.ends_with(PIPELINE_BLOC_SUFFIX)
 }
 
 /// Find the first Pipeline block in an interplanetary area
-fn find_pipeline_block(ip_context: &InterplanetaryContext) -> Result<Cid> {
-    let ip_area_path = &ip_context.ip_area_path;
-    if ip_area_path.is_dir() {
-        for first_level_entry in fs::read_dir(ip_area_path)? {
-            let first_level_path = first_level_entry?.path();
-            if first_level_path.is_dir() {
-                for second_level_entry in fs::read_dir(first_level_path)? {
-                    let second_level_path = second_level_entry?.path();
-                    if second_level_path.is_file() {
-                        if is_pipeline_block(&second_level_path)? {
-                            // compute related block path
-                            let cid = path_to_cid(&second_level_path, &ip_context)?;
-                            return Ok(cid);
-                        }
-                    }
-                }
-            }
+pub(crate) fn find_pipeline_block(ip_context: &InterplanetaryContext) -> Result<Cid> {
+    find_pipeline_blocks(ip_context)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::FindToFindPipelineBlock.into())
+}
+
+/// Find all Pipeline blocks in an interplanetary area
+pub(crate) fn find_pipeline_blocks(ip_context: &InterplanetaryContext) -> Result<Vec<Cid>> {
+    let mut pipeline_cids = Vec::new();
+    for cid in ip_context.block_store.list()? {
+        if cid.codec() == u64::from(&BlockMulticodec::DagCbor)
+            && is_pipeline_block(&ip_context.block_store.get(&cid)?)
+        {
+            pipeline_cids.push(cid);
         }
     }
-    Err(Error::FindToFindPipelineBlock.into())
+    Ok(pipeline_cids)
 }
 
 /// Use the interplanetary context to import a pipeline vertices into the local area.
@@ -247,7 +226,8 @@ fn import_transformation(
         ModuleBytecodeEnvelope::try_from(*module_bytecode_envelope_block)?;
     // fetch and parse module bytecode
     let module_bytecode_cid = &module_bytecode_envelope.module_bytecode_cid;
-    let module_bytecode = ModuleBytecode::read_from_ip_area(&module_bytecode_cid, &ip_context)?;
+    let module_bytecode =
+        ModuleBytecode::read_chunked_from_ip_area(&module_bytecode_cid, &ip_context)?;
     let json_schema_in = metadata
         .json_schema_in
         .as_ref()
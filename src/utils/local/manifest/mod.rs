@@ -0,0 +1,878 @@
+//! Reconcile the local area of a project with a declarative manifest, and dump the local area into
+//! such a manifest.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Error as AnyhowError, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use sled::transaction::{ConflictableTransactionResult, Transactional};
+
+use crate::utils::errors::Error::{
+    BinCodeDeserializeFailed, BinCodeSerializeFailed, DbOperationFailed,
+};
+use crate::utils::interplanetary::kinds::selector::Selector;
+use crate::utils::local::context::helpers::{
+    build_connection_id, build_named_portation_id, build_node_typed_name, build_portation_id,
+    db_key_to_str, parse_connection_id, parse_node_typed_name, parse_portation_id,
+    parse_portation_name, validate_node_name, validate_portation_name, NodeType,
+    PortationDirectionType,
+};
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::bytecode::{read_all_wasm_module, validate_wasm_function_export};
+use crate::utils::local::helpers::jsonschema::{
+    parse_root_json_schema, validate_pipeline_node_json_schema, HoliumJsonSchema,
+};
+use crate::utils::local::helpers::prints::json::json_literal_to_value;
+use crate::utils::local::helpers::selector::path::selector_to_path;
+use crate::utils::local::helpers::selector::{
+    parse_selector_argument, validate_selector, validate_selectors_pairing,
+};
+use crate::utils::local::models::connection::Connection;
+use crate::utils::local::models::shaper::Shaper;
+use crate::utils::local::models::source::Source;
+use crate::utils::local::models::transformation::Transformation;
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::helpers::to_relative_path_to_root;
+use crate::utils::repo::models::manifest::{
+    Manifest, ManifestConnection, ManifestJsonSchema, ManifestNode, ManifestPortation,
+    ManifestTransformation,
+};
+use crate::utils::repo::models::portation::Portation;
+use crate::utils::repo::ports::helpers::{validate_import_portations, validate_portation_for_node};
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("object declared more than once in the manifest: {0}")]
+    DuplicateObject(String),
+    #[error("unknown node in the manifest: {0}")]
+    UnknownNode(String),
+    #[error("failed to read json schema file: {0}")]
+    FailedToReadJsonSchemaFile(String),
+    #[error("invalid json schema for {0}")]
+    InvalidJsonSchema(String),
+    #[error("invalid selector for connection {0}")]
+    InvalidSelector(String),
+    #[error("invalid bytecode for transformation {0}")]
+    InvalidBytecode(String),
+    #[error("invalid portation for node {0}")]
+    InvalidPortation(String),
+    #[error("failed to write bytecode file: {0}")]
+    FailedToWriteBytecode(String),
+}
+
+/// Kind of change to apply to an object of the local area.
+#[derive(Debug, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Create,
+    Update,
+    Delete,
+}
+
+/// Type of the objects a manifest describes.
+#[derive(Debug, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ObjectType {
+    Source,
+    Shaper,
+    Transformation,
+    Connection,
+    Portation,
+}
+
+impl fmt::Display for ObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ObjectType::Source => "source",
+            ObjectType::Shaper => "shaper",
+            ObjectType::Transformation => "transformation",
+            ObjectType::Connection => "connection",
+            ObjectType::Portation => "portation",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Change to apply to an object of the local area, identified by its type and key.
+#[derive(Debug, Serialize)]
+pub struct ManifestChange {
+    pub kind: ChangeKind,
+    pub object_type: ObjectType,
+    pub key: String,
+}
+
+/// Objects described by a manifest, indexed by their keys in the local area.
+#[derive(Default)]
+struct DesiredState {
+    sources: BTreeMap<String, Source>,
+    shapers: BTreeMap<String, Shaper>,
+    transformations: BTreeMap<String, Transformation>,
+    connections: BTreeMap<String, Connection>,
+    portations: BTreeMap<String, Portation>,
+}
+
+/// Ordered list of changes reconciling the local area with a manifest.
+pub struct ManifestPlan {
+    pub changes: Vec<ManifestChange>,
+    desired: DesiredState,
+}
+
+/// Validate a manifest and compute the changes needed for the local area to match it. Relative
+/// paths of schema and bytecode files are resolved from the manifest directory.
+pub fn plan_manifest(
+    local_context: &LocalContext,
+    repo_context: &RepositoryContext,
+    manifest: &Manifest,
+    manifest_dir: &Path,
+) -> Result<ManifestPlan> {
+    let desired = resolve_manifest(manifest, manifest_dir, &local_context.root_path)?;
+    // links between nodes are deleted before nodes, and created or updated after them
+    let mut deletions: Vec<ManifestChange> = Vec::new();
+    let mut node_upserts: Vec<ManifestChange> = Vec::new();
+    let mut link_upserts: Vec<ManifestChange> = Vec::new();
+    diff_objects(
+        ObjectType::Connection,
+        &read_tree(&local_context.connections)?,
+        &desired.connections,
+        |current: &Connection, desired| {
+            json_literal_to_value(&current.tail_selector)
+                == json_literal_to_value(&desired.tail_selector)
+                && json_literal_to_value(&current.head_selector)
+                    == json_literal_to_value(&desired.head_selector)
+        },
+        &mut deletions,
+        &mut link_upserts,
+    );
+    let current_portations: BTreeMap<String, Portation> = repo_context
+        .portations
+        .values()
+        .map(|portation| (portation.id.clone(), portation.clone()))
+        .collect();
+    diff_objects(
+        ObjectType::Portation,
+        &current_portations,
+        &desired.portations,
+        |current, desired| {
+            current.file_path == desired.file_path
+                && current.file_format == desired.file_format
+                && current.file_pattern == desired.file_pattern
+                && current.file_name_field == desired.file_name_field
+                && current.selector.as_deref().map(json_literal_to_value)
+                    == desired.selector.as_deref().map(json_literal_to_value)
+        },
+        &mut deletions,
+        &mut link_upserts,
+    );
+    diff_objects(
+        ObjectType::Source,
+        &read_tree(&local_context.sources)?,
+        &desired.sources,
+        |current: &Source, desired| {
+            json_literal_to_value(&current.json_schema)
+                == json_literal_to_value(&desired.json_schema)
+        },
+        &mut deletions,
+        &mut node_upserts,
+    );
+    diff_objects(
+        ObjectType::Shaper,
+        &read_tree(&local_context.shapers)?,
+        &desired.shapers,
+        |current: &Shaper, desired| {
+            json_literal_to_value(&current.json_schema)
+                == json_literal_to_value(&desired.json_schema)
+        },
+        &mut deletions,
+        &mut node_upserts,
+    );
+    diff_objects(
+        ObjectType::Transformation,
+        &read_tree(&local_context.transformations)?,
+        &desired.transformations,
+        |current: &Transformation, desired| {
+            current.bytecode == desired.bytecode
+                && current.handle == desired.handle
+                && json_literal_to_value(&current.json_schema_in)
+                    == json_literal_to_value(&desired.json_schema_in)
+                && json_literal_to_value(&current.json_schema_out)
+                    == json_literal_to_value(&desired.json_schema_out)
+        },
+        &mut deletions,
+        &mut node_upserts,
+    );
+    let mut changes = deletions;
+    changes.append(&mut node_upserts);
+    changes.append(&mut link_upserts);
+    Ok(ManifestPlan { changes, desired })
+}
+
+/// Apply the changes of a plan to the local area. Changes to pipeline nodes and connections are
+/// written in a single transaction, that also removes the data stored for deleted nodes. Portations
+/// are stored to their configuration file once that transaction succeeded.
+pub fn apply_manifest_plan(
+    local_context: &LocalContext,
+    repo_context: &mut RepositoryContext,
+    plan: &ManifestPlan,
+) -> Result<()> {
+    // encode every object before writing anything
+    let mut writes: Vec<(ObjectType, &String, Option<Vec<u8>>)> = Vec::new();
+    for change in plan.changes.iter() {
+        let key = &change.key;
+        let encoded = match (change.object_type, change.kind) {
+            (ObjectType::Portation, _) => continue,
+            (_, ChangeKind::Delete) => None,
+            (object_type, _) => Some(
+                match object_type {
+                    ObjectType::Source => bincode::serialize(&plan.desired.sources[key]),
+                    ObjectType::Shaper => bincode::serialize(&plan.desired.shapers[key]),
+                    ObjectType::Transformation => {
+                        bincode::serialize(&plan.desired.transformations[key])
+                    }
+                    ObjectType::Connection => bincode::serialize(&plan.desired.connections[key]),
+                    ObjectType::Portation => unreachable!(),
+                }
+                .context(BinCodeSerializeFailed)?,
+            ),
+        };
+        writes.push((change.object_type, key, encoded));
+    }
+
+    // write pipeline nodes and connections, and remove data of deleted nodes, all or nothing
+    let trees = [
+        &local_context.sources,
+        &local_context.shapers,
+        &local_context.transformations,
+        &local_context.connections,
+        &local_context.data,
+    ];
+    trees[..]
+        .transaction(|trees| -> ConflictableTransactionResult<(), ()> {
+            for (object_type, key, encoded) in writes.iter() {
+                let tree = &trees[tree_index(*object_type)];
+                match encoded {
+                    Some(encoded) => {
+                        tree.insert(key.as_str(), encoded.as_slice())?;
+                    }
+                    None => {
+                        tree.remove(key.as_str())?;
+                        if let Some(node_type) = node_type_of(*object_type) {
+                            trees[DATA_TREE_INDEX]
+                                .remove(build_node_typed_name(&node_type, key).as_str())?;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+        .ok()
+        .context(DbOperationFailed)?;
+
+    // portations live in a configuration file, stored once all changes are applied to it
+    let mut portations_changed = false;
+    for change in plan.changes.iter() {
+        let key = &change.key;
+        match (change.object_type, change.kind) {
+            (ObjectType::Portation, ChangeKind::Delete) => {
+                repo_context.portations.remove_transient(key);
+            }
+            (ObjectType::Portation, _) => {
+                repo_context
+                    .portations
+                    .insert_transient(key.clone(), plan.desired.portations[key].clone());
+            }
+            _ => continue,
+        }
+        portations_changed = true;
+    }
+    if portations_changed {
+        repo_context.portations.save()?;
+    }
+    Ok(())
+}
+
+/// Describe the local area in a manifest. The bytecode of transformations is written in a
+/// directory, relative to the manifest directory, that manifest entries refer to.
+pub fn dump_manifest(
+    local_context: &LocalContext,
+    repo_context: &RepositoryContext,
+    manifest_dir: &Path,
+    bytecode_dir: &str,
+) -> Result<Manifest> {
+    let mut manifest = Manifest::default();
+    for (name, source) in read_tree::<Source>(&local_context.sources)? {
+        manifest.sources.push(ManifestNode {
+            name,
+            json_schema: ManifestJsonSchema::Inline(json_literal_to_value(&source.json_schema)),
+        });
+    }
+    for (name, shaper) in read_tree::<Shaper>(&local_context.shapers)? {
+        manifest.shapers.push(ManifestNode {
+            name,
+            json_schema: ManifestJsonSchema::Inline(json_literal_to_value(&shaper.json_schema)),
+        });
+    }
+    let transformations = read_tree::<Transformation>(&local_context.transformations)?;
+    if !transformations.is_empty() {
+        fs::create_dir_all(manifest_dir.join(bytecode_dir))
+            .context(Error::FailedToWriteBytecode(bytecode_dir.to_string()))?;
+    }
+    for (name, transformation) in transformations {
+        let bytecode_path = format!("{}/{}.wasm", bytecode_dir, name);
+        fs::write(manifest_dir.join(&bytecode_path), &transformation.bytecode)
+            .context(Error::FailedToWriteBytecode(bytecode_path.clone()))?;
+        manifest.transformations.push(ManifestTransformation {
+            name,
+            bytecode: bytecode_path,
+            handle: transformation.handle,
+            json_schema_in: ManifestJsonSchema::Inline(json_literal_to_value(
+                &transformation.json_schema_in,
+            )),
+            json_schema_out: ManifestJsonSchema::Inline(json_literal_to_value(
+                &transformation.json_schema_out,
+            )),
+        });
+    }
+    for (id, connection) in read_tree::<Connection>(&local_context.connections)? {
+        let (tail, head) = parse_connection_id(&id)?;
+        manifest.connections.push(ManifestConnection {
+            tail: tail.to_string(),
+            tail_selector: dump_selector(&connection.tail_selector),
+            head: head.to_string(),
+            head_selector: dump_selector(&connection.head_selector),
+        });
+    }
+    let mut portations: Vec<&Portation> = repo_context.portations.values().collect();
+    portations.sort_by(|a, b| a.id.cmp(&b.id));
+    for portation in portations {
+        let (direction, node_typed_name) = parse_portation_id(&portation.id)?;
+        manifest.portations.push(ManifestPortation {
+            direction: direction.to_string(),
+            node: node_typed_name.to_string(),
+            name: parse_portation_name(&portation.id).map(str::to_string),
+            file_path: portation.file_path.clone(),
+            file_format: portation.file_format.clone(),
+            file_pattern: portation.file_pattern.clone(),
+            file_name_field: portation.file_name_field.clone(),
+            selector: portation.selector.as_deref().map(dump_selector),
+        });
+    }
+    Ok(manifest)
+}
+
+/// Validate the objects described by a manifest and index them by their keys.
+fn resolve_manifest(
+    manifest: &Manifest,
+    manifest_dir: &Path,
+    root_path: &Path,
+) -> Result<DesiredState> {
+    let mut desired = DesiredState::default();
+    for node in manifest.sources.iter() {
+        let typed_name = build_node_typed_name(&NodeType::source, &node.name);
+        validate_node_name(&node.name)?;
+        let object = Source {
+            name: node.name.clone(),
+            json_schema: resolve_json_schema(&node.json_schema, manifest_dir, &typed_name)?,
+        };
+        insert_unique(&mut desired.sources, node.name.clone(), object, &typed_name)?;
+    }
+    for node in manifest.shapers.iter() {
+        let typed_name = build_node_typed_name(&NodeType::shaper, &node.name);
+        validate_node_name(&node.name)?;
+        let object = Shaper {
+            name: node.name.clone(),
+            json_schema: resolve_json_schema(&node.json_schema, manifest_dir, &typed_name)?,
+        };
+        insert_unique(&mut desired.shapers, node.name.clone(), object, &typed_name)?;
+    }
+    for node in manifest.transformations.iter() {
+        let typed_name = build_node_typed_name(&NodeType::transformation, &node.name);
+        validate_node_name(&node.name)?;
+        let bytecode = read_all_wasm_module(&manifest_dir.join(&node.bytecode))
+            .context(Error::InvalidBytecode(node.name.clone()))?;
+        validate_wasm_function_export(&bytecode, &node.handle)
+            .context(Error::InvalidBytecode(node.name.clone()))?;
+        let object = Transformation {
+            name: node.name.clone(),
+            bytecode,
+            handle: node.handle.clone(),
+            json_schema_in: resolve_json_schema(&node.json_schema_in, manifest_dir, &typed_name)?,
+            json_schema_out: resolve_json_schema(&node.json_schema_out, manifest_dir, &typed_name)?,
+        };
+        insert_unique(
+            &mut desired.transformations,
+            node.name.clone(),
+            object,
+            &typed_name,
+        )?;
+    }
+    for connection in manifest.connections.iter() {
+        validate_node_declaration(&desired, &connection.tail)?;
+        validate_node_declaration(&desired, &connection.head)?;
+        let id = build_connection_id(&connection.tail, &connection.head);
+        let tail_selector = resolve_selector(&connection.tail_selector)
+            .context(Error::InvalidSelector(id.clone()))?;
+        let head_selector = resolve_selector(&connection.head_selector)
+            .context(Error::InvalidSelector(id.clone()))?;
+        validate_selectors_pairing(&tail_selector, &head_selector)
+            .context(Error::InvalidSelector(id.clone()))?;
+        let object = Connection {
+            id: id.clone(),
+            tail_selector,
+            head_selector,
+        };
+        insert_unique(&mut desired.connections, id.clone(), object, &id)?;
+    }
+    for portation in manifest.portations.iter() {
+        validate_node_declaration(&desired, &portation.node)?;
+        let direction = portation
+            .direction
+            .parse::<PortationDirectionType>()
+            .map_err(AnyhowError::msg)
+            .context(Error::InvalidPortation(portation.node.clone()))?;
+        let id = match &portation.name {
+            Some(name) => {
+                validate_portation_name(name)
+                    .context(Error::InvalidPortation(portation.node.clone()))?;
+                build_named_portation_id(&direction, &portation.node, name)
+            }
+            None => build_portation_id(&direction, &portation.node),
+        };
+        let selector = portation
+            .selector
+            .as_ref()
+            .map(resolve_selector)
+            .transpose()
+            .context(Error::InvalidPortation(portation.node.clone()))?;
+        // file paths are relative to the project root, and should not lead outside of it
+        let file_path = to_relative_path_to_root(root_path, &portation.file_path)
+            .context(Error::InvalidPortation(portation.node.clone()))?;
+        let object = Portation {
+            id: id.clone(),
+            file_path,
+            file_format: portation.file_format.clone(),
+            file_pattern: portation.file_pattern.clone(),
+            file_name_field: portation.file_name_field.clone(),
+            selector,
+        };
+        // validate the portation as on creation, against the node declared in the manifest
+        let node_json_schema = desired_node_json_schema(&desired, &portation.node, &direction)?;
+        validate_portation_for_node(root_path, &object, node_json_schema)
+            .context(Error::InvalidPortation(portation.node.clone()))?;
+        insert_unique(&mut desired.portations, id.clone(), object, &id)?;
+    }
+    // data entering a node can not be both fully and partially imported
+    let mut import_portations: BTreeMap<&str, Vec<&Portation>> = BTreeMap::new();
+    for portation in desired.portations.values() {
+        let (direction, node_typed_name) = parse_portation_id(&portation.id)?;
+        if direction == PortationDirectionType::toHolium {
+            import_portations
+                .entry(node_typed_name)
+                .or_default()
+                .push(portation);
+        }
+    }
+    for (node_typed_name, portations) in import_portations.iter() {
+        validate_import_portations(node_typed_name, portations)?;
+    }
+    Ok(desired)
+}
+
+/// Get the JSON literal of a schema declared in a manifest, and validate it.
+fn resolve_json_schema(
+    json_schema: &ManifestJsonSchema,
+    manifest_dir: &Path,
+    node_typed_name: &str,
+) -> Result<String> {
+    let literal = match json_schema {
+        ManifestJsonSchema::File { file } => fs::read_to_string(manifest_dir.join(file))
+            .context(Error::FailedToReadJsonSchemaFile(file.clone()))?
+            .trim()
+            .to_string(),
+        ManifestJsonSchema::Inline(value) => value.to_string(),
+    };
+    validate_pipeline_node_json_schema(&literal)
+        .context(Error::InvalidJsonSchema(node_typed_name.to_string()))?;
+    Ok(literal)
+}
+
+/// Get the JSON literal of a selector declared in a manifest, and validate it.
+fn resolve_selector(selector: &Value) -> Result<String> {
+    let literal = match selector {
+        Value::String(argument) => parse_selector_argument(argument)?,
+        value => value.to_string(),
+    };
+    validate_selector(&literal)?;
+    Ok(literal)
+}
+
+/// Represent a selector in a manifest, in the path syntax whenever possible.
+fn dump_selector(literal: &str) -> Value {
+    Selector::try_from(literal)
+        .ok()
+        .and_then(|selector| selector_to_path(&selector))
+        .map(Value::String)
+        .unwrap_or_else(|| json_literal_to_value(literal))
+}
+
+/// Check that a typed name refers to a node declared in the manifest.
+fn validate_node_declaration(desired: &DesiredState, node_typed_name: &str) -> Result<()> {
+    let (node_type, node_name) = parse_node_typed_name(node_typed_name)?;
+    let is_declared = match node_type {
+        NodeType::source => desired.sources.contains_key(&node_name),
+        NodeType::shaper => desired.shapers.contains_key(&node_name),
+        NodeType::transformation => desired.transformations.contains_key(&node_name),
+    };
+    if !is_declared {
+        return Err(Error::UnknownNode(node_typed_name.to_string()).into());
+    }
+    Ok(())
+}
+
+/// Get the json schema of the data entering or leaving a node declared in a manifest.
+fn desired_node_json_schema(
+    desired: &DesiredState,
+    node_typed_name: &str,
+    direction: &PortationDirectionType,
+) -> Result<HoliumJsonSchema> {
+    let (node_type, node_name) = parse_node_typed_name(node_typed_name)?;
+    let json_schema = match node_type {
+        NodeType::source => &desired.sources[&node_name].json_schema,
+        NodeType::shaper => &desired.shapers[&node_name].json_schema,
+        NodeType::transformation => {
+            let transformation = &desired.transformations[&node_name];
+            if direction == &PortationDirectionType::toHolium {
+                &transformation.json_schema_in
+            } else {
+                &transformation.json_schema_out
+            }
+        }
+    };
+    parse_root_json_schema(&json_literal_to_value(json_schema))
+}
+
+fn insert_unique<T>(
+    map: &mut BTreeMap<String, T>,
+    key: String,
+    object: T,
+    label: &str,
+) -> Result<()> {
+    if map.insert(key, object).is_some() {
+        return Err(Error::DuplicateObject(label.to_string()).into());
+    }
+    Ok(())
+}
+
+/// Push the deletions and the creations or updates needed to go from current to desired objects.
+fn diff_objects<T, F: Fn(&T, &T) -> bool>(
+    object_type: ObjectType,
+    current: &BTreeMap<String, T>,
+    desired: &BTreeMap<String, T>,
+    is_same: F,
+    deletions: &mut Vec<ManifestChange>,
+    upserts: &mut Vec<ManifestChange>,
+) {
+    let change = |kind: ChangeKind, key: &String| ManifestChange {
+        kind,
+        object_type,
+        key: key.clone(),
+    };
+    for key in current.keys().filter(|key| !desired.contains_key(*key)) {
+        deletions.push(change(ChangeKind::Delete, key));
+    }
+    for (key, desired_object) in desired.iter() {
+        match current.get(key) {
+            None => upserts.push(change(ChangeKind::Create, key)),
+            Some(current_object) if !is_same(current_object, desired_object) => {
+                upserts.push(change(ChangeKind::Update, key))
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Read all objects of a tree of the local area, indexed by their keys.
+fn read_tree<T: DeserializeOwned>(tree: &sled::Tree) -> Result<BTreeMap<String, T>> {
+    tree.iter()
+        .map(|o| -> Result<(String, T)> {
+            let (key_vec, encoded) = o.context(DbOperationFailed)?;
+            let key = db_key_to_str(key_vec)?;
+            let decoded: T = bincode::deserialize(&encoded[..])
+                .ok()
+                .context(BinCodeDeserializeFailed)?;
+            Ok((key, decoded))
+        })
+        .collect()
+}
+
+/// Index of the data tree in the set of trees written when applying a plan.
+const DATA_TREE_INDEX: usize = 4;
+
+fn tree_index(object_type: ObjectType) -> usize {
+    match object_type {
+        ObjectType::Source => 0,
+        ObjectType::Shaper => 1,
+        ObjectType::Transformation => 2,
+        ObjectType::Connection => 3,
+        ObjectType::Portation => unreachable!("portations are not stored in the local area"),
+    }
+}
+
+fn node_type_of(object_type: ObjectType) -> Option<NodeType> {
+    match object_type {
+        ObjectType::Source => Some(NodeType::source),
+        ObjectType::Shaper => Some(NodeType::shaper),
+        ObjectType::Transformation => Some(NodeType::transformation),
+        ObjectType::Connection | ObjectType::Portation => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::repo::models::portation::PortationFileFormat;
+
+    const JSON_SCHEMA: &str = r#"{"type": "array", "prefixItems": [{"type": "string"}]}"#;
+
+    fn manifest_with_source(json_schema: &str) -> Manifest {
+        Manifest {
+            sources: vec![ManifestNode {
+                name: "source".to_string(),
+                json_schema: ManifestJsonSchema::Inline(serde_json::from_str(json_schema).unwrap()),
+            }],
+            ..Manifest::default()
+        }
+    }
+
+    /// Directory holding the Wasm modules used in tests.
+    fn bytecode_dir() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("utils")
+            .join("local")
+            .join("helpers")
+            .join("bytecode")
+            .join("assets")
+    }
+
+    fn contexts() -> (LocalContext, RepositoryContext, tempfile::TempDir) {
+        let (local_context, dir) = LocalContext::new_tmp().unwrap();
+        let repo_context = RepositoryContext::from_root_path(&dir.path().to_path_buf()).unwrap();
+        (local_context, repo_context, dir)
+    }
+
+    #[test]
+    fn can_plan_and_apply_manifest() {
+        let (local_context, mut repo_context, dir) = contexts();
+        let manifest = manifest_with_source(JSON_SCHEMA);
+
+        let plan = plan_manifest(&local_context, &repo_context, &manifest, dir.path()).unwrap();
+        assert_eq!(plan.changes.len(), 1);
+        assert_eq!(plan.changes[0].kind, ChangeKind::Create);
+        assert_eq!(plan.changes[0].object_type, ObjectType::Source);
+
+        apply_manifest_plan(&local_context, &mut repo_context, &plan).unwrap();
+        let plan = plan_manifest(&local_context, &repo_context, &manifest, dir.path()).unwrap();
+        assert!(plan.changes.is_empty());
+    }
+
+    #[test]
+    fn can_plan_update_and_deletion() {
+        let (local_context, mut repo_context, dir) = contexts();
+        let plan = plan_manifest(
+            &local_context,
+            &repo_context,
+            &manifest_with_source(JSON_SCHEMA),
+            dir.path(),
+        )
+        .unwrap();
+        apply_manifest_plan(&local_context, &mut repo_context, &plan).unwrap();
+
+        let other_schema = r#"{"type": "array", "prefixItems": [{"type": "number"}]}"#;
+        let plan = plan_manifest(
+            &local_context,
+            &repo_context,
+            &manifest_with_source(other_schema),
+            dir.path(),
+        )
+        .unwrap();
+        assert_eq!(plan.changes[0].kind, ChangeKind::Update);
+
+        let plan = plan_manifest(
+            &local_context,
+            &repo_context,
+            &Manifest::default(),
+            dir.path(),
+        )
+        .unwrap();
+        assert_eq!(plan.changes[0].kind, ChangeKind::Delete);
+    }
+
+    #[test]
+    fn deleting_node_removes_its_data() {
+        let (local_context, mut repo_context, dir) = contexts();
+        let plan = plan_manifest(
+            &local_context,
+            &repo_context,
+            &manifest_with_source(JSON_SCHEMA),
+            dir.path(),
+        )
+        .unwrap();
+        apply_manifest_plan(&local_context, &mut repo_context, &plan).unwrap();
+        let node_typed_name = build_node_typed_name(&NodeType::source, "source");
+        local_context
+            .data
+            .insert(node_typed_name.as_str(), vec![0x80])
+            .unwrap();
+
+        let plan = plan_manifest(
+            &local_context,
+            &repo_context,
+            &Manifest::default(),
+            dir.path(),
+        )
+        .unwrap();
+        apply_manifest_plan(&local_context, &mut repo_context, &plan).unwrap();
+        assert!(!local_context.sources.contains_key("source").unwrap());
+        assert!(!local_context
+            .data
+            .contains_key(node_typed_name.as_str())
+            .unwrap());
+    }
+
+    #[test]
+    fn cannot_plan_connection_to_undeclared_node() {
+        let (local_context, repo_context, dir) = contexts();
+        let mut manifest = manifest_with_source(JSON_SCHEMA);
+        manifest.connections.push(ManifestConnection {
+            tail: "source:source".to_string(),
+            tail_selector: Value::from("."),
+            head: "shaper:shaper".to_string(),
+            head_selector: Value::from("."),
+        });
+
+        let res = plan_manifest(&local_context, &repo_context, &manifest, dir.path());
+
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("unknown node in the manifest: shaper:shaper"));
+    }
+
+    fn portation_to_source(file_path: &str) -> ManifestPortation {
+        ManifestPortation {
+            direction: "toHolium".to_string(),
+            node: "source:source".to_string(),
+            name: None,
+            file_path: file_path.to_string(),
+            file_format: PortationFileFormat::json,
+            file_pattern: None,
+            file_name_field: None,
+            selector: None,
+        }
+    }
+
+    #[test]
+    fn can_plan_portation_in_project() {
+        let (local_context, repo_context, dir) = contexts();
+        let mut manifest = manifest_with_source(JSON_SCHEMA);
+        manifest
+            .portations
+            .push(portation_to_source("data/./in.json"));
+
+        let plan = plan_manifest(&local_context, &repo_context, &manifest, dir.path()).unwrap();
+
+        assert_eq!(
+            plan.desired.portations["to:source:source"].file_path,
+            "data/in.json"
+        );
+    }
+
+    #[test]
+    fn cannot_plan_portation_outside_project() {
+        let (local_context, repo_context, dir) = contexts();
+        for file_path in ["../outside.json", "/outside.json"].iter() {
+            let mut manifest = manifest_with_source(JSON_SCHEMA);
+            manifest.portations.push(portation_to_source(file_path));
+
+            let res = plan_manifest(&local_context, &repo_context, &manifest, dir.path());
+
+            assert!(res.is_err());
+            assert!(format!("{:#}", res.err().unwrap())
+                .contains("file path outside the project directory"));
+        }
+    }
+
+    #[test]
+    fn cannot_plan_portation_with_invalid_selector() {
+        let (local_context, repo_context, dir) = contexts();
+        let mut manifest = manifest_with_source(JSON_SCHEMA);
+        let mut portation = portation_to_source("in.json");
+        portation.selector = Some(Value::from("3"));
+        manifest.portations.push(portation);
+
+        let res = plan_manifest(&local_context, &repo_context, &manifest, dir.path());
+
+        assert!(res.is_err());
+    }
+
+    fn manifest_with_transformation(handle: &str) -> Manifest {
+        Manifest {
+            transformations: vec![ManifestTransformation {
+                name: "transformation".to_string(),
+                bytecode: "import.wasm".to_string(),
+                handle: handle.to_string(),
+                json_schema_in: ManifestJsonSchema::Inline(
+                    serde_json::from_str(JSON_SCHEMA).unwrap(),
+                ),
+                json_schema_out: ManifestJsonSchema::Inline(
+                    serde_json::from_str(JSON_SCHEMA).unwrap(),
+                ),
+            }],
+            ..Manifest::default()
+        }
+    }
+
+    #[test]
+    fn can_plan_transformation_with_exported_handle() {
+        let (local_context, repo_context, _dir) = contexts();
+        let manifest = manifest_with_transformation("helloWorld");
+
+        let plan =
+            plan_manifest(&local_context, &repo_context, &manifest, &bytecode_dir()).unwrap();
+
+        assert_eq!(plan.changes[0].object_type, ObjectType::Transformation);
+    }
+
+    #[test]
+    fn cannot_plan_transformation_with_missing_handle() {
+        let (local_context, repo_context, _dir) = contexts();
+        let manifest = manifest_with_transformation("non_existent_handle");
+
+        let res = plan_manifest(&local_context, &repo_context, &manifest, &bytecode_dir());
+
+        assert!(res.is_err());
+        assert!(
+            format!("{:#}", res.err().unwrap()).contains("no function exported by the Wasm module")
+        );
+    }
+
+    #[test]
+    fn can_dump_manifest() {
+        let (local_context, mut repo_context, dir) = contexts();
+        let manifest = manifest_with_source(JSON_SCHEMA);
+        let plan = plan_manifest(&local_context, &repo_context, &manifest, dir.path()).unwrap();
+        apply_manifest_plan(&local_context, &mut repo_context, &plan).unwrap();
+
+        let dumped = dump_manifest(&local_context, &repo_context, dir.path(), "wasm").unwrap();
+
+        assert_eq!(dumped, manifest);
+    }
+}
@@ -3,4 +3,5 @@ pub mod dag;
 pub mod export;
 pub mod helpers;
 pub mod import;
+pub mod manifest;
 pub mod models;
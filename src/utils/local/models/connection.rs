@@ -3,10 +3,12 @@ use anyhow::Context;
 use optional_struct::OptionalStruct;
 use prettytable::{cell, row, Row};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 
 use crate::utils::errors::Error::BinCodeSerializeFailed;
-use crate::utils::local::helpers::prints::json::shorten_prettify_json_literal;
+use crate::utils::local::helpers::prints::json::json_literal_to_value;
 use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+use crate::utils::local::helpers::selector::display_selector;
 
 pub const TREE_NAME: &[u8] = b"connection";
 
@@ -47,16 +49,24 @@ impl PrintableModel for Connection {
     fn title_row() -> Row {
         row![
             b->"ID",
-            "TAIL SELECTOR (JSON Schema)",
-            "HEAD SELECTOR (JSON Schema)",
+            "TAIL SELECTOR",
+            "HEAD SELECTOR",
         ]
     }
 
     fn object_to_row(&self) -> Row {
         row![
             b->self.id,
-            shorten_prettify_json_literal(&self.tail_selector),
-            shorten_prettify_json_literal(&self.head_selector),
+            display_selector(&self.tail_selector),
+            display_selector(&self.head_selector),
         ]
     }
+
+    fn object_to_value(&self) -> Value {
+        json!({
+            "id": self.id,
+            "tail_selector": json_literal_to_value(&self.tail_selector),
+            "head_selector": json_literal_to_value(&self.head_selector),
+        })
+    }
 }
@@ -1,6 +1,11 @@
 use crate::utils::cbor::as_holium_cbor::AsHoliumCbor;
 use crate::utils::cbor::write_holium_cbor::WriteHoliumCbor;
 
+use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+
+use humansize::{file_size_opts, FileSize};
+use prettytable::{cell, row, Row};
+use serde_json::{json, Value};
 use sled::IVec;
 use std::io::Cursor;
 
@@ -28,3 +33,29 @@ impl AsHoliumCbor for IVec {
         Cursor::new(self)
     }
 }
+
+/// Summary of the HoliumCBOR data stored for a pipeline node
+pub struct NodeData {
+    pub node_typed_name: String,
+    pub size: usize,
+}
+
+impl PrintableModel for NodeData {
+    fn title_row() -> Row {
+        row![b->"NODE", "SIZE"]
+    }
+
+    fn object_to_row(&self) -> Row {
+        row![
+            b->self.node_typed_name,
+            self.size.file_size(file_size_opts::CONVENTIONAL).unwrap_or("".to_string()),
+        ]
+    }
+
+    fn object_to_value(&self) -> Value {
+        json!({
+            "node": self.node_typed_name,
+            "size": self.size,
+        })
+    }
+}
@@ -1,5 +1,6 @@
 pub mod connection;
 pub mod data;
+pub mod run;
 pub mod shaper;
 pub mod source;
 pub mod transformation;
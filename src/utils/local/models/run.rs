@@ -0,0 +1,348 @@
+//! Model related to the provenance records of pipeline runs, stored in the local Holium area.
+
+use humansize::{file_size_opts, FileSize};
+use prettytable::{cell, row, Row};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::utils::local::helpers::prints::json::json_literal_to_value;
+use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+use crate::utils::local::helpers::selector::display_selector;
+use crate::utils::run::runtime::RuntimeMetrics;
+
+pub const TREE_NAME: &[u8] = b"run";
+
+/// Provenance record of a run of the pipeline.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct Run {
+    #[serde(skip)]
+    pub id: String,
+    /// Start of the run, in milliseconds since the Unix epoch
+    pub started_at: u64,
+    /// End of the run, in milliseconds since the Unix epoch
+    pub finished_at: u64,
+    /// Nodes of the pipeline, in the order they have been run
+    pub nodes: Vec<RunNode>,
+    /// Cid of the pipeline exported along with the record, if any
+    pub pipeline_cid: Option<String>,
+    /// Cid of the interplanetary block holding the record, if exported
+    pub block_cid: Option<String>,
+}
+
+/// Provenance of the data of a node during a run.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct RunNode {
+    pub node_typed_name: String,
+    /// Cid of the data entering the node
+    pub input_cid: String,
+    /// Cid of the data leaving the node
+    pub output_cid: String,
+    /// Size of the data entering the node, in bytes
+    #[serde(default)]
+    pub input_size: u64,
+    /// Size of the data leaving the node, in bytes
+    #[serde(default)]
+    pub output_size: u64,
+    /// Cid of the raw bytecode, for transformations
+    pub bytecode_cid: Option<String>,
+    /// Handle of the function run, for transformations
+    pub handle: Option<String>,
+    /// Connections the data entering the node came from
+    pub connections: Vec<RunConnection>,
+    /// Portations the data entering the node was imported with
+    pub imports: Vec<RunPortation>,
+    /// Portations the data leaving the node was exported with
+    pub exports: Vec<RunPortation>,
+    /// Measurements made by the runtime, for transformations
+    #[serde(default)]
+    pub metrics: Option<RuntimeMetrics>,
+}
+
+/// Connection used during a run, with its selectors as resolved for the run.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct RunConnection {
+    pub id: String,
+    pub tail_selector: String,
+    pub head_selector: String,
+}
+
+/// Portation used during a run, with the cid of the ported file.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct RunPortation {
+    pub id: String,
+    pub file_path: String,
+    pub selector: Option<String>,
+    /// Cid of the content of the ported file, none for directory portations
+    pub file_cid: Option<String>,
+}
+
+impl Run {
+    /// Typed names of nodes whose output has been exported during the run, along with the path of
+    /// the written files.
+    pub fn node_exports(&self) -> Vec<(String, String)> {
+        self.nodes
+            .iter()
+            .flat_map(|node| {
+                node.exports
+                    .iter()
+                    .map(move |export| (node.node_typed_name.clone(), export.file_path.clone()))
+            })
+            .collect()
+    }
+
+    /// Report on the time and resources used by each node during the run.
+    pub fn report(&self) -> Vec<NodeReport> {
+        self.nodes
+            .iter()
+            .map(|node| NodeReport {
+                node_typed_name: node.node_typed_name.clone(),
+                input_size: node.input_size,
+                output_size: node.output_size,
+                metrics: node.metrics.clone(),
+            })
+            .collect()
+    }
+
+    fn duration(&self) -> String {
+        format!(
+            "{:.3}s",
+            self.finished_at.saturating_sub(self.started_at) as f64 / 1000.
+        )
+    }
+}
+
+/// Report on the time and resources used by a node during a run
+pub struct NodeReport {
+    pub node_typed_name: String,
+    pub input_size: u64,
+    pub output_size: u64,
+    pub metrics: Option<RuntimeMetrics>,
+}
+
+impl NodeReport {
+    fn time_cell(&self, time: fn(&RuntimeMetrics) -> Duration) -> String {
+        self.metrics
+            .as_ref()
+            .map(|metrics| format!("{:.3} ms", as_millis(time(metrics))))
+            .unwrap_or_else(|| "-".to_string())
+    }
+}
+
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.
+}
+
+fn display_size(size: u64) -> String {
+    size.file_size(file_size_opts::CONVENTIONAL)
+        .unwrap_or_default()
+}
+
+/// Get the current time, in milliseconds since the Unix epoch.
+pub fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Format a time given in milliseconds since the Unix epoch as an ISO 8601 UTC date and time.
+pub fn format_timestamp(millis: u64) -> String {
+    let (days, millis_of_day) = (millis / 86_400_000, millis % 86_400_000);
+    // convert days since the epoch to a civil date, in the proleptic Gregorian calendar
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        millis_of_day / 3_600_000,
+        millis_of_day / 60_000 % 60,
+        millis_of_day / 1000 % 60,
+        millis_of_day % 1000
+    )
+}
+
+impl PrintableModel for Run {
+    fn title_row() -> Row {
+        row![
+            b->"ID",
+            "STARTED AT",
+            "DURATION",
+            "NODES",
+            "EXPORTS",
+            "PIPELINE CID",
+        ]
+    }
+
+    fn object_to_row(&self) -> Row {
+        row![
+            b->self.id,
+            format_timestamp(self.started_at),
+            self.duration(),
+            self.nodes.len(),
+            self.node_exports().len(),
+            self.pipeline_cid.as_deref().unwrap_or_default(),
+        ]
+    }
+
+    fn object_to_value(&self) -> Value {
+        json!({
+            "id": self.id,
+            "started_at": format_timestamp(self.started_at),
+            "finished_at": format_timestamp(self.finished_at),
+            "pipeline_cid": self.pipeline_cid,
+            "block_cid": self.block_cid,
+            "nodes": self.nodes.iter().map(RunNode::object_to_value).collect::<Vec<Value>>(),
+        })
+    }
+}
+
+impl PrintableModel for RunNode {
+    fn title_row() -> Row {
+        row![
+            b->"NODE",
+            "INPUT CID",
+            "OUTPUT CID",
+            "BYTECODE CID",
+            "HANDLE",
+            "CONNECTIONS",
+            "PORTATIONS",
+        ]
+    }
+
+    fn object_to_row(&self) -> Row {
+        let connections: Vec<String> = self
+            .connections
+            .iter()
+            .map(|connection| {
+                format!(
+                    "{}\n  {} → {}",
+                    connection.id,
+                    display_selector(&connection.tail_selector),
+                    display_selector(&connection.head_selector)
+                )
+            })
+            .collect();
+        let portations: Vec<String> = self
+            .imports
+            .iter()
+            .chain(self.exports.iter())
+            .map(|portation| {
+                format!(
+                    "{}\n  {}\n  {}",
+                    portation.id,
+                    portation.file_path,
+                    portation.file_cid.as_deref().unwrap_or("-")
+                )
+            })
+            .collect();
+        row![
+            b->self.node_typed_name,
+            self.input_cid,
+            self.output_cid,
+            self.bytecode_cid.as_deref().unwrap_or_default(),
+            self.handle.as_deref().unwrap_or_default(),
+            connections.join("\n"),
+            portations.join("\n"),
+        ]
+    }
+
+    fn object_to_value(&self) -> Value {
+        let portation_to_value = |portation: &RunPortation| {
+            json!({
+                "id": portation.id,
+                "file_path": portation.file_path,
+                "selector": portation.selector.as_deref().map(json_literal_to_value),
+                "file_cid": portation.file_cid,
+            })
+        };
+        json!({
+            "node": self.node_typed_name,
+            "input_cid": self.input_cid,
+            "output_cid": self.output_cid,
+            "bytecode_cid": self.bytecode_cid,
+            "handle": self.handle,
+            "connections": self.connections.iter().map(|connection| json!({
+                "id": connection.id,
+                "tail_selector": json_literal_to_value(&connection.tail_selector),
+                "head_selector": json_literal_to_value(&connection.head_selector),
+            })).collect::<Vec<Value>>(),
+            "imports": self.imports.iter().map(portation_to_value).collect::<Vec<Value>>(),
+            "exports": self.exports.iter().map(portation_to_value).collect::<Vec<Value>>(),
+        })
+    }
+}
+
+impl PrintableModel for NodeReport {
+    fn title_row() -> Row {
+        row![
+            b->"NODE",
+            "COMPILATION",
+            "INSTANTIATION",
+            "EXECUTION",
+            "INPUT SIZE",
+            "OUTPUT SIZE",
+            "MEMORY",
+        ]
+    }
+
+    fn object_to_row(&self) -> Row {
+        row![
+            b->self.node_typed_name,
+            r->self.time_cell(|metrics| metrics.compilation_time),
+            r->self.time_cell(|metrics| metrics.instantiation_time),
+            r->self.time_cell(|metrics| metrics.execution_time),
+            r->display_size(self.input_size),
+            r->display_size(self.output_size),
+            r->self
+                .metrics
+                .as_ref()
+                .map(|metrics| display_size(metrics.memory_size))
+                .unwrap_or_else(|| "-".to_string()),
+        ]
+    }
+
+    fn object_to_value(&self) -> Value {
+        json!({
+            "node": self.node_typed_name,
+            "compilation_time_ms": self.metrics.as_ref().map(|m| as_millis(m.compilation_time)),
+            "instantiation_time_ms": self.metrics.as_ref().map(|m| as_millis(m.instantiation_time)),
+            "execution_time_ms": self.metrics.as_ref().map(|m| as_millis(m.execution_time)),
+            "input_size": self.input_size,
+            "output_size": self.output_size,
+            "memory_size": self.metrics.as_ref().map(|m| m.memory_size),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            format_timestamp(951_782_400_000),
+            "2000-02-29T00:00:00.000Z"
+        );
+        assert_eq!(
+            format_timestamp(1_700_000_000_123),
+            "2023-11-14T22:13:20.123Z"
+        );
+    }
+}
@@ -3,9 +3,12 @@ use anyhow::Context;
 use optional_struct::OptionalStruct;
 use prettytable::{cell, row, Row};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 
 use crate::utils::errors::Error::BinCodeSerializeFailed;
-use crate::utils::local::helpers::prints::json::shorten_prettify_json_literal;
+use crate::utils::local::helpers::prints::json::{
+    json_literal_to_value, shorten_prettify_json_literal,
+};
 use crate::utils::local::helpers::prints::printable_model::PrintableModel;
 
 pub const TREE_NAME: &[u8] = b"shaper";
@@ -54,4 +57,11 @@ impl PrintableModel for Shaper {
             shorten_prettify_json_literal(&self.json_schema),
         ]
     }
+
+    fn object_to_value(&self) -> Value {
+        json!({
+            "name": self.name,
+            "json_schema": json_literal_to_value(&self.json_schema),
+        })
+    }
 }
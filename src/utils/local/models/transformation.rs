@@ -5,9 +5,12 @@ use humansize::{file_size_opts, FileSize};
 use optional_struct::OptionalStruct;
 use prettytable::{cell, row, Row};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 
 use crate::utils::errors::Error::BinCodeSerializeFailed;
-use crate::utils::local::helpers::prints::json::shorten_prettify_json_literal;
+use crate::utils::local::helpers::prints::json::{
+    json_literal_to_value, shorten_prettify_json_literal,
+};
 use crate::utils::local::helpers::prints::printable_model::PrintableModel;
 
 pub const TREE_NAME: &[u8] = b"transformation";
@@ -75,4 +78,14 @@ impl PrintableModel for Transformation {
             shorten_prettify_json_literal(&self.json_schema_out),
         ]
     }
+
+    fn object_to_value(&self) -> Value {
+        json!({
+            "name": self.name,
+            "handle": self.handle,
+            "bytecode_size": self.bytecode.len(),
+            "json_schema_in": json_literal_to_value(&self.json_schema_in),
+            "json_schema_out": json_literal_to_value(&self.json_schema_out),
+        })
+    }
 }
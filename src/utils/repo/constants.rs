@@ -3,7 +3,15 @@ pub(crate) const HOLIUM_DIR: &'static str = ".holium";
 
 /// Name of the interplanetary Holium area directory.
 pub(crate) const INTERPLANETARY_DIR: &'static str = "interplanetary";
+/// Name of the interplanetary Holium area database, when blocks are stored in a single database.
+pub(crate) const INTERPLANETARY_DB_DIR: &'static str = "interplanetary_db";
 /// Name of the local Holium area directory.
 pub(crate) const LOCAL_DIR: &'static str = "local";
+/// Name of the project configuration file.
+pub(crate) const CONFIG_FILE: &'static str = "config";
 /// Name of the portions file.
 pub(crate) const PORTATIONS_FILE: &'static str = "portations";
+/// Name of the directory where corrupted interplanetary blocks are moved to.
+pub(crate) const QUARANTINE_DIR: &'static str = "quarantine";
+/// Name of the declarative manifest of a project, stored at its root.
+pub(crate) const MANIFEST_FILE: &'static str = "holium.yaml";
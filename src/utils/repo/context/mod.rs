@@ -4,8 +4,9 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 
-use crate::utils::repo::constants::{HOLIUM_DIR, PORTATIONS_FILE};
+use crate::utils::repo::constants::{CONFIG_FILE, HOLIUM_DIR, PORTATIONS_FILE};
 use crate::utils::repo::helpers::get_root_path;
+use crate::utils::repo::models::config::ProjectConfig;
 use crate::utils::repo::models::portation::Portations;
 
 #[derive(thiserror::Error, Debug)]
@@ -17,6 +18,7 @@ enum Error {
 /// Context structure helping accessing the repository area in a consistent way throughout the CLI
 /// commands.
 pub struct RepositoryContext {
+    pub config: ProjectConfig,
     pub portations: Portations,
 }
 
@@ -29,7 +31,7 @@ impl RepositoryContext {
     }
 
     /// Initialize an repository context from a project root path.
-    fn from_root_path(root_path: &PathBuf) -> Result<Self> {
+    pub fn from_root_path(root_path: &PathBuf) -> Result<Self> {
         // create the holium root directory if it does not exist
         let holium_root_path = root_path.join(HOLIUM_DIR);
         if !holium_root_path.exists() {
@@ -46,7 +48,9 @@ impl RepositoryContext {
         }
         // Get portations handler from the configuration file
         let portations = Portations::from_path(portations_file_path)?;
+        // Get project settings from the configuration file
+        let config = ProjectConfig::from_path(holium_root_path.join(CONFIG_FILE))?;
         // configure local context
-        Ok(RepositoryContext { portations })
+        Ok(RepositoryContext { config, portations })
     }
 }
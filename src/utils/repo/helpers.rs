@@ -1,9 +1,12 @@
-use crate::utils::repo::constants::HOLIUM_DIR;
+use crate::utils::repo::constants::{
+    CONFIG_FILE, HOLIUM_DIR, INTERPLANETARY_DIR, LOCAL_DIR, PORTATIONS_FILE,
+};
 use crate::utils::repo::errors::Error::OutsideHoliumRepo;
 use anyhow::{Context, Result};
 use path_clean::PathClean;
-use std::env;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::{env, fs};
 use thiserror;
 
 #[derive(thiserror::Error, Debug)]
@@ -28,6 +31,23 @@ pub fn get_root_path() -> Result<PathBuf> {
     }
 }
 
+/// Create the `.holium` directory of a new project, with its interplanetary and local areas and
+/// empty configuration files. The local area is ignored by git if the project is tracked by it.
+pub fn create_holium_dir(root_dir: &Path, is_scm_enabled: bool) -> Result<()> {
+    let holium_dir = root_dir.join(HOLIUM_DIR);
+    fs::create_dir(&holium_dir)?;
+    fs::create_dir(&holium_dir.join(INTERPLANETARY_DIR))?;
+    fs::create_dir(&holium_dir.join(LOCAL_DIR))?;
+    fs::File::create(&holium_dir.join(PORTATIONS_FILE))?;
+    fs::File::create(&holium_dir.join(CONFIG_FILE))?;
+    // add a .gitignore file
+    if is_scm_enabled {
+        let gitignore_file = fs::File::create(&holium_dir.join(".gitignore"))?;
+        writeln!(&gitignore_file, "{}", LOCAL_DIR)?;
+    }
+    Ok(())
+}
+
 /// Check that a path leads to a location that is part of the project
 /// TODO tests required here for security purposes
 pub fn to_relative_path_to_project_root(tested_path_os_str: &str) -> Result<String> {
@@ -37,8 +57,21 @@ pub fn to_relative_path_to_project_root(tested_path_os_str: &str) -> Result<Stri
     // is not part of the project, otherwise return an equivalent path string relative to the
     // project root directory.
     let root_path = get_root_path()?;
+    strip_root_path(&root_path, &path)
+}
+
+/// Same as [to_relative_path_to_project_root], for a path that is relative to the root directory
+/// of the project rather than to the current directory, if not absolute.
+pub fn to_relative_path_to_root(root_path: &Path, tested_path_str: &str) -> Result<String> {
+    let path = root_path.join(tested_path_str).clean();
+    strip_root_path(root_path, &path)
+}
+
+/// Strip the root directory of a project from a clean absolute path, failing if the path is not
+/// part of the project.
+fn strip_root_path(root_path: &Path, path: &Path) -> Result<String> {
     let stripped_path = path
-        .strip_prefix(&root_path)
+        .strip_prefix(root_path)
         .context(Error::UnsecureFilePath)?;
     let stripped_path_str = stripped_path.to_str().ok_or(Error::NonUnicodeFilePath)?;
     Ok(stripped_path_str.to_string())
@@ -0,0 +1,95 @@
+use std::fs::File;
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+
+use prettytable::{cell, row, Row};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_yaml;
+
+use crate::utils::interplanetary::block_store::BlockStoreKind;
+use crate::utils::interplanetary::multiformats::HashFunction;
+use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+
+/// Project-level settings, stored in the configuration file.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct ProjectConfig {
+    #[serde(skip)]
+    pub path: PathBuf,
+    /// Hash function used to compute the cid of newly written interplanetary blocks.
+    pub hash_function: HashFunction,
+    /// Backend storing the blocks of the interplanetary area.
+    pub block_store: BlockStoreKind,
+}
+
+impl ProjectConfig {
+    /// Create a [ProjectConfig] handler from the path of a configuration file. Default settings
+    /// are used if the file does not exist or is empty.
+    pub fn from_path(path: PathBuf) -> Result<Self> {
+        if !path.exists() {
+            return Ok(ProjectConfig {
+                path,
+                ..ProjectConfig::default()
+            });
+        }
+        let file = File::open(&path).context(anyhow!("failed to open configuration file"))?;
+        let metadata = file
+            .metadata()
+            .context(anyhow!("failed to read metadata of the configuration file"))?;
+        let mut config: ProjectConfig = if 0 < metadata.len() {
+            serde_yaml::from_reader(file).context(anyhow!("invalid configuration file"))?
+        } else {
+            ProjectConfig::default()
+        };
+        config.path = path;
+        Ok(config)
+    }
+
+    /// Store the current settings to the configuration file.
+    pub fn save(&self) -> Result<()> {
+        let file =
+            File::create(&self.path).context(anyhow!("failed to create configuration file"))?;
+        serde_yaml::to_writer(&file, &self).context(anyhow!("failed to write configuration file"))
+    }
+}
+
+impl PrintableModel for ProjectConfig {
+    fn title_row() -> Row {
+        row![b->"HASH FUNCTION", "BLOCK STORE"]
+    }
+
+    fn object_to_row(&self) -> Row {
+        row![b->self.hash_function, self.block_store]
+    }
+
+    fn object_to_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_use_default_config_without_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ProjectConfig::from_path(dir.path().join("config")).unwrap();
+        assert_eq!(config.hash_function, HashFunction::Blake3);
+        assert_eq!(config.block_store, BlockStoreKind::FlatFs);
+    }
+
+    #[test]
+    fn can_save_and_read_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config");
+        let mut config = ProjectConfig::from_path(path.clone()).unwrap();
+        config.hash_function = HashFunction::Sha2_256;
+        config.save().unwrap();
+        let config = ProjectConfig::from_path(path).unwrap();
+        assert_eq!(config.hash_function, HashFunction::Sha2_256);
+    }
+}
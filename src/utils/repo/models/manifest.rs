@@ -0,0 +1,163 @@
+//! Declarative description of a project pipeline, stored in a manifest file.
+
+use std::fs::File;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::utils::repo::models::portation::PortationFileFormat;
+
+/// Nodes, connections and portations of a project, as described in a manifest file.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Manifest {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<ManifestNode>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shapers: Vec<ManifestNode>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transformations: Vec<ManifestTransformation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub connections: Vec<ManifestConnection>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub portations: Vec<ManifestPortation>,
+}
+
+/// Source or shaper node.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestNode {
+    pub name: String,
+    pub json_schema: ManifestJsonSchema,
+}
+
+/// Transformation node. The path to the Wasm module is relative to the manifest file.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestTransformation {
+    pub name: String,
+    pub bytecode: String,
+    pub handle: String,
+    pub json_schema_in: ManifestJsonSchema,
+    pub json_schema_out: ManifestJsonSchema,
+}
+
+/// Connection between two nodes, identified by their typed names. Selectors are either strings,
+/// holding a JSON literal or a path, or inline selector objects.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestConnection {
+    pub tail: String,
+    pub tail_selector: Value,
+    pub head: String,
+    pub head_selector: Value,
+}
+
+/// Portation of the data entering (*toHolium*) or leaving (*fromHolium*) a node. The file path is
+/// relative to the project root. Directory portations also hold a file pattern, relative to the
+/// directory at the file path. Several portations of a node in a direction are told apart by their
+/// names. Partial portations hold a selector of the ported part of the node data, written as for
+/// connections.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestPortation {
+    pub direction: String,
+    pub node: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub file_path: String,
+    pub file_format: PortationFileFormat,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name_field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<Value>,
+}
+
+/// JSON schema, either written inline or referenced by a path relative to the manifest file.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ManifestJsonSchema {
+    File { file: String },
+    Inline(Value),
+}
+
+impl Manifest {
+    /// Read a manifest file.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let file = File::open(path).context(anyhow!(
+            "failed to open manifest file: {}",
+            path.to_string_lossy()
+        ))?;
+        serde_yaml::from_reader(file).context(anyhow!("invalid manifest file"))
+    }
+
+    /// Write the manifest to a file.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let file = File::create(path).context(anyhow!("failed to create manifest file"))?;
+        serde_yaml::to_writer(&file, &self).context(anyhow!("failed to write manifest file"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_manifest() {
+        let literal = r#"
+sources:
+  - name: source
+    json_schema:
+      type: array
+      prefixItems: [{ type: string }]
+transformations:
+  - name: transformation
+    bytecode: wasm/transformation.wasm
+    handle: main
+    json_schema_in: { file: schemas/in.json }
+    json_schema_out: { file: schemas/out.json }
+connections:
+  - tail: source:source
+    tail_selector: "."
+    head: transformation:transformation
+    head_selector: { ".": {} }
+portations:
+  - direction: toHolium
+    node: source:source
+    file_path: data.json
+    file_format: json
+"#;
+
+        let manifest: Manifest = serde_yaml::from_str(literal).unwrap();
+
+        assert_eq!(manifest.sources.len(), 1);
+        assert!(matches!(
+            manifest.sources[0].json_schema,
+            ManifestJsonSchema::Inline(_)
+        ));
+        assert_eq!(
+            manifest.transformations[0].json_schema_in,
+            ManifestJsonSchema::File {
+                file: "schemas/in.json".to_string()
+            }
+        );
+        assert_eq!(manifest.connections[0].tail_selector, Value::from("."));
+        assert_eq!(
+            manifest.portations[0].file_format,
+            PortationFileFormat::json
+        );
+    }
+
+    #[test]
+    fn cannot_parse_manifest_with_unknown_fields() {
+        let literal = "sources:\n  - name: source\n    schema: {}\n";
+
+        let res: std::result::Result<Manifest, _> = serde_yaml::from_str(literal);
+
+        assert!(res.is_err());
+    }
+}
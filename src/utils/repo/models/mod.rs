@@ -1 +1,3 @@
+pub mod config;
+pub mod manifest;
 pub mod portation;
@@ -9,9 +9,12 @@ use clap::arg_enum;
 
 use prettytable::{cell, row, Row};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use serde_yaml;
 
+use crate::utils::local::helpers::prints::json::json_literal_to_value;
 use crate::utils::local::helpers::prints::printable_model::PrintableModel;
+use crate::utils::local::helpers::selector::display_selector;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Portation {
@@ -19,6 +22,15 @@ pub struct Portation {
     pub id: String,
     pub file_path: String,
     pub file_format: PortationFileFormat,
+    /// Glob pattern selecting, in the directory at `file_path`, the files of a directory portation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_pattern: Option<String>,
+    /// Object property holding the name of the file of each item of a directory portation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name_field: Option<String>,
+    /// JSON literal of the selector of a partial portation, porting only part of the node data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
 }
 
 arg_enum! {
@@ -28,6 +40,10 @@ arg_enum! {
         cbor,
         csv,
         json,
+        msgpack,
+        ndjson,
+        parquet,
+        yaml,
     }
 }
 
@@ -61,7 +77,7 @@ impl Portations {
     }
 
     /// Store the current set of portations to the configuration file.
-    fn save(&self) -> Result<()> {
+    pub fn save(&self) -> Result<()> {
         let file = File::create(&self.path)
             .context(anyhow!("failed to create portations configuration file"))?;
         serde_yaml::to_writer(&file, &self.set)
@@ -85,10 +101,22 @@ impl Portations {
         self.save().map(|_| ok_res)
     }
 
+    /// Insert a portation for the lifetime of the handler only, without storing it to the
+    /// configuration file.
+    pub fn insert_transient(&mut self, k: String, v: Portation) -> Option<Portation> {
+        self.set.insert(k, v)
+    }
+
     pub fn remove(&mut self, k: &String) -> Result<Option<Portation>> {
         let ok_res = self.set.remove(k);
         self.save().map(|_| ok_res)
     }
+
+    /// Remove a portation for the lifetime of the handler only, without storing the change to the
+    /// configuration file.
+    pub fn remove_transient(&mut self, k: &String) -> Option<Portation> {
+        self.set.remove(k)
+    }
 }
 
 impl PrintableModel for Portation {
@@ -97,6 +125,8 @@ impl PrintableModel for Portation {
             b->"ID",
             "FILE FORMAT",
             "FILE PATH",
+            "FILE PATTERN",
+            "SELECTOR",
         ]
     }
 
@@ -105,6 +135,22 @@ impl PrintableModel for Portation {
             b->self.id,
             self.file_format,
             self.file_path,
+            self.file_pattern.as_deref().unwrap_or_default(),
+            self.selector
+                .as_deref()
+                .map(display_selector)
+                .unwrap_or_default(),
         ]
     }
+
+    fn object_to_value(&self) -> Value {
+        json!({
+            "id": self.id,
+            "file_format": self.file_format.to_string(),
+            "file_path": self.file_path,
+            "file_pattern": self.file_pattern,
+            "file_name_field": self.file_name_field,
+            "selector": self.selector.as_deref().map(json_literal_to_value),
+        })
+    }
 }
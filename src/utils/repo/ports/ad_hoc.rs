@@ -0,0 +1,157 @@
+//! Ad-hoc portations, declared for a single run of the pipeline rather than stored in the
+//! portations configuration file.
+//!
+//! They are declared as `<typed-name>=<path>`, where the path is relative to the current directory
+//! or is `-` for the standard input or output. Standard streams are staged in temporary files, so
+//! that ad-hoc portations are handled as any other portation. Several ad-hoc portations of a node
+//! in a direction are named after their order of declaration, so that imported data is combined in
+//! that order.
+
+use crate::utils::local::context::helpers::{
+    build_named_portation_id, build_portation_id, get_node_portations, parse_node_typed_name,
+    validate_pipeline_node_existence, PortationDirectionType,
+};
+use crate::utils::local::context::LocalContext;
+
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::models::portation::{Portation, PortationFileFormat};
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{copy, stdin, stdout, Write};
+use std::path::PathBuf;
+use tempfile::{tempdir, TempDir};
+
+/// Path standing for the standard input or output in the declaration of an ad-hoc portation
+pub const STANDARD_STREAM_PATH: &str = "-";
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("invalid ad-hoc portation, expected <typed-name>=<path|->: {0}")]
+    InvalidAdHocPortation(String),
+    #[error("standard {0} can only be used by a single ad-hoc portation")]
+    StandardStreamUsedMoreThanOnce(&'static str),
+    #[error("failed to create staging area for standard streams")]
+    FailedToCreateStagingArea,
+    #[error("failed to read standard input")]
+    FailedToReadStdin,
+    #[error("failed to write standard output")]
+    FailedToWriteStdout,
+}
+
+/// Ad-hoc portations registered in a repository context for a single run
+pub struct AdHocPortations {
+    /// Held so that staged standard streams are removed once the run is over
+    _staging_dir: TempDir,
+    stdout_staging_path: Option<PathBuf>,
+}
+
+impl AdHocPortations {
+    /// Parse declarations of ad-hoc portations and register them in the repository context,
+    /// replacing the stored portations of the same nodes in the same directions.
+    pub fn register(
+        local_context: &LocalContext,
+        repo_context: &mut RepositoryContext,
+        imports: &[&str],
+        exports: &[&str],
+        file_format: &PortationFileFormat,
+    ) -> Result<Self> {
+        let staging_dir = tempdir().context(Error::FailedToCreateStagingArea)?;
+        let mut stdin_staging_path: Option<PathBuf> = None;
+        let mut stdout_staging_path: Option<PathBuf> = None;
+        // number of ad-hoc portations declared so far for each node and direction
+        let mut declaration_counts: HashMap<String, usize> = HashMap::new();
+        let declarations = imports
+            .iter()
+            .map(|declaration| (PortationDirectionType::toHolium, declaration))
+            .chain(
+                exports
+                    .iter()
+                    .map(|declaration| (PortationDirectionType::fromHolium, declaration)),
+            );
+        for (direction, declaration) in declarations {
+            let (node_typed_name, path) = declaration
+                .split_once('=')
+                .filter(|(node_typed_name, path)| !node_typed_name.is_empty() && !path.is_empty())
+                .ok_or_else(|| Error::InvalidAdHocPortation(declaration.to_string()))?;
+            // validate the existence of the node
+            let (node_type, node_name) = parse_node_typed_name(node_typed_name)
+                .context(Error::InvalidAdHocPortation(declaration.to_string()))?;
+            let node_typed_name =
+                validate_pipeline_node_existence(local_context, &node_type, &node_name)?;
+            // locate the file to port from/to
+            let file_path = if path == STANDARD_STREAM_PATH {
+                let (staging_path, stream_name) = match direction {
+                    PortationDirectionType::toHolium => (&mut stdin_staging_path, "input"),
+                    PortationDirectionType::fromHolium => (&mut stdout_staging_path, "output"),
+                };
+                if staging_path.is_some() {
+                    return Err(Error::StandardStreamUsedMoreThanOnce(stream_name).into());
+                }
+                staging_path
+                    .insert(staging_dir.path().join(stream_name))
+                    .clone()
+            } else {
+                std::env::current_dir()?.join(path)
+            };
+            // replace stored portations of the node in this direction, on first declaration
+            let declaration_count = declaration_counts
+                .entry(build_portation_id(&direction, &node_typed_name))
+                .or_insert(0);
+            if *declaration_count == 0 {
+                let stored_ids: Vec<String> =
+                    get_node_portations(repo_context, &direction, &node_typed_name)?
+                        .into_iter()
+                        .map(|portation| portation.id.clone())
+                        .collect();
+                for stored_id in stored_ids.iter() {
+                    repo_context.portations.remove_transient(stored_id);
+                }
+            }
+            // register the portation, with an absolute path
+            let id = build_named_portation_id(
+                &direction,
+                &node_typed_name,
+                &format!("ad-hoc-{:03}", declaration_count),
+            );
+            *declaration_count += 1;
+            let portation = Portation {
+                id: id.clone(),
+                file_path: file_path.to_string_lossy().to_string(),
+                file_format: file_format.clone(),
+                file_pattern: None,
+                file_name_field: None,
+                selector: None,
+            };
+            repo_context.portations.insert_transient(id, portation);
+        }
+        // stage data of the standard input
+        if let Some(stdin_staging_path) = &stdin_staging_path {
+            let mut file =
+                File::create(stdin_staging_path).context(Error::FailedToCreateStagingArea)?;
+            copy(&mut stdin().lock(), &mut file).context(Error::FailedToReadStdin)?;
+        }
+        Ok(AdHocPortations {
+            _staging_dir: staging_dir,
+            stdout_staging_path,
+        })
+    }
+
+    /// Check if some data is exported to the standard output, which should then not be used for
+    /// anything else.
+    pub fn writes_to_stdout(&self) -> bool {
+        self.stdout_staging_path.is_some()
+    }
+
+    /// Copy data exported during the run to the standard output, if any.
+    pub fn flush_stdout(&self) -> Result<()> {
+        if let Some(stdout_staging_path) = &self.stdout_staging_path {
+            let mut file = File::open(stdout_staging_path).context(Error::FailedToWriteStdout)?;
+            let mut stdout = stdout();
+            copy(&mut file, &mut stdout).context(Error::FailedToWriteStdout)?;
+            stdout.flush().context(Error::FailedToWriteStdout)?;
+        }
+        Ok(())
+    }
+}
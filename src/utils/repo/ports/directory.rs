@@ -0,0 +1,545 @@
+//! Port arrays of items from and to directories, with one file per item.
+//!
+//! Files are selected by a glob pattern relative to the directory, and ported in the order of their
+//! relative paths. Items may hold the relative path of their file when they are objects with a text
+//! property dedicated to it and a single other property holding the file contents. Otherwise,
+//! items are exported to numbered files named after a pattern with a single wildcard.
+//!
+//! Files written by an export are recorded in a hidden file of the directory, so that the next
+//! export only removes these files and leaves other files of the directory untouched.
+
+use crate::utils::cbor::as_holium_cbor::MajorType;
+use crate::utils::cbor::helpers::generate_array_cbor_header;
+use crate::utils::local::context::helpers::PortationDirectionType;
+use crate::utils::local::helpers::jsonschema::{HoliumJsonSchema, HoliumJsonSchemaType};
+
+use crate::utils::repo::models::portation::Portation;
+use crate::utils::repo::ports::export_from_holium::export_from_holium_to_writer;
+use crate::utils::repo::ports::formats::{
+    items_array_schema, locate_array_items, Error as FormatError,
+};
+use crate::utils::repo::ports::import_to_holium::import_reader_to_holium;
+
+use anyhow::{Context, Result};
+use glob::{MatchOptions, Pattern};
+use sk_cbor::Value as CborValue;
+use sk_cbor::{cbor_text, write};
+use std::collections::HashSet;
+use std::fs;
+use std::io::{BufReader, BufWriter, Cursor, Read, Write};
+use std::path::{Component, Path};
+
+/// Options used to match relative file paths against file patterns. Wildcards do not match path
+/// separators, so that `**` is required to select files of subdirectories.
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Name of the file recording, in a directory, the relative paths of the files written by the last
+/// export to it
+const EXPORT_RECORD_FILE: &str = ".holium-exported-files";
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error(
+        "invalid file pattern, expected a glob pattern relative to the portation directory: {0}"
+    )]
+    InvalidFilePattern(String),
+    #[error(
+        "numbered files can only be exported with a file pattern holding a single * wildcard: {0}"
+    )]
+    InvalidNumberedFilePattern(String),
+    #[error("json schema should describe an array of items to be ported from/to a directory")]
+    InvalidSchemaForDirectory,
+    #[error("items should be objects with a text property {0} and a single other property to be ported with their file names")]
+    InvalidSchemaForFileNameField(String),
+    #[error("failed to list files of directory: {0}")]
+    FailedToListFiles(String),
+    #[error("failed to import file: {0}")]
+    FailedToImportFile(String),
+    #[error("failed to export file: {0}")]
+    FailedToExportFile(String),
+    #[error("failed to remove previously exported file: {0}")]
+    FailedToRemoveFile(String),
+    #[error("failed to read or write the record of exported files of directory: {0}")]
+    FailedToRecordExportedFiles(String),
+    #[error("invalid file name for an exported item, expected a relative path matching the file pattern: {0}")]
+    InvalidItemFileName(String),
+    #[error("several exported items share the same file name: {0}")]
+    DuplicateItemFileName(String),
+}
+
+/// Layout of the items of a directory portation
+struct DirectoryLayout<'a> {
+    is_wrapped: bool,
+    /// Positions of the file name and content properties in item objects, if any
+    file_name_positions: Option<(usize, usize)>,
+    content_schema: &'a HoliumJsonSchema,
+}
+
+impl<'a> DirectoryLayout<'a> {
+    fn try_new(json_schema: &'a HoliumJsonSchema, file_name_field: Option<&str>) -> Result<Self> {
+        let (is_wrapped, items_schema) =
+            items_array_schema(json_schema).ok_or(Error::InvalidSchemaForDirectory)?;
+        let file_name_field = match file_name_field {
+            Some(file_name_field) => file_name_field,
+            None => {
+                return Ok(DirectoryLayout {
+                    is_wrapped,
+                    file_name_positions: None,
+                    content_schema: items_schema,
+                })
+            }
+        };
+        let invalid_schema = || Error::InvalidSchemaForFileNameField(file_name_field.to_string());
+        let properties = match items_schema.1.as_ref() {
+            HoliumJsonSchemaType::Object(properties) if properties.len() == 2 => properties,
+            _ => return Err(invalid_schema().into()),
+        };
+        let name_position = properties
+            .iter()
+            .position(|property| property.0 .0.as_deref() == Some(file_name_field))
+            .ok_or_else(invalid_schema)?;
+        if !matches!(
+            properties[name_position].1.as_ref(),
+            HoliumJsonSchemaType::TextString
+        ) {
+            return Err(invalid_schema().into());
+        }
+        let content_position = 1 - name_position;
+        Ok(DirectoryLayout {
+            is_wrapped,
+            file_name_positions: Some((name_position, content_position)),
+            content_schema: &properties[content_position],
+        })
+    }
+}
+
+/// Validate that a file pattern is a glob pattern relative to a directory, and parse it.
+pub fn validate_file_pattern(file_pattern: &str) -> Result<Pattern> {
+    let is_relative = !file_pattern.is_empty()
+        && Path::new(file_pattern)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if !is_relative {
+        return Err(Error::InvalidFilePattern(file_pattern.to_string()).into());
+    }
+    Pattern::new(file_pattern).context(Error::InvalidFilePattern(file_pattern.to_string()))
+}
+
+/// Validate a directory portation of data described by a JSON schema, and return the JSON schema of
+/// the contents of each of its files.
+pub fn validate_directory_portation<'a>(
+    json_schema: &'a HoliumJsonSchema,
+    direction: &PortationDirectionType,
+    file_pattern: &str,
+    file_name_field: Option<&str>,
+) -> Result<&'a HoliumJsonSchema> {
+    validate_file_pattern(file_pattern)?;
+    let layout = DirectoryLayout::try_new(json_schema, file_name_field)?;
+    if direction == &PortationDirectionType::fromHolium && layout.file_name_positions.is_none() {
+        validate_numbered_file_pattern(file_pattern)?;
+    }
+    Ok(layout.content_schema)
+}
+
+/// Read the files of a directory matching a file pattern, and write them as a HoliumCBOR array with
+/// one item per file.
+pub fn import_directory_to_holium<W: Write>(
+    json_schema: &HoliumJsonSchema,
+    portation: &Portation,
+    directory: &Path,
+    file_pattern: &str,
+    writer: &mut W,
+) -> Result<()> {
+    let layout = DirectoryLayout::try_new(json_schema, portation.file_name_field.as_deref())?;
+    let pattern = validate_file_pattern(file_pattern)?;
+    let relative_paths = list_matching_files(directory, &pattern)?;
+    // convert every file to a HoliumCBOR item
+    let mut items_buffer: Vec<u8> = Vec::new();
+    for relative_path in relative_paths.iter() {
+        let file = fs::File::open(directory.join(relative_path))
+            .context(Error::FailedToImportFile(relative_path.clone()))?;
+        let mut content = Vec::new();
+        import_reader_to_holium(
+            layout.content_schema,
+            &portation.file_format,
+            &mut BufReader::new(file),
+            &mut content,
+        )
+        .context(Error::FailedToImportFile(relative_path.clone()))?;
+        match layout.file_name_positions {
+            Some((name_position, _)) => {
+                let mut file_name = Vec::new();
+                write(cbor_text!(relative_path.as_str()), &mut file_name)
+                    .map_err(|_| FormatError::FailedToWriteHoliumCbor)?;
+                let properties = if name_position == 0 {
+                    [file_name, content]
+                } else {
+                    [content, file_name]
+                };
+                items_buffer.extend_from_slice(&generate_array_cbor_header(2));
+                for property in properties.iter() {
+                    items_buffer.extend_from_slice(property);
+                }
+            }
+            None => items_buffer.extend_from_slice(&content),
+        }
+    }
+    // write the HoliumCBOR array to the writer
+    if layout.is_wrapped {
+        writer
+            .write_all(&generate_array_cbor_header(1))
+            .context(FormatError::FailedToWriteHoliumCbor)?;
+    }
+    writer
+        .write_all(&generate_array_cbor_header(relative_paths.len() as u64))
+        .context(FormatError::FailedToWriteHoliumCbor)?;
+    writer
+        .write_all(&items_buffer)
+        .context(FormatError::FailedToWriteHoliumCbor)?;
+    Ok(())
+}
+
+/// Read a HoliumCBOR array and write each of its items in its own file of a directory. Files
+/// written by the previous export to the directory are removed first.
+pub fn export_directory_from_holium<R: Read>(
+    json_schema: &HoliumJsonSchema,
+    portation: &Portation,
+    directory: &Path,
+    file_pattern: &str,
+    reader: &mut R,
+) -> Result<()> {
+    let layout = DirectoryLayout::try_new(json_schema, portation.file_name_field.as_deref())?;
+    let pattern = validate_file_pattern(file_pattern)?;
+    // read the Holium CBOR contents and locate its items
+    let mut buffer = Vec::new();
+    reader.read_to_end(&mut buffer)?;
+    let items = locate_array_items(&buffer, layout.is_wrapped)?;
+    // name the file of every item and locate its contents
+    let mut files: Vec<(String, &MajorType)> = Vec::new();
+    match layout.file_name_positions {
+        Some((name_position, content_position)) => {
+            let mut file_names = HashSet::new();
+            for item in items.iter() {
+                let properties = match item {
+                    MajorType::Array(object) if object.elements.len() == 2 => &object.elements,
+                    _ => return Err(FormatError::IncompatibleSchemaAndValue.into()),
+                };
+                let file_name = match sk_cbor::read(item_bytes(&buffer, &properties[name_position]))
+                {
+                    Ok(CborValue::TextString(file_name)) => file_name,
+                    _ => return Err(FormatError::IncompatibleSchemaAndValue.into()),
+                };
+                if !is_valid_item_file_name(&file_name, &pattern) {
+                    return Err(Error::InvalidItemFileName(file_name).into());
+                }
+                if !file_names.insert(file_name.clone()) {
+                    return Err(Error::DuplicateItemFileName(file_name).into());
+                }
+                files.push((file_name, &properties[content_position]));
+            }
+        }
+        None => {
+            validate_numbered_file_pattern(file_pattern)?;
+            for (index, item) in items.iter().enumerate() {
+                files.push((numbered_file_name(file_pattern, index, items.len()), item));
+            }
+        }
+    }
+    // remove files written by the previous export, if any
+    for relative_path in read_export_record(directory)? {
+        let path = directory.join(&relative_path);
+        if path.is_file() {
+            fs::remove_file(path).context(Error::FailedToRemoveFile(relative_path))?;
+        }
+    }
+    // record the files about to be written, then write every item to its file
+    write_export_record(directory, &files)?;
+    for (relative_path, content) in files {
+        let path = directory.join(&relative_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context(Error::FailedToExportFile(relative_path.clone()))?;
+        }
+        let file =
+            fs::File::create(&path).context(Error::FailedToExportFile(relative_path.clone()))?;
+        let mut writer = BufWriter::new(file);
+        export_from_holium_to_writer(
+            layout.content_schema,
+            &portation.file_format,
+            &mut Cursor::new(item_bytes(&buffer, content)),
+            &mut writer,
+        )
+        .context(Error::FailedToExportFile(relative_path.clone()))?;
+        writer
+            .flush()
+            .context(Error::FailedToExportFile(relative_path))?;
+    }
+    Ok(())
+}
+
+/// Read the relative paths of the files written by the previous export to a directory. Paths that
+/// would lead out of the directory are ignored.
+fn read_export_record(directory: &Path) -> Result<Vec<String>> {
+    let record_path = directory.join(EXPORT_RECORD_FILE);
+    if !record_path.is_file() {
+        return Ok(Vec::new());
+    }
+    let record = fs::read_to_string(&record_path).context(Error::FailedToRecordExportedFiles(
+        directory.to_string_lossy().to_string(),
+    ))?;
+    Ok(record
+        .lines()
+        .filter(|relative_path| {
+            !relative_path.is_empty()
+                && Path::new(relative_path)
+                    .components()
+                    .all(|component| matches!(component, Component::Normal(_)))
+        })
+        .map(str::to_string)
+        .collect())
+}
+
+/// Record the relative paths of the files written by an export to a directory.
+fn write_export_record(directory: &Path, files: &[(String, &MajorType)]) -> Result<()> {
+    let error = || Error::FailedToRecordExportedFiles(directory.to_string_lossy().to_string());
+    fs::create_dir_all(directory).context(error())?;
+    let record: String = files
+        .iter()
+        .map(|(relative_path, _)| format!("{}\n", relative_path))
+        .collect();
+    fs::write(directory.join(EXPORT_RECORD_FILE), record).context(error())
+}
+
+/// Slice the bytes of a located HoliumCBOR value
+fn item_bytes<'a>(buffer: &'a [u8], item: &MajorType) -> &'a [u8] {
+    let (offset, size) = item.details();
+    &buffer[offset as usize..offset as usize + size]
+}
+
+/// List the files of a directory matching a pattern, with their paths relative to the directory, in
+/// the order of these paths. The record of exported files is left out.
+fn list_matching_files(directory: &Path, pattern: &Pattern) -> Result<Vec<String>> {
+    let mut relative_paths = Vec::new();
+    collect_relative_paths(directory, "", &mut relative_paths).context(
+        Error::FailedToListFiles(directory.to_string_lossy().to_string()),
+    )?;
+    let mut matching_paths: Vec<String> = relative_paths
+        .into_iter()
+        .filter(|relative_path| {
+            relative_path != EXPORT_RECORD_FILE
+                && pattern.matches_with(relative_path, MATCH_OPTIONS)
+        })
+        .collect();
+    matching_paths.sort();
+    Ok(matching_paths)
+}
+
+fn collect_relative_paths(
+    directory: &Path,
+    prefix: &str,
+    relative_paths: &mut Vec<String>,
+) -> std::io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let relative_path = if prefix.is_empty() {
+            file_name
+        } else {
+            format!("{}/{}", prefix, file_name)
+        };
+        if entry.file_type()?.is_dir() {
+            collect_relative_paths(&entry.path(), &relative_path, relative_paths)?;
+        } else if entry.path().is_file() {
+            relative_paths.push(relative_path);
+        }
+    }
+    Ok(())
+}
+
+/// Check that the file name held by an item is a relative path matching the file pattern.
+fn is_valid_item_file_name(file_name: &str, pattern: &Pattern) -> bool {
+    !file_name.is_empty()
+        && Path::new(file_name)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        && pattern.matches_with(file_name, MATCH_OPTIONS)
+}
+
+fn validate_numbered_file_pattern(file_pattern: &str) -> Result<()> {
+    let has_single_wildcard =
+        file_pattern.matches('*').count() == 1 && !file_pattern.contains(['?', '[', ']']);
+    if !has_single_wildcard {
+        return Err(Error::InvalidNumberedFilePattern(file_pattern.to_string()).into());
+    }
+    Ok(())
+}
+
+/// Name the file of an item after its index, replacing the wildcard of a file pattern with the
+/// index padded to the width of the largest one, so that files sort in the order of the items.
+fn numbered_file_name(file_pattern: &str, index: usize, nb_items: usize) -> String {
+    let width = nb_items.saturating_sub(1).to_string().len();
+    file_pattern.replacen('*', &format!("{:0width$}", index, width = width), 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::local::helpers::jsonschema::parse_root_json_schema;
+    use crate::utils::repo::models::portation::PortationFileFormat;
+    use serde_json::json;
+
+    fn portation(file_name_field: Option<&str>) -> Portation {
+        Portation {
+            id: "toHolium:source:images".to_string(),
+            file_path: "images".to_string(),
+            file_format: PortationFileFormat::bin,
+            file_pattern: Some("*.png".to_string()),
+            file_name_field: file_name_field.map(str::to_string),
+            selector: None,
+        }
+    }
+
+    fn named_files_json_schema() -> HoliumJsonSchema {
+        parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "content": { "type": "string", "contentEncoding": "base64" }
+                    }
+                }
+            }]
+        }))
+        .unwrap()
+    }
+
+    fn files_json_schema() -> HoliumJsonSchema {
+        parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{
+                "type": "array",
+                "items": { "type": "string", "contentEncoding": "base64" }
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn can_round_trip_named_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("b.png"), [2u8]).unwrap();
+        fs::write(dir.path().join("a.png"), [1u8]).unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+        let json_schema = named_files_json_schema();
+        let portation = portation(Some("name"));
+        // import files, ordered by name
+        let mut holium_cbor = Vec::new();
+        import_directory_to_holium(
+            &json_schema,
+            &portation,
+            dir.path(),
+            "*.png",
+            &mut holium_cbor,
+        )
+        .unwrap();
+        let value = sk_cbor::read(&holium_cbor).unwrap();
+        assert_eq!(
+            value,
+            sk_cbor::cbor_array_vec!(vec![sk_cbor::cbor_array_vec!(vec![
+                sk_cbor::cbor_array_vec!(vec![cbor_text!("a.png"), sk_cbor::cbor_bytes!(vec![1])]),
+                sk_cbor::cbor_array_vec!(vec![cbor_text!("b.png"), sk_cbor::cbor_bytes!(vec![2])]),
+            ])])
+        );
+        // export them to another directory
+        let out_dir = tempfile::tempdir().unwrap();
+        export_directory_from_holium(
+            &json_schema,
+            &portation,
+            out_dir.path(),
+            "*.png",
+            &mut Cursor::new(holium_cbor),
+        )
+        .unwrap();
+        assert_eq!(fs::read(out_dir.path().join("a.png")).unwrap(), vec![1]);
+        assert_eq!(fs::read(out_dir.path().join("b.png")).unwrap(), vec![2]);
+    }
+
+    fn export_numbered_files(directory: &Path, nb_items: u8) {
+        let holium_cbor = sk_cbor::cbor_array_vec!(vec![sk_cbor::cbor_array_vec!((0..nb_items)
+            .map(|i| sk_cbor::cbor_bytes!(vec![i]))
+            .collect::<Vec<CborValue>>())]);
+        let mut buffer = Vec::new();
+        write(holium_cbor, &mut buffer).unwrap();
+        export_directory_from_holium(
+            &files_json_schema(),
+            &portation(None),
+            directory,
+            "*.png",
+            &mut Cursor::new(buffer),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn can_export_numbered_files_and_remove_previous_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        export_numbered_files(dir.path(), 11);
+        assert_eq!(fs::read(dir.path().join("00.png")).unwrap(), vec![0]);
+        assert_eq!(fs::read(dir.path().join("10.png")).unwrap(), vec![10]);
+        // files of the previous export are replaced
+        export_numbered_files(dir.path(), 2);
+        assert!(!dir.path().join("00.png").exists());
+        assert!(!dir.path().join("10.png").exists());
+        assert_eq!(fs::read(dir.path().join("1.png")).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn can_export_files_without_removing_unrelated_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("user.png"), [42u8]).unwrap();
+        export_numbered_files(dir.path(), 2);
+        export_numbered_files(dir.path(), 1);
+        // the matching file of the user survives exports, and is not mistaken for one of them
+        assert_eq!(fs::read(dir.path().join("user.png")).unwrap(), vec![42]);
+        assert!(!dir.path().join("1.png").exists());
+        assert_eq!(fs::read(dir.path().join("0.png")).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn cannot_use_file_name_field_with_incompatible_schema() {
+        let json_schema = files_json_schema();
+        let res = validate_directory_portation(
+            &json_schema,
+            &PortationDirectionType::toHolium,
+            "*.png",
+            Some("name"),
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn cannot_use_pattern_out_of_directory() {
+        assert!(validate_file_pattern("*.png").is_ok());
+        assert!(validate_file_pattern("**/*.png").is_ok());
+        assert!(validate_file_pattern("../*.png").is_err());
+        assert!(validate_file_pattern("/tmp/*.png").is_err());
+    }
+
+    #[test]
+    fn cannot_export_numbered_files_without_single_wildcard() {
+        let json_schema = files_json_schema();
+        let res = validate_directory_portation(
+            &json_schema,
+            &PortationDirectionType::fromHolium,
+            "**/*.png",
+            None,
+        );
+        assert!(res.is_err());
+    }
+}
@@ -1,15 +1,24 @@
 use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::jsonschema::HoliumJsonSchema;
+use crate::utils::local::models::data::HoliumCbor;
 
-use crate::utils::repo::helpers::get_root_path;
 use crate::utils::repo::models::portation::{Portation, PortationFileFormat};
+use crate::utils::repo::ports::directory::export_directory_from_holium;
 use crate::utils::repo::ports::formats::bin::BinPorter;
 use crate::utils::repo::ports::formats::cbor::CborPorter;
 use crate::utils::repo::ports::formats::json::JsonPorter;
+use crate::utils::repo::ports::formats::msgpack::MsgpackPorter;
+use crate::utils::repo::ports::formats::ndjson::NdjsonPorter;
+use crate::utils::repo::ports::formats::parquet::ParquetPorter;
+use crate::utils::repo::ports::formats::yaml::YamlPorter;
 use crate::utils::repo::ports::formats::FormatPorter;
-use crate::utils::repo::ports::helpers::get_portation_json_schema;
+use crate::utils::repo::ports::helpers::{
+    get_portation_json_schema, get_portation_node_json_schema,
+};
+use crate::utils::repo::ports::partial::{resolve_portation_selector, select_partial_data};
 use anyhow::{bail, Context, Result};
 
-use std::io::Read;
+use std::io::{Cursor, Read, Write};
 
 #[derive(thiserror::Error, Debug)]
 enum Error {
@@ -17,7 +26,8 @@ enum Error {
     FailedToCreateFile(String, String),
 }
 
-/// Read Holium CBOR data from a Portation and write it in a file in the repository.
+/// Read Holium CBOR data from a Portation and write it in a file, or in the files of a directory,
+/// in the repository.
 pub fn export_from_holium<R: Read>(
     local_context: &LocalContext,
     portation: &Portation,
@@ -25,8 +35,37 @@ pub fn export_from_holium<R: Read>(
 ) -> Result<()> {
     // get json schema from the portation
     let json_schema = get_portation_json_schema(&local_context, &portation)?;
+    // only export the selected part of the node data for partial portations
+    if let Some(selector_literal) = &portation.selector {
+        let node_json_schema = get_portation_node_json_schema(local_context, portation)?;
+        let selector = resolve_portation_selector(selector_literal, &node_json_schema)?;
+        let mut data: HoliumCbor = Vec::new();
+        reader.read_to_end(&mut data)?;
+        let partial_data = select_partial_data(&portation.id, &data, &selector)?;
+        return export_to_file(
+            local_context,
+            portation,
+            &json_schema,
+            &mut Cursor::new(partial_data),
+        );
+    }
+    export_to_file(local_context, portation, &json_schema, reader)
+}
+
+/// Write Holium CBOR data, as described by a JSON schema, in the file or the files of a directory
+/// of a portation.
+fn export_to_file<R: Read>(
+    local_context: &LocalContext,
+    portation: &Portation,
+    json_schema: &HoliumJsonSchema,
+    reader: &mut R,
+) -> Result<()> {
+    let path = local_context.root_path.join(&portation.file_path);
+    // write items of directory portations in their own files
+    if let Some(file_pattern) = &portation.file_pattern {
+        return export_directory_from_holium(json_schema, portation, &path, file_pattern, reader);
+    }
     // open file from its path
-    let path = get_root_path()?.join(&portation.file_path);
     let mut file = std::fs::File::create(&path).context(Error::FailedToCreateFile(
         path.file_name()
             .map(|oss| oss.to_string_lossy().to_string())
@@ -34,15 +73,31 @@ pub fn export_from_holium<R: Read>(
         portation.id.clone(),
     ))?;
     // parse Holium CBOR data into the right format and write it
-    match portation.file_format {
-        PortationFileFormat::bin => BinPorter::export_from_holium(&json_schema, reader, &mut file),
-        PortationFileFormat::cbor => {
-            CborPorter::export_from_holium(&json_schema, reader, &mut file)
-        }
+    export_from_holium_to_writer(json_schema, &portation.file_format, reader, &mut file)
+}
+
+/// Read HoliumCBOR data and write it in a given file format, as described by a JSON schema.
+pub fn export_from_holium_to_writer<R: Read, W: Write>(
+    json_schema: &HoliumJsonSchema,
+    file_format: &PortationFileFormat,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<()> {
+    match file_format {
+        PortationFileFormat::bin => BinPorter::export_from_holium(json_schema, reader, writer),
+        PortationFileFormat::cbor => CborPorter::export_from_holium(json_schema, reader, writer),
         PortationFileFormat::csv => bail!("export to CSV format is not supported yet"),
-        PortationFileFormat::json => {
-            JsonPorter::export_from_holium(&json_schema, reader, &mut file)
+        PortationFileFormat::json => JsonPorter::export_from_holium(json_schema, reader, writer),
+        PortationFileFormat::msgpack => {
+            MsgpackPorter::export_from_holium(json_schema, reader, writer)
+        }
+        PortationFileFormat::ndjson => {
+            NdjsonPorter::export_from_holium(json_schema, reader, writer)
+        }
+        PortationFileFormat::parquet => {
+            ParquetPorter::export_from_holium(json_schema, reader, writer)
         }
+        PortationFileFormat::yaml => YamlPorter::export_from_holium(json_schema, reader, writer),
     }?;
     Ok(())
 }
@@ -18,17 +18,19 @@ impl FormatPorter for BinPorter {
         reader: &mut R,
         writer: &mut W,
     ) -> Result<()> {
-        // read the binary contents
-        let mut contents = Vec::new();
-        reader.read_to_end(&mut contents)?;
         // check that the json schema is coherent
-        validate_json_schema_for_bin_porter(&json_schema)?;
-        // read the CBOR contents
+        let is_wrapped = validate_json_schema_for_bin_porter(json_schema)?;
+        // read the binary contents
         let mut contents = Vec::new();
         reader.read_to_end(&mut contents)?;
         // encode the binary contents as a cbor byte string and write it
-        let holium_cbor = cbor_array_vec!(vec![cbor_bytes!(contents)]); // todo: we could just compute the cbor headers, and prepend them to contents
-                                                                        // write the HoliumCBOR to the writer
+        // todo: we could just compute the cbor headers, and prepend them to contents
+        let holium_cbor = if is_wrapped {
+            cbor_array_vec!(vec![cbor_bytes!(contents)])
+        } else {
+            cbor_bytes!(contents)
+        };
+        // write the HoliumCBOR to the writer
         let mut buffer: Vec<u8> = Vec::new();
         write(holium_cbor, &mut buffer).map_err(|_| Error::FailedToWriteHoliumCbor)?;
         writer
@@ -43,20 +45,24 @@ impl FormatPorter for BinPorter {
         writer: &mut W,
     ) -> Result<()> {
         // check that the json schema is coherent
-        validate_json_schema_for_bin_porter(&json_schema)?;
+        let is_wrapped = validate_json_schema_for_bin_porter(json_schema)?;
         // read the Holium CBOR contents
         let mut buffer = Vec::new();
         reader.read_to_end(&mut buffer)?;
         let holium_cbor_value =
             sk_cbor::read(&buffer).map_err(|_| Error::FailedToReadHoliumCborData)?;
         // get the expected inner byte string
-        let tuples_array = match holium_cbor_value {
-            CborValue::Array(tuples_array) => tuples_array,
-            _ => return Err(Error::IncompatibleSchemaAndValue.into()),
+        let sub_value = if is_wrapped {
+            let tuples_array = match &holium_cbor_value {
+                CborValue::Array(tuples_array) => tuples_array,
+                _ => return Err(Error::IncompatibleSchemaAndValue.into()),
+            };
+            tuples_array
+                .get(0)
+                .ok_or(Error::IncompatibleSchemaAndValue)?
+        } else {
+            &holium_cbor_value
         };
-        let sub_value = tuples_array
-            .get(0)
-            .ok_or(Error::IncompatibleSchemaAndValue)?;
         let bytes = match sub_value {
             CborValue::ByteString(bytes) => bytes,
             _ => return Err(Error::IncompatibleSchemaAndValue.into()),
@@ -68,19 +74,23 @@ impl FormatPorter for BinPorter {
     }
 }
 
-fn validate_json_schema_for_bin_porter(json_schema: &HoliumJsonSchema) -> Result<()> {
+/// Check that a json schema describes a byte string, which may be wrapped in a tuple as at the root
+/// of pipeline nodes. Return whether it is wrapped.
+fn validate_json_schema_for_bin_porter(json_schema: &HoliumJsonSchema) -> Result<bool> {
     let schema: &HoliumJsonSchemaType = &json_schema.1.as_ref();
-    let tuples_array = match schema {
-        HoliumJsonSchemaType::TupleArray(tuples_array) => tuples_array,
-        _ => return Err(Error::IncompatibleSchemaAndValue.into()),
+    let (is_wrapped, sub_schema) = match schema {
+        HoliumJsonSchemaType::TupleArray(tuples_array) => (
+            true,
+            tuples_array
+                .get(0)
+                .ok_or(Error::IncompatibleSchemaAndValue)?,
+        ),
+        _ => (false, json_schema),
     };
-    let sub_schema = tuples_array
-        .get(0)
-        .ok_or(Error::IncompatibleSchemaAndValue)?;
     let sub_schema: &HoliumJsonSchemaType = &sub_schema.1.as_ref();
     match sub_schema {
         HoliumJsonSchemaType::ByteString => {}
         _ => return Err(Error::IncompatibleSchemaAndValue.into()),
     }
-    Ok(())
+    Ok(is_wrapped)
 }
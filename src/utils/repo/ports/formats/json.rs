@@ -1,16 +1,15 @@
-use crate::utils::local::helpers::jsonschema::{HoliumJsonSchema, HoliumJsonSchemaType};
+use crate::utils::local::helpers::jsonschema::HoliumJsonSchema;
 
+use crate::utils::repo::ports::formats::value::{self, PortableValue};
 use crate::utils::repo::ports::formats::{Error, FormatPorter};
 
 use anyhow::{Context, Result};
+use serde::Deserialize;
 use serde_json::Map;
 use serde_json::{to_writer, Number, Value as JsonValue};
 use sk_cbor::write;
 use sk_cbor::SimpleValue;
 use sk_cbor::Value as CborValue;
-use sk_cbor::{
-    cbor_array_vec, cbor_bool, cbor_bytes, cbor_int, cbor_null, cbor_text, cbor_unsigned,
-};
 use std::io::Read;
 use std::io::Write;
 
@@ -54,116 +53,113 @@ impl FormatPorter for JsonPorter {
     }
 }
 
-fn import_value_to_holium(json_schema: &HoliumJsonSchema, v: &JsonValue) -> Result<CborValue> {
-    let schema: &HoliumJsonSchemaType = &json_schema.1.as_ref();
-    match (schema, v) {
-        (HoliumJsonSchemaType::Null, _) => Ok(cbor_null!()),
-        (HoliumJsonSchemaType::Boolean, JsonValue::Bool(v)) => Ok(cbor_bool!(*v)),
-        (HoliumJsonSchemaType::Number, JsonValue::Number(v)) => {
-            if let Some(v) = v.as_u64() {
-                Ok(cbor_unsigned!(v))
-            } else if let Some(v) = v.as_i64() {
-                Ok(cbor_int!(v))
-            } else {
-                Err(Error::UnhandledFloat.into())
+/// Convert a HoliumCBOR serialized value to JSON, as described by a JSON schema.
+pub fn holium_cbor_to_json(
+    json_schema: &HoliumJsonSchema,
+    holium_cbor: &[u8],
+) -> Result<JsonValue> {
+    let holium_cbor_value =
+        sk_cbor::read(holium_cbor).map_err(|_| Error::FailedToReadHoliumCborData)?;
+    export_value_from_holium(json_schema, &holium_cbor_value)
+}
+
+/// Convert a JSON value to serialized HoliumCBOR, as described by a JSON schema.
+pub fn json_to_holium_cbor(
+    json_schema: &HoliumJsonSchema,
+    json_value: &JsonValue,
+) -> Result<Vec<u8>> {
+    let holium_cbor_value = import_value_to_holium(json_schema, json_value)?;
+    let mut buffer: Vec<u8> = Vec::new();
+    write(holium_cbor_value, &mut buffer).map_err(|_| Error::FailedToWriteHoliumCbor)?;
+    Ok(buffer)
+}
+
+/// Convert a CBOR serialized value to JSON when no JSON schema describes it. Byte strings are
+/// encoded in base64.
+pub fn cbor_to_json(cbor: &[u8]) -> Result<JsonValue> {
+    let cbor_value = sk_cbor::read(cbor).map_err(|_| Error::FailedToReadCborData)?;
+    cbor_value_to_json(&cbor_value)
+}
+
+fn cbor_value_to_json(v: &CborValue) -> Result<JsonValue> {
+    match v {
+        CborValue::Unsigned(v) => Ok(JsonValue::Number(Number::from(*v))),
+        CborValue::Negative(v) => Ok(JsonValue::Number(Number::from(*v))),
+        CborValue::ByteString(v) => Ok(JsonValue::String(base64::encode(&v))),
+        CborValue::TextString(v) => Ok(JsonValue::String(v.clone())),
+        CborValue::Array(values) => Ok(JsonValue::Array(
+            values
+                .iter()
+                .map(cbor_value_to_json)
+                .collect::<Result<Vec<JsonValue>>>()?,
+        )),
+        CborValue::Map(tuples) => {
+            let mut map = Map::new();
+            for (k, v) in tuples.iter() {
+                let key = match cbor_value_to_json(k)? {
+                    JsonValue::String(key) => key,
+                    key => key.to_string(),
+                };
+                map.insert(key, cbor_value_to_json(v)?);
             }
+            Ok(JsonValue::Object(map))
         }
-        (HoliumJsonSchemaType::TextString, JsonValue::String(v)) => Ok(cbor_text!(v.clone())),
-        (HoliumJsonSchemaType::ByteString, JsonValue::String(v)) => {
-            // decode base64-encoded string to Vec<u8>
-            let bytes = base64::decode(&v).context("base64 decode error")?;
-            Ok(cbor_bytes!(bytes))
-        }
-        (HoliumJsonSchemaType::ItemsArray(ref items_schema), JsonValue::Array(values)) => {
-            let cbor_array = values
-                .into_iter()
-                .map(|v| import_value_to_holium(items_schema, &v))
-                .collect::<Result<Vec<CborValue>>>()?;
-            Ok(cbor_array_vec!(cbor_array))
-        }
-        (HoliumJsonSchemaType::TupleArray(ref tuple_schemata), JsonValue::Array(values)) => {
-            let cbor_array = tuple_schemata
-                .into_iter()
-                .zip(values.iter())
-                .map(|(schema, v)| import_value_to_holium(schema, &v))
-                .collect::<Result<Vec<CborValue>>>()?;
-            Ok(cbor_array_vec!(cbor_array))
-        }
-        (HoliumJsonSchemaType::Object(ref object_schemata), JsonValue::Object(values)) => {
-            let cbor_array = object_schemata
-                .into_iter()
-                .map(|s| {
-                    let key = s.0 .0.as_ref().ok_or(Error::MissingKeyInObjectTypeSchema)?;
-                    let value = values
-                        .get(key)
-                        .ok_or(Error::MissingObjectKey(key.to_string()))?;
-                    let cbor_value = import_value_to_holium(s, value)?;
-                    Ok(cbor_value)
-                })
-                .collect::<Result<Vec<CborValue>>>()?;
-            Ok(cbor_array_vec!(cbor_array))
-        }
-        _ => Err(Error::IncompatibleSchemaAndValue.into()),
+        CborValue::Tag(_, v) => cbor_value_to_json(v),
+        CborValue::Simple(SimpleValue::TrueValue) => Ok(JsonValue::Bool(true)),
+        CborValue::Simple(SimpleValue::FalseValue) => Ok(JsonValue::Bool(false)),
+        CborValue::Simple(_) => Ok(JsonValue::Null),
     }
 }
 
+fn import_value_to_holium(json_schema: &HoliumJsonSchema, v: &JsonValue) -> Result<CborValue> {
+    let portable_value = PortableValue::deserialize(v).context(Error::FailedToReadJsonData)?;
+    value::import_value_to_holium(json_schema, &portable_value)
+}
+
 fn export_value_from_holium(json_schema: &HoliumJsonSchema, v: &CborValue) -> Result<JsonValue> {
-    let boxed_schema = &json_schema.1;
-    let schema: &HoliumJsonSchemaType = boxed_schema.as_ref();
-    match (schema, v) {
-        (HoliumJsonSchemaType::Null, _) => Ok(JsonValue::Null),
-        (HoliumJsonSchemaType::Boolean, CborValue::Simple(SimpleValue::TrueValue)) => {
-            Ok(JsonValue::Bool(true))
-        }
-        (HoliumJsonSchemaType::Boolean, CborValue::Simple(SimpleValue::FalseValue)) => {
-            Ok(JsonValue::Bool(false))
-        }
-        (HoliumJsonSchemaType::Number, CborValue::Unsigned(v)) => Ok(JsonValue::Number(
-            Number::from_f64(*v as f64).ok_or(Error::FailedToConvertNumberFromHoliumCborToJson)?,
-        )),
-        (HoliumJsonSchemaType::Number, CborValue::Negative(v)) => Ok(JsonValue::Number(
-            Number::from_f64(*v as f64).ok_or(Error::FailedToConvertNumberFromHoliumCborToJson)?,
+    let portable_value = value::export_value_from_holium(json_schema, v)?;
+    portable_value_to_json(&portable_value)
+}
+
+/// Convert a portable value to JSON. Numbers are written as floats and byte strings are encoded in
+/// base64.
+fn portable_value_to_json(v: &PortableValue) -> Result<JsonValue> {
+    match v {
+        PortableValue::Null => Ok(JsonValue::Null),
+        PortableValue::Boolean(v) => Ok(JsonValue::Bool(*v)),
+        PortableValue::Unsigned(v) => float_to_json(*v as f64),
+        PortableValue::Negative(v) => float_to_json(*v as f64),
+        PortableValue::Float(v) => float_to_json(*v),
+        PortableValue::Text(v) => Ok(JsonValue::String(v.clone())),
+        // encode Vec<u8> to base64-encoded string
+        PortableValue::Bytes(v) => Ok(JsonValue::String(base64::encode(v))),
+        PortableValue::Array(values) => Ok(JsonValue::Array(
+            values
+                .iter()
+                .map(portable_value_to_json)
+                .collect::<Result<Vec<JsonValue>>>()?,
         )),
-        (HoliumJsonSchemaType::TextString, CborValue::TextString(v)) => {
-            Ok(JsonValue::String(v.clone()))
-        }
-        (HoliumJsonSchemaType::ByteString, CborValue::ByteString(v)) => {
-            // encode Vec<u8> to base64-encoded string
-            let bytes = base64::encode(&v);
-            Ok(JsonValue::String(bytes))
-        }
-        (HoliumJsonSchemaType::ItemsArray(ref items_schema), CborValue::Array(values)) => {
-            let json_array = values
-                .into_iter()
-                .map(|v| export_value_from_holium(items_schema, &v))
-                .collect::<Result<Vec<JsonValue>>>()?;
-            Ok(JsonValue::Array(json_array))
-        }
-        (HoliumJsonSchemaType::TupleArray(ref tuple_schemata), CborValue::Array(values)) => {
-            let json_array = tuple_schemata
-                .into_iter()
-                .zip(values.iter())
-                .map(|(schema, v)| export_value_from_holium(schema, &v))
-                .collect::<Result<Vec<JsonValue>>>()?;
-            Ok(JsonValue::Array(json_array))
-        }
-        (HoliumJsonSchemaType::Object(ref object_schemata), CborValue::Array(values)) => {
-            let mut cbor_map = Map::new();
-            for (s, v) in object_schemata.into_iter().zip(values.iter()) {
-                let key = s.0 .0.as_ref().ok_or(Error::MissingKeyInObjectTypeSchema)?;
-                let cbor_value = export_value_from_holium(s, v)?;
-                cbor_map.insert(key.to_string(), cbor_value);
+        PortableValue::Map(entries) => {
+            let mut map = Map::new();
+            for (k, v) in entries.iter() {
+                map.insert(k.clone(), portable_value_to_json(v)?);
             }
-            Ok(JsonValue::Object(cbor_map))
+            Ok(JsonValue::Object(map))
         }
-        _ => Err(Error::IncompatibleSchemaAndValue.into()),
     }
 }
 
+fn float_to_json(v: f64) -> Result<JsonValue> {
+    Ok(JsonValue::Number(
+        Number::from_f64(v).ok_or(Error::FailedToConvertNumberFromHoliumCborToJson)?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::local::helpers::jsonschema::HoliumJsonSchemaName;
+    use crate::utils::local::helpers::jsonschema::{HoliumJsonSchemaName, HoliumJsonSchemaType};
+    use sk_cbor::{cbor_array_vec, cbor_bool, cbor_bytes, cbor_unsigned};
 
     #[test]
     fn can_import_json_boolean_value() {
@@ -1,11 +1,18 @@
-use crate::utils::local::helpers::jsonschema::HoliumJsonSchema;
-use anyhow::Result;
+use crate::utils::cbor::as_holium_cbor::MajorType;
+use crate::utils::cbor::helpers::{fetch_recursive_elements_detail, read_header};
+use crate::utils::local::helpers::jsonschema::{HoliumJsonSchema, HoliumJsonSchemaType};
+use anyhow::{Context, Result};
 use std::io::Read;
-use std::io::Write;
+use std::io::{Cursor, Write};
 
 pub mod bin;
 pub mod cbor;
 pub mod json;
+pub mod msgpack;
+pub mod ndjson;
+pub mod parquet;
+pub mod value;
+pub mod yaml;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -33,6 +40,125 @@ pub enum Error {
     FailedToWriteCborData,
     #[error("failed to write json data")]
     FailedToWriteJsonData,
+    #[error("failed to read json data at line {0}")]
+    FailedToReadNdjsonLine(usize),
+    #[error("json schema should describe an array of items to be ported in ndjson format")]
+    InvalidSchemaForNdjson,
+    #[error("failed to read parquet data")]
+    FailedToReadParquetData,
+    #[error("failed to write parquet data")]
+    FailedToWriteParquetData,
+    #[error("json schema should describe an array of objects or tuples of scalar values to be ported in parquet format")]
+    InvalidSchemaForParquet,
+    #[error("missing column in parquet data: {0}")]
+    MissingParquetColumn(String),
+    #[error("parquet column incompatible with json schema: {0}")]
+    IncompatibleParquetColumn(String),
+    #[error("failed to read msgpack data")]
+    FailedToReadMsgpackData,
+    #[error("failed to write msgpack data")]
+    FailedToWriteMsgpackData,
+    #[error("failed to read yaml data")]
+    FailedToReadYamlData,
+    #[error("failed to write yaml data")]
+    FailedToWriteYamlData,
+}
+
+/// Get the JSON schema of the items of an items-array schema, which may be wrapped in a
+/// single-element tuple as at the root of pipeline nodes. Also return whether it is wrapped.
+pub(crate) fn items_array_schema(
+    json_schema: &HoliumJsonSchema,
+) -> Option<(bool, &HoliumJsonSchema)> {
+    let (is_wrapped, array_schema) = match json_schema.1.as_ref() {
+        HoliumJsonSchemaType::TupleArray(schemata) if schemata.len() == 1 => (true, &schemata[0]),
+        _ => (false, json_schema),
+    };
+    match array_schema.1.as_ref() {
+        HoliumJsonSchemaType::ItemsArray(items_schema) => Some((is_wrapped, items_schema)),
+        _ => None,
+    }
+}
+
+/// Locate the items of a serialized HoliumCBOR array, which may be wrapped in a single-element
+/// array as at the root of pipeline nodes.
+pub(crate) fn locate_array_items(holium_cbor: &[u8], is_wrapped: bool) -> Result<Vec<MajorType>> {
+    let mut cursor = Cursor::new(holium_cbor);
+    let mut array = read_header(&mut cursor, 0).context(Error::FailedToReadHoliumCborData)?;
+    if let MajorType::Array(root) = &mut array {
+        fetch_recursive_elements_detail(&mut cursor, root)
+            .context(Error::FailedToReadHoliumCborData)?;
+    }
+    if is_wrapped {
+        array = match array {
+            MajorType::Array(root) if root.elements.len() == 1 => root.elements[0].clone(),
+            _ => return Err(Error::IncompatibleSchemaAndValue.into()),
+        };
+    }
+    match array {
+        MajorType::Array(items) => Ok(items.elements),
+        _ => Err(Error::IncompatibleSchemaAndValue.into()),
+    }
+}
+
+/// Read the header of a serialized HoliumCBOR array from a stream, and return its number of items.
+pub(crate) fn read_array_length<R: Read>(reader: &mut R) -> Result<u64> {
+    match read_item_header(reader, &mut Vec::new())? {
+        (4, length) => Ok(length),
+        _ => Err(Error::IncompatibleSchemaAndValue.into()),
+    }
+}
+
+/// Read the next serialized HoliumCBOR item of a stream, along with all its nested items, and
+/// append its raw bytes to a buffer.
+pub(crate) fn read_raw_item<R: Read>(reader: &mut R, item: &mut Vec<u8>) -> Result<()> {
+    // number of items still to be read, nested ones included
+    let mut remaining = 1u64;
+    while remaining > 0 {
+        remaining -= 1;
+        let (major_type, argument) = read_item_header(reader, item)?;
+        match major_type {
+            // byte and text strings are followed by their content
+            2 | 3 => {
+                let size = reader
+                    .take(argument)
+                    .read_to_end(item)
+                    .context(Error::FailedToReadHoliumCborData)?;
+                if size as u64 != argument {
+                    return Err(Error::FailedToReadHoliumCborData.into());
+                }
+            }
+            4 => remaining = remaining.saturating_add(argument),
+            5 => remaining = remaining.saturating_add(argument.saturating_mul(2)),
+            6 => remaining += 1,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Read the header of a serialized CBOR item, appending its raw bytes to a buffer, and return its
+/// major type and argument.
+fn read_item_header<R: Read>(reader: &mut R, item: &mut Vec<u8>) -> Result<(u8, u64)> {
+    let mut first_byte = [0u8];
+    reader
+        .read_exact(&mut first_byte)
+        .context(Error::FailedToReadHoliumCborData)?;
+    item.push(first_byte[0]);
+    let (major_type, details) = (first_byte[0] >> 5, first_byte[0] & 0x1F);
+    let argument_size = match details {
+        0..=23 => return Ok((major_type, details as u64)),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return Err(Error::FailedToReadHoliumCborData.into()),
+    };
+    let mut argument = [0u8; 8];
+    reader
+        .read_exact(&mut argument[8 - argument_size..])
+        .context(Error::FailedToReadHoliumCborData)?;
+    item.extend_from_slice(&argument[8 - argument_size..]);
+    Ok((major_type, u64::from_be_bytes(argument)))
 }
 
 /// Trait FormatPorter with [ import_to_holium ] and [ export_from_holium ]
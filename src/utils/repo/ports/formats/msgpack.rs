@@ -0,0 +1,113 @@
+//! Port data from and to MessagePack files. Byte strings are stored as MessagePack binary values.
+
+use crate::utils::local::helpers::jsonschema::HoliumJsonSchema;
+
+use crate::utils::repo::ports::formats::value::{
+    export_value_from_holium, import_value_to_holium, PortableValue,
+};
+use crate::utils::repo::ports::formats::{Error, FormatPorter};
+
+use anyhow::{Context, Result};
+use sk_cbor::write;
+use std::io::Read;
+use std::io::Write;
+
+pub struct MsgpackPorter;
+
+impl FormatPorter for MsgpackPorter {
+    fn import_to_holium<R: Read, W: Write>(
+        json_schema: &HoliumJsonSchema,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Result<()> {
+        // read the MessagePack contents
+        let value: PortableValue =
+            rmp_serde::from_read(reader).context(Error::FailedToReadMsgpackData)?;
+        // convert the value to HoliumCBOR
+        let holium_cbor = import_value_to_holium(json_schema, &value)?;
+        // write the HoliumCBOR to the writer
+        let mut buffer: Vec<u8> = Vec::new();
+        write(holium_cbor, &mut buffer).map_err(|_| Error::FailedToWriteHoliumCbor)?;
+        writer
+            .write_all(&buffer)
+            .context(Error::FailedToWriteHoliumCbor)?;
+        Ok(())
+    }
+
+    fn export_from_holium<R: Read, W: Write>(
+        json_schema: &HoliumJsonSchema,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Result<()> {
+        // read the Holium CBOR contents
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer)?;
+        let holium_cbor_value =
+            sk_cbor::read(&buffer).map_err(|_| Error::FailedToReadHoliumCborData)?;
+        // convert the HoliumCBOR data to a portable value
+        let contents = export_value_from_holium(json_schema, &holium_cbor_value)?;
+        // write the MessagePack encoding to the writer
+        rmp_serde::encode::write(writer, &contents).context(Error::FailedToWriteMsgpackData)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::local::helpers::jsonschema::parse_root_json_schema;
+    use serde_json::json;
+    use std::io::Cursor;
+
+    fn record_json_schema() -> HoliumJsonSchema {
+        parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "offset": { "type": "number" },
+                    "payload": { "type": "string", "contentEncoding": "base64" }
+                }
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn can_round_trip_through_holium_cbor() {
+        let json_schema = record_json_schema();
+        let value = PortableValue::Array(vec![PortableValue::Map(vec![
+            ("name".to_string(), PortableValue::Text("block".to_string())),
+            ("offset".to_string(), PortableValue::Negative(-12)),
+            ("payload".to_string(), PortableValue::Bytes(vec![0, 255])),
+        ])]);
+        let msgpack = rmp_serde::to_vec(&value).unwrap();
+        // import
+        let mut holium_cbor = Vec::new();
+        MsgpackPorter::import_to_holium(&json_schema, &mut Cursor::new(msgpack), &mut holium_cbor)
+            .unwrap();
+        // export back
+        let mut exported = Vec::new();
+        MsgpackPorter::export_from_holium(
+            &json_schema,
+            &mut Cursor::new(holium_cbor),
+            &mut exported,
+        )
+        .unwrap();
+        let exported_value: PortableValue = rmp_serde::from_slice(&exported).unwrap();
+        assert_eq!(exported_value, value);
+    }
+
+    #[test]
+    fn cannot_import_invalid_msgpack() {
+        let json_schema = record_json_schema();
+        let mut holium_cbor = Vec::new();
+        let res = MsgpackPorter::import_to_holium(
+            &json_schema,
+            &mut Cursor::new(vec![0xc1]),
+            &mut holium_cbor,
+        );
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,202 @@
+//! Port arrays of items from and to newline-delimited JSON files, with one item per line.
+//!
+//! Items are read, converted and written one at a time. When importing, their HoliumCBOR encoding
+//! is spooled to a temporary file, as the array header written first holds the number of items.
+
+use crate::utils::cbor::helpers::generate_array_cbor_header;
+use crate::utils::local::helpers::jsonschema::HoliumJsonSchema;
+
+use crate::utils::repo::ports::formats::json::{holium_cbor_to_json, json_to_holium_cbor};
+use crate::utils::repo::ports::formats::{
+    items_array_schema, read_array_length, read_raw_item, Error, FormatPorter,
+};
+
+use anyhow::{Context, Result};
+use serde_json::{to_writer, Value as JsonValue};
+use std::io::{BufRead, BufReader, BufWriter, Read};
+use std::io::{Seek, SeekFrom, Write};
+
+pub struct NdjsonPorter;
+
+impl FormatPorter for NdjsonPorter {
+    fn import_to_holium<R: Read, W: Write>(
+        json_schema: &HoliumJsonSchema,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Result<()> {
+        let (is_wrapped, items_schema) =
+            items_array_schema(json_schema).ok_or(Error::InvalidSchemaForNdjson)?;
+        // convert every non-empty line to a HoliumCBOR item, spooled to a temporary file
+        let mut nb_items = 0u64;
+        let mut items_file =
+            BufWriter::new(tempfile::tempfile().context(Error::FailedToWriteHoliumCbor)?);
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line.context(Error::FailedToReadNdjsonLine(index + 1))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let json_value: JsonValue =
+                serde_json::from_str(&line).context(Error::FailedToReadNdjsonLine(index + 1))?;
+            let item = json_to_holium_cbor(items_schema, &json_value)
+                .context(Error::FailedToReadNdjsonLine(index + 1))?;
+            items_file
+                .write_all(&item)
+                .context(Error::FailedToWriteHoliumCbor)?;
+            nb_items += 1;
+        }
+        let mut items_file = items_file
+            .into_inner()
+            .map_err(|_| Error::FailedToWriteHoliumCbor)?;
+        items_file
+            .seek(SeekFrom::Start(0))
+            .context(Error::FailedToWriteHoliumCbor)?;
+        // write the HoliumCBOR array to the writer
+        if is_wrapped {
+            writer
+                .write_all(&generate_array_cbor_header(1))
+                .context(Error::FailedToWriteHoliumCbor)?;
+        }
+        writer
+            .write_all(&generate_array_cbor_header(nb_items))
+            .context(Error::FailedToWriteHoliumCbor)?;
+        std::io::copy(&mut items_file, writer).context(Error::FailedToWriteHoliumCbor)?;
+        Ok(())
+    }
+
+    fn export_from_holium<R: Read, W: Write>(
+        json_schema: &HoliumJsonSchema,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Result<()> {
+        let (is_wrapped, items_schema) =
+            items_array_schema(json_schema).ok_or(Error::InvalidSchemaForNdjson)?;
+        // read the headers of the HoliumCBOR array
+        let mut reader = BufReader::new(reader);
+        if is_wrapped && read_array_length(&mut reader)? != 1 {
+            return Err(Error::IncompatibleSchemaAndValue.into());
+        }
+        let nb_items = read_array_length(&mut reader)?;
+        // read and write items one at a time, one per line
+        let mut writer = BufWriter::new(writer);
+        let mut item = Vec::new();
+        for _ in 0..nb_items {
+            item.clear();
+            read_raw_item(&mut reader, &mut item)?;
+            let json_value = holium_cbor_to_json(items_schema, &item)?;
+            to_writer(&mut writer, &json_value).context(Error::FailedToWriteJsonData)?;
+            writer
+                .write_all(b"\n")
+                .context(Error::FailedToWriteJsonData)?;
+        }
+        writer.flush().context(Error::FailedToWriteJsonData)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::local::helpers::jsonschema::parse_root_json_schema;
+    use serde_json::json;
+    use std::io::Cursor;
+
+    fn log_json_schema() -> HoliumJsonSchema {
+        parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "level": { "type": "string" },
+                        "code": { "type": "number" }
+                    }
+                }
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn can_round_trip_lines_through_holium_cbor() {
+        let json_schema = log_json_schema();
+        let ndjson = "{\"level\":\"info\",\"code\":1}\n\n{\"level\":\"error\",\"code\":2}\n";
+        // import lines
+        let mut holium_cbor = Vec::new();
+        NdjsonPorter::import_to_holium(
+            &json_schema,
+            &mut Cursor::new(ndjson.as_bytes()),
+            &mut holium_cbor,
+        )
+        .unwrap();
+        // export them back
+        let mut exported = Vec::new();
+        NdjsonPorter::export_from_holium(
+            &json_schema,
+            &mut Cursor::new(holium_cbor),
+            &mut exported,
+        )
+        .unwrap();
+        let lines: Vec<JsonValue> = String::from_utf8(exported)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                json!({"level": "info", "code": 1.0}),
+                json!({"level": "error", "code": 2.0})
+            ]
+        );
+    }
+
+    #[test]
+    fn can_read_items_one_at_a_time() {
+        let json_schema = log_json_schema();
+        let ndjson = "{\"level\":\"info\",\"code\":1}\n{\"level\":\"error\",\"code\":300}\n";
+        let mut holium_cbor = Vec::new();
+        NdjsonPorter::import_to_holium(
+            &json_schema,
+            &mut Cursor::new(ndjson.as_bytes()),
+            &mut holium_cbor,
+        )
+        .unwrap();
+        let (_, items_schema) = items_array_schema(&json_schema).unwrap();
+        // read headers, then each item, leaving the following ones in the stream
+        let mut reader = Cursor::new(holium_cbor);
+        assert_eq!(read_array_length(&mut reader).unwrap(), 1);
+        assert_eq!(read_array_length(&mut reader).unwrap(), 2);
+        let mut item = Vec::new();
+        read_raw_item(&mut reader, &mut item).unwrap();
+        assert_eq!(
+            holium_cbor_to_json(items_schema, &item).unwrap(),
+            json!({"level": "info", "code": 1.0})
+        );
+        item.clear();
+        read_raw_item(&mut reader, &mut item).unwrap();
+        assert_eq!(
+            holium_cbor_to_json(items_schema, &item).unwrap(),
+            json!({"level": "error", "code": 300.0})
+        );
+        assert!(read_raw_item(&mut reader, &mut item).is_err());
+    }
+
+    #[test]
+    fn cannot_import_invalid_line() {
+        let json_schema = log_json_schema();
+        let ndjson = "{\"level\":\"info\",\"code\":1}\nnot json\n";
+        let mut holium_cbor = Vec::new();
+        let res = NdjsonPorter::import_to_holium(
+            &json_schema,
+            &mut Cursor::new(ndjson.as_bytes()),
+            &mut holium_cbor,
+        );
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("failed to read json data at line 2"));
+    }
+}
@@ -0,0 +1,479 @@
+//! Port tables of scalar values from and to Apache Parquet files.
+//!
+//! A table is described by a JSON schema holding an array of rows, each row being an object (with
+//! columns matched by name) or a tuple (with columns matched by position) of scalar values. As the
+//! root JSON schema of a pipeline node is a tuple, a tuple holding a single table is also accepted.
+
+use crate::utils::local::helpers::jsonschema::{HoliumJsonSchema, HoliumJsonSchemaType};
+
+use crate::utils::repo::ports::formats::{Error, FormatPorter};
+
+use anyhow::{Context, Result};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{
+    Array, ArrayRef, BinaryArray, BooleanArray, Int64Array, NullArray, RecordBatch, StringArray,
+};
+use arrow_schema::{DataType, Field, Schema};
+use bytes::Bytes;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use sk_cbor::write;
+use sk_cbor::{
+    cbor_array_vec, cbor_bool, cbor_bytes, cbor_int, cbor_null, cbor_text, cbor_unsigned,
+};
+use sk_cbor::{SimpleValue, Value as CborValue};
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+pub struct ParquetPorter;
+
+impl FormatPorter for ParquetPorter {
+    fn import_to_holium<R: Read, W: Write>(
+        json_schema: &HoliumJsonSchema,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Result<()> {
+        let table = TableLayout::try_from(json_schema)?;
+        // read the Parquet contents
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer)?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer))
+            .context(Error::FailedToReadParquetData)?;
+        let column_indices = table.resolve_columns(builder.schema())?;
+        // convert rows of every record batch to HoliumCBOR
+        let mut rows = Vec::new();
+        for batch in builder.build().context(Error::FailedToReadParquetData)? {
+            let batch = batch.context(Error::FailedToReadParquetData)?;
+            for row in 0..batch.num_rows() {
+                let values = table
+                    .columns
+                    .iter()
+                    .zip(column_indices.iter())
+                    .map(|((_, json_schema), index)| {
+                        import_value_to_holium(json_schema, batch.column(*index), row)
+                    })
+                    .collect::<Result<Vec<CborValue>>>()?;
+                rows.push(cbor_array_vec!(values));
+            }
+        }
+        let mut holium_cbor = cbor_array_vec!(rows);
+        if table.is_wrapped {
+            holium_cbor = cbor_array_vec!(vec![holium_cbor]);
+        }
+        // write the HoliumCBOR to the writer
+        let mut buffer: Vec<u8> = Vec::new();
+        write(holium_cbor, &mut buffer).map_err(|_| Error::FailedToWriteHoliumCbor)?;
+        writer
+            .write_all(&buffer)
+            .context(Error::FailedToWriteHoliumCbor)?;
+        Ok(())
+    }
+
+    fn export_from_holium<R: Read, W: Write>(
+        json_schema: &HoliumJsonSchema,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Result<()> {
+        let table = TableLayout::try_from(json_schema)?;
+        // read the Holium CBOR contents
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer)?;
+        let holium_cbor_value =
+            sk_cbor::read(&buffer).map_err(|_| Error::FailedToReadHoliumCborData)?;
+        let rows = table.rows(&holium_cbor_value)?;
+        // build one column array per field of the table
+        let fields = table
+            .columns
+            .iter()
+            .map(|(name, json_schema)| column_field(name, json_schema))
+            .collect::<Result<Vec<Field>>>()?;
+        let columns = table
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, (_, json_schema))| export_column_from_holium(json_schema, rows, index))
+            .collect::<Result<Vec<ArrayRef>>>()?;
+        let schema = Arc::new(Schema::new(fields));
+        let batch = RecordBatch::try_new(schema.clone(), columns)
+            .context(Error::FailedToWriteParquetData)?;
+        // write the record batch to the writer
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut arrow_writer = ArrowWriter::try_new(&mut buffer, schema, None)
+            .context(Error::FailedToWriteParquetData)?;
+        arrow_writer
+            .write(&batch)
+            .context(Error::FailedToWriteParquetData)?;
+        arrow_writer
+            .close()
+            .context(Error::FailedToWriteParquetData)?;
+        writer
+            .write_all(&buffer)
+            .context(Error::FailedToWriteParquetData)?;
+        Ok(())
+    }
+}
+
+/// Validate that data described by a JSON schema can be ported in Parquet format and, if a
+/// Parquet file is given, that its columns are compatible with the schema.
+pub fn validate_parquet_compatibility(
+    json_schema: &HoliumJsonSchema,
+    parquet_file_path: Option<&Path>,
+) -> Result<()> {
+    let table = TableLayout::try_from(json_schema)?;
+    if let Some(path) = parquet_file_path {
+        let file = File::open(path).context(Error::FailedToReadParquetData)?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+            .context(Error::FailedToReadParquetData)?;
+        table.resolve_columns(builder.schema())?;
+    }
+    Ok(())
+}
+
+/// Layout of a table described by a JSON schema
+struct TableLayout<'a> {
+    /// Whether the table is wrapped in a single-element tuple
+    is_wrapped: bool,
+    /// Whether rows are objects, with columns matched by name, or tuples
+    has_object_rows: bool,
+    /// Name and JSON schema of every column
+    columns: Vec<(String, &'a HoliumJsonSchema)>,
+}
+
+impl<'a> TryFrom<&'a HoliumJsonSchema> for TableLayout<'a> {
+    type Error = anyhow::Error;
+
+    fn try_from(json_schema: &'a HoliumJsonSchema) -> Result<Self> {
+        // unwrap the table from the root tuple of a pipeline node, if needed
+        let (is_wrapped, table_schema) = match json_schema.1.as_ref() {
+            HoliumJsonSchemaType::TupleArray(schemata) if schemata.len() == 1 => {
+                (true, &schemata[0])
+            }
+            _ => (false, json_schema),
+        };
+        let row_schema = match table_schema.1.as_ref() {
+            HoliumJsonSchemaType::ItemsArray(row_schema) => row_schema,
+            _ => return Err(Error::InvalidSchemaForParquet.into()),
+        };
+        let (has_object_rows, columns) = match row_schema.1.as_ref() {
+            HoliumJsonSchemaType::Object(schemata) => (
+                true,
+                schemata
+                    .iter()
+                    .map(|s| {
+                        let key = s.0 .0.as_ref().ok_or(Error::MissingKeyInObjectTypeSchema)?;
+                        Ok((key.to_string(), s))
+                    })
+                    .collect::<Result<Vec<(String, &HoliumJsonSchema)>>>()?,
+            ),
+            HoliumJsonSchemaType::TupleArray(schemata) => (
+                false,
+                schemata
+                    .iter()
+                    .enumerate()
+                    .map(|(index, s)| (index.to_string(), s))
+                    .collect(),
+            ),
+            _ => return Err(Error::InvalidSchemaForParquet.into()),
+        };
+        if columns.is_empty() || !columns.iter().all(|(_, s)| is_scalar(s)) {
+            return Err(Error::InvalidSchemaForParquet.into());
+        }
+        Ok(TableLayout {
+            is_wrapped,
+            has_object_rows,
+            columns,
+        })
+    }
+}
+
+impl<'a> TableLayout<'a> {
+    /// Find the index of the Parquet column matching each column of the table, checking that
+    /// their types are compatible.
+    fn resolve_columns(&self, schema: &Schema) -> Result<Vec<usize>> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(position, (name, json_schema))| {
+                let index = if self.has_object_rows {
+                    schema.index_of(name).ok()
+                } else {
+                    Some(position).filter(|position| *position < schema.fields().len())
+                }
+                .ok_or(Error::MissingParquetColumn(name.to_string()))?;
+                if !is_compatible_data_type(json_schema, schema.field(index).data_type()) {
+                    return Err(Error::IncompatibleParquetColumn(name.to_string()).into());
+                }
+                Ok(index)
+            })
+            .collect()
+    }
+
+    /// Get the rows of a HoliumCBOR table value.
+    fn rows<'b>(&self, value: &'b CborValue) -> Result<&'b Vec<CborValue>> {
+        let table_value = match (self.is_wrapped, value) {
+            (false, _) => value,
+            (true, CborValue::Array(values)) if values.len() == 1 => &values[0],
+            _ => return Err(Error::IncompatibleSchemaAndValue.into()),
+        };
+        match table_value {
+            CborValue::Array(rows) => Ok(rows),
+            _ => Err(Error::IncompatibleSchemaAndValue.into()),
+        }
+    }
+}
+
+fn is_scalar(json_schema: &HoliumJsonSchema) -> bool {
+    matches!(
+        json_schema.1.as_ref(),
+        HoliumJsonSchemaType::Boolean
+            | HoliumJsonSchemaType::Number
+            | HoliumJsonSchemaType::TextString
+            | HoliumJsonSchemaType::ByteString
+            | HoliumJsonSchemaType::Null
+    )
+}
+
+fn is_compatible_data_type(json_schema: &HoliumJsonSchema, data_type: &DataType) -> bool {
+    match (json_schema.1.as_ref(), data_type) {
+        (HoliumJsonSchemaType::Null, _) => true,
+        (HoliumJsonSchemaType::Boolean, DataType::Boolean) => true,
+        (HoliumJsonSchemaType::Number, data_type) => data_type.is_integer(),
+        (HoliumJsonSchemaType::TextString, DataType::Utf8 | DataType::LargeUtf8) => true,
+        (HoliumJsonSchemaType::ByteString, DataType::Binary | DataType::LargeBinary) => true,
+        _ => false,
+    }
+}
+
+fn column_field(name: &str, json_schema: &HoliumJsonSchema) -> Result<Field> {
+    let field = match json_schema.1.as_ref() {
+        HoliumJsonSchemaType::Null => Field::new(name, DataType::Null, true),
+        HoliumJsonSchemaType::Boolean => Field::new(name, DataType::Boolean, false),
+        HoliumJsonSchemaType::Number => Field::new(name, DataType::Int64, false),
+        HoliumJsonSchemaType::TextString => Field::new(name, DataType::Utf8, false),
+        HoliumJsonSchemaType::ByteString => Field::new(name, DataType::Binary, false),
+        _ => return Err(Error::InvalidSchemaForParquet.into()),
+    };
+    Ok(field)
+}
+
+fn import_value_to_holium(
+    json_schema: &HoliumJsonSchema,
+    column: &ArrayRef,
+    row: usize,
+) -> Result<CborValue> {
+    let schema: &HoliumJsonSchemaType = json_schema.1.as_ref();
+    if let HoliumJsonSchemaType::Null = schema {
+        return Ok(cbor_null!());
+    }
+    if column.is_null(row) {
+        return Err(Error::IncompatibleSchemaAndValue.into());
+    }
+    match (schema, column.data_type()) {
+        (HoliumJsonSchemaType::Boolean, DataType::Boolean) => {
+            Ok(cbor_bool!(column.as_boolean().value(row)))
+        }
+        (HoliumJsonSchemaType::Number, DataType::Int8) => Ok(cbor_int!(column
+            .as_primitive::<Int8Type>()
+            .value(row)
+            as i64)),
+        (HoliumJsonSchemaType::Number, DataType::Int16) => Ok(cbor_int!(column
+            .as_primitive::<Int16Type>()
+            .value(row)
+            as i64)),
+        (HoliumJsonSchemaType::Number, DataType::Int32) => Ok(cbor_int!(column
+            .as_primitive::<Int32Type>()
+            .value(row)
+            as i64)),
+        (HoliumJsonSchemaType::Number, DataType::Int64) => {
+            Ok(cbor_int!(column.as_primitive::<Int64Type>().value(row)))
+        }
+        (HoliumJsonSchemaType::Number, DataType::UInt8) => Ok(cbor_unsigned!(column
+            .as_primitive::<UInt8Type>()
+            .value(row)
+            as u64)),
+        (HoliumJsonSchemaType::Number, DataType::UInt16) => Ok(cbor_unsigned!(column
+            .as_primitive::<UInt16Type>()
+            .value(row)
+            as u64)),
+        (HoliumJsonSchemaType::Number, DataType::UInt32) => Ok(cbor_unsigned!(column
+            .as_primitive::<UInt32Type>()
+            .value(row)
+            as u64)),
+        (HoliumJsonSchemaType::Number, DataType::UInt64) => Ok(cbor_unsigned!(column
+            .as_primitive::<UInt64Type>()
+            .value(row))),
+        (
+            HoliumJsonSchemaType::Number,
+            DataType::Float16 | DataType::Float32 | DataType::Float64,
+        ) => Err(Error::UnhandledFloat.into()),
+        (HoliumJsonSchemaType::TextString, DataType::Utf8) => {
+            Ok(cbor_text!(column.as_string::<i32>().value(row).to_string()))
+        }
+        (HoliumJsonSchemaType::TextString, DataType::LargeUtf8) => {
+            Ok(cbor_text!(column.as_string::<i64>().value(row).to_string()))
+        }
+        (HoliumJsonSchemaType::ByteString, DataType::Binary) => {
+            Ok(cbor_bytes!(column.as_binary::<i32>().value(row).to_vec()))
+        }
+        (HoliumJsonSchemaType::ByteString, DataType::LargeBinary) => {
+            Ok(cbor_bytes!(column.as_binary::<i64>().value(row).to_vec()))
+        }
+        _ => Err(Error::IncompatibleSchemaAndValue.into()),
+    }
+}
+
+fn export_column_from_holium(
+    json_schema: &HoliumJsonSchema,
+    rows: &[CborValue],
+    index: usize,
+) -> Result<ArrayRef> {
+    // collect the values of the column in every row
+    let values = rows
+        .iter()
+        .map(|row| match row {
+            CborValue::Array(values) => values.get(index).ok_or(Error::IncompatibleSchemaAndValue),
+            _ => Err(Error::IncompatibleSchemaAndValue),
+        })
+        .collect::<Result<Vec<&CborValue>, Error>>()?;
+    let schema: &HoliumJsonSchemaType = json_schema.1.as_ref();
+    let column: ArrayRef = match schema {
+        HoliumJsonSchemaType::Null => Arc::new(NullArray::new(values.len())),
+        HoliumJsonSchemaType::Boolean => Arc::new(BooleanArray::from(
+            values
+                .iter()
+                .map(|v| match v {
+                    CborValue::Simple(SimpleValue::TrueValue) => Ok(true),
+                    CborValue::Simple(SimpleValue::FalseValue) => Ok(false),
+                    _ => Err(Error::IncompatibleSchemaAndValue),
+                })
+                .collect::<Result<Vec<bool>, Error>>()?,
+        )),
+        HoliumJsonSchemaType::Number => Arc::new(Int64Array::from(
+            values
+                .iter()
+                .map(|v| match v {
+                    CborValue::Unsigned(v) => {
+                        i64::try_from(*v).map_err(|_| Error::IncompatibleSchemaAndValue)
+                    }
+                    CborValue::Negative(v) => Ok(*v),
+                    _ => Err(Error::IncompatibleSchemaAndValue),
+                })
+                .collect::<Result<Vec<i64>, Error>>()?,
+        )),
+        HoliumJsonSchemaType::TextString => Arc::new(StringArray::from(
+            values
+                .iter()
+                .map(|v| match v {
+                    CborValue::TextString(v) => Ok(v.as_str()),
+                    _ => Err(Error::IncompatibleSchemaAndValue),
+                })
+                .collect::<Result<Vec<&str>, Error>>()?,
+        )),
+        HoliumJsonSchemaType::ByteString => Arc::new(BinaryArray::from(
+            values
+                .iter()
+                .map(|v| match v {
+                    CborValue::ByteString(v) => Ok(v.as_slice()),
+                    _ => Err(Error::IncompatibleSchemaAndValue),
+                })
+                .collect::<Result<Vec<&[u8]>, Error>>()?,
+        )),
+        _ => return Err(Error::InvalidSchemaForParquet.into()),
+    };
+    Ok(column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::local::helpers::jsonschema::parse_root_json_schema;
+    use serde_json::json;
+    use std::io::Cursor;
+
+    fn table_json_schema() -> HoliumJsonSchema {
+        parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "age": { "type": "number" },
+                        "member": { "type": "boolean" }
+                    }
+                }
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn can_round_trip_table_through_parquet() {
+        let json_schema = table_json_schema();
+        let holium_cbor_value = cbor_array_vec!(vec![cbor_array_vec!(vec![
+            cbor_array_vec!(vec![
+                cbor_text!("alice"),
+                cbor_unsigned!(42),
+                cbor_bool!(true)
+            ]),
+            cbor_array_vec!(vec![cbor_text!("bob"), cbor_int!(-1), cbor_bool!(false)]),
+        ])]);
+        let mut holium_cbor = Vec::new();
+        write(holium_cbor_value.clone(), &mut holium_cbor).unwrap();
+        // export to parquet
+        let mut parquet = Vec::new();
+        ParquetPorter::export_from_holium(
+            &json_schema,
+            &mut Cursor::new(holium_cbor),
+            &mut parquet,
+        )
+        .unwrap();
+        // import back
+        let mut imported = Vec::new();
+        ParquetPorter::import_to_holium(&json_schema, &mut Cursor::new(parquet), &mut imported)
+            .unwrap();
+        assert_eq!(sk_cbor::read(&imported).unwrap(), holium_cbor_value);
+    }
+
+    #[test]
+    fn cannot_port_non_tabular_schema() {
+        let json_schema = parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [{ "type": "string" }, { "type": "number" }]
+        }))
+        .unwrap();
+        let res = validate_parquet_compatibility(&json_schema, None);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn cannot_import_parquet_with_missing_column() {
+        let json_schema = table_json_schema();
+        // write a table with a single column
+        let schema = Arc::new(Schema::new(vec![Field::new("name", DataType::Utf8, false)]));
+        let column: ArrayRef = Arc::new(StringArray::from(vec!["alice"]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![column]).unwrap();
+        let mut parquet = Vec::new();
+        let mut arrow_writer = ArrowWriter::try_new(&mut parquet, schema, None).unwrap();
+        arrow_writer.write(&batch).unwrap();
+        arrow_writer.close().unwrap();
+        // try to import it
+        let mut imported = Vec::new();
+        let res =
+            ParquetPorter::import_to_holium(&json_schema, &mut Cursor::new(parquet), &mut imported);
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("missing column in parquet data: age"));
+    }
+}
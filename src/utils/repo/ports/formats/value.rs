@@ -0,0 +1,357 @@
+//! Format-independent model of the values held in portation files, and its mapping to and from
+//! HoliumCBOR as described by a JSON schema.
+//!
+//! Porters of self-describing formats deserialize their contents into a [ PortableValue ] before
+//! mapping it to HoliumCBOR, and serialize the [ PortableValue ] mapped from HoliumCBOR on export.
+
+use crate::utils::local::helpers::jsonschema::{HoliumJsonSchema, HoliumJsonSchemaType};
+
+use crate::utils::repo::ports::formats::Error;
+
+use anyhow::{Context, Result};
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sk_cbor::SimpleValue;
+use sk_cbor::Value as CborValue;
+use sk_cbor::{
+    cbor_array_vec, cbor_bool, cbor_bytes, cbor_int, cbor_null, cbor_text, cbor_unsigned,
+};
+use std::fmt;
+
+/// Value read from, or written to, a portation file
+#[derive(Clone, Debug, PartialEq)]
+pub enum PortableValue {
+    Null,
+    Boolean(bool),
+    Unsigned(u64),
+    Negative(i64),
+    Float(f64),
+    Text(String),
+    Bytes(Vec<u8>),
+    Array(Vec<PortableValue>),
+    Map(Vec<(String, PortableValue)>),
+}
+
+impl PortableValue {
+    /// Replace byte strings with their base64 encoding, for formats with no byte string type.
+    pub fn with_base64_bytes(self) -> Self {
+        match self {
+            PortableValue::Bytes(v) => PortableValue::Text(base64::encode(&v)),
+            PortableValue::Array(values) => PortableValue::Array(
+                values
+                    .into_iter()
+                    .map(PortableValue::with_base64_bytes)
+                    .collect(),
+            ),
+            PortableValue::Map(entries) => PortableValue::Map(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, v.with_base64_bytes()))
+                    .collect(),
+            ),
+            v => v,
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&PortableValue> {
+        match self {
+            PortableValue::Map(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for PortableValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            PortableValue::Null => serializer.serialize_unit(),
+            PortableValue::Boolean(v) => serializer.serialize_bool(*v),
+            PortableValue::Unsigned(v) => serializer.serialize_u64(*v),
+            PortableValue::Negative(v) => serializer.serialize_i64(*v),
+            PortableValue::Float(v) => serializer.serialize_f64(*v),
+            PortableValue::Text(v) => serializer.serialize_str(v),
+            PortableValue::Bytes(v) => serializer.serialize_bytes(v),
+            PortableValue::Array(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for v in values {
+                    seq.serialize_element(v)?;
+                }
+                seq.end()
+            }
+            PortableValue::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (k, v) in entries {
+                    map.serialize_entry(k, v)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+struct PortableValueVisitor;
+
+impl<'de> Visitor<'de> for PortableValueVisitor {
+    type Value = PortableValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a value of a self-describing format")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<PortableValue, E> {
+        Ok(PortableValue::Boolean(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<PortableValue, E> {
+        if v < 0 {
+            Ok(PortableValue::Negative(v))
+        } else {
+            Ok(PortableValue::Unsigned(v as u64))
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<PortableValue, E> {
+        Ok(PortableValue::Unsigned(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<PortableValue, E> {
+        Ok(PortableValue::Float(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<PortableValue, E> {
+        Ok(PortableValue::Text(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<PortableValue, E> {
+        Ok(PortableValue::Text(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<PortableValue, E> {
+        Ok(PortableValue::Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<PortableValue, E> {
+        Ok(PortableValue::Bytes(v))
+    }
+
+    fn visit_none<E>(self) -> Result<PortableValue, E> {
+        Ok(PortableValue::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<PortableValue, D::Error> {
+        PortableValue::deserialize(deserializer)
+    }
+
+    fn visit_unit<E>(self) -> Result<PortableValue, E> {
+        Ok(PortableValue::Null)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<PortableValue, A::Error> {
+        let mut values = Vec::new();
+        while let Some(v) = seq.next_element()? {
+            values.push(v);
+        }
+        Ok(PortableValue::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<PortableValue, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry::<String, PortableValue>()? {
+            entries.push(entry);
+        }
+        Ok(PortableValue::Map(entries))
+    }
+}
+
+impl<'de> Deserialize<'de> for PortableValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(PortableValueVisitor)
+    }
+}
+
+/// Map a portable value to HoliumCBOR, as described by a JSON schema. Byte strings may be given as
+/// base64-encoded text, for formats with no byte string type.
+pub fn import_value_to_holium(
+    json_schema: &HoliumJsonSchema,
+    v: &PortableValue,
+) -> Result<CborValue> {
+    let schema: &HoliumJsonSchemaType = json_schema.1.as_ref();
+    match (schema, v) {
+        (HoliumJsonSchemaType::Null, _) => Ok(cbor_null!()),
+        (HoliumJsonSchemaType::Boolean, PortableValue::Boolean(v)) => Ok(cbor_bool!(*v)),
+        (HoliumJsonSchemaType::Number, PortableValue::Unsigned(v)) => Ok(cbor_unsigned!(*v)),
+        (HoliumJsonSchemaType::Number, PortableValue::Negative(v)) => Ok(cbor_int!(*v)),
+        (HoliumJsonSchemaType::Number, PortableValue::Float(_)) => {
+            Err(Error::UnhandledFloat.into())
+        }
+        (HoliumJsonSchemaType::TextString, PortableValue::Text(v)) => Ok(cbor_text!(v.clone())),
+        (HoliumJsonSchemaType::ByteString, PortableValue::Bytes(v)) => Ok(cbor_bytes!(v.clone())),
+        (HoliumJsonSchemaType::ByteString, PortableValue::Text(v)) => {
+            // decode base64-encoded string to Vec<u8>
+            let bytes = base64::decode(v).context("base64 decode error")?;
+            Ok(cbor_bytes!(bytes))
+        }
+        (HoliumJsonSchemaType::ItemsArray(items_schema), PortableValue::Array(values)) => {
+            let cbor_array = values
+                .iter()
+                .map(|v| import_value_to_holium(items_schema, v))
+                .collect::<Result<Vec<CborValue>>>()?;
+            Ok(cbor_array_vec!(cbor_array))
+        }
+        (HoliumJsonSchemaType::TupleArray(tuple_schemata), PortableValue::Array(values)) => {
+            let cbor_array = tuple_schemata
+                .iter()
+                .zip(values.iter())
+                .map(|(schema, v)| import_value_to_holium(schema, v))
+                .collect::<Result<Vec<CborValue>>>()?;
+            Ok(cbor_array_vec!(cbor_array))
+        }
+        (HoliumJsonSchemaType::Object(object_schemata), PortableValue::Map(_)) => {
+            let cbor_array = object_schemata
+                .iter()
+                .map(|s| {
+                    let key = s.0 .0.as_ref().ok_or(Error::MissingKeyInObjectTypeSchema)?;
+                    let value = v
+                        .get(key)
+                        .ok_or_else(|| Error::MissingObjectKey(key.to_string()))?;
+                    import_value_to_holium(s, value)
+                })
+                .collect::<Result<Vec<CborValue>>>()?;
+            Ok(cbor_array_vec!(cbor_array))
+        }
+        _ => Err(Error::IncompatibleSchemaAndValue.into()),
+    }
+}
+
+/// Map a HoliumCBOR value to a portable value, as described by a JSON schema.
+pub fn export_value_from_holium(
+    json_schema: &HoliumJsonSchema,
+    v: &CborValue,
+) -> Result<PortableValue> {
+    let schema: &HoliumJsonSchemaType = json_schema.1.as_ref();
+    match (schema, v) {
+        (HoliumJsonSchemaType::Null, _) => Ok(PortableValue::Null),
+        (HoliumJsonSchemaType::Boolean, CborValue::Simple(SimpleValue::TrueValue)) => {
+            Ok(PortableValue::Boolean(true))
+        }
+        (HoliumJsonSchemaType::Boolean, CborValue::Simple(SimpleValue::FalseValue)) => {
+            Ok(PortableValue::Boolean(false))
+        }
+        (HoliumJsonSchemaType::Number, CborValue::Unsigned(v)) => Ok(PortableValue::Unsigned(*v)),
+        (HoliumJsonSchemaType::Number, CborValue::Negative(v)) => Ok(PortableValue::Negative(*v)),
+        (HoliumJsonSchemaType::TextString, CborValue::TextString(v)) => {
+            Ok(PortableValue::Text(v.clone()))
+        }
+        (HoliumJsonSchemaType::ByteString, CborValue::ByteString(v)) => {
+            Ok(PortableValue::Bytes(v.clone()))
+        }
+        (HoliumJsonSchemaType::ItemsArray(items_schema), CborValue::Array(values)) => {
+            let array = values
+                .iter()
+                .map(|v| export_value_from_holium(items_schema, v))
+                .collect::<Result<Vec<PortableValue>>>()?;
+            Ok(PortableValue::Array(array))
+        }
+        (HoliumJsonSchemaType::TupleArray(tuple_schemata), CborValue::Array(values)) => {
+            let array = tuple_schemata
+                .iter()
+                .zip(values.iter())
+                .map(|(schema, v)| export_value_from_holium(schema, v))
+                .collect::<Result<Vec<PortableValue>>>()?;
+            Ok(PortableValue::Array(array))
+        }
+        (HoliumJsonSchemaType::Object(object_schemata), CborValue::Array(values)) => {
+            let mut entries = Vec::new();
+            for (s, v) in object_schemata.iter().zip(values.iter()) {
+                let key = s.0 .0.as_ref().ok_or(Error::MissingKeyInObjectTypeSchema)?;
+                entries.push((key.to_string(), export_value_from_holium(s, v)?));
+            }
+            Ok(PortableValue::Map(entries))
+        }
+        _ => Err(Error::IncompatibleSchemaAndValue.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::local::helpers::jsonschema::HoliumJsonSchemaName;
+
+    fn object_json_schema() -> HoliumJsonSchema {
+        HoliumJsonSchema(
+            HoliumJsonSchemaName(None),
+            Box::new(HoliumJsonSchemaType::Object(vec![
+                HoliumJsonSchema(
+                    HoliumJsonSchemaName(Some("payload".to_string())),
+                    Box::new(HoliumJsonSchemaType::ByteString),
+                ),
+                HoliumJsonSchema(
+                    HoliumJsonSchemaName(Some("delta".to_string())),
+                    Box::new(HoliumJsonSchemaType::Number),
+                ),
+            ])),
+        )
+    }
+
+    #[test]
+    fn can_round_trip_object_value() {
+        let json_schema = object_json_schema();
+        let value = PortableValue::Map(vec![
+            ("delta".to_string(), PortableValue::Negative(-3)),
+            ("payload".to_string(), PortableValue::Bytes(vec![1, 2, 3])),
+        ]);
+        let holium_cbor = import_value_to_holium(&json_schema, &value).unwrap();
+        assert_eq!(
+            holium_cbor,
+            cbor_array_vec!(vec![cbor_bytes!(vec![1, 2, 3]), cbor_int!(-3)])
+        );
+        // keys are exported in schema order
+        let exported = export_value_from_holium(&json_schema, &holium_cbor).unwrap();
+        assert_eq!(
+            exported,
+            PortableValue::Map(vec![
+                ("payload".to_string(), PortableValue::Bytes(vec![1, 2, 3])),
+                ("delta".to_string(), PortableValue::Negative(-3)),
+            ])
+        );
+    }
+
+    #[test]
+    fn can_import_bytes_as_base64_text() {
+        let json_schema = object_json_schema();
+        let value = PortableValue::Map(vec![
+            ("payload".to_string(), PortableValue::Bytes(vec![1, 2, 3])),
+            ("delta".to_string(), PortableValue::Unsigned(3)),
+        ])
+        .with_base64_bytes();
+        let holium_cbor = import_value_to_holium(&json_schema, &value).unwrap();
+        assert_eq!(
+            holium_cbor,
+            cbor_array_vec!(vec![cbor_bytes!(vec![1, 2, 3]), cbor_unsigned!(3)])
+        );
+    }
+
+    #[test]
+    fn cannot_import_object_with_missing_key() {
+        let json_schema = object_json_schema();
+        let value = PortableValue::Map(vec![("delta".to_string(), PortableValue::Unsigned(3))]);
+        let res = import_value_to_holium(&json_schema, &value);
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("missing key in imported object: payload"));
+    }
+
+    #[test]
+    fn cannot_import_float() {
+        let json_schema = HoliumJsonSchema(
+            HoliumJsonSchemaName(None),
+            Box::new(HoliumJsonSchemaType::Number),
+        );
+        let res = import_value_to_holium(&json_schema, &PortableValue::Float(1.5));
+        assert!(res.is_err());
+    }
+}
@@ -2,31 +2,150 @@ use crate::utils::errors::Error::{
     BinCodeDeserializeFailed, DbOperationFailed, NoObjectForGivenKey,
 };
 use crate::utils::local::context::helpers::{
-    parse_node_typed_name, parse_portation_id, NodeType, PortationDirectionType,
+    get_node_portations, parse_node_typed_name, parse_portation_id, NodeType,
+    PortationDirectionType,
 };
 use crate::utils::local::context::LocalContext;
 use crate::utils::local::helpers::jsonschema::{parse_root_json_schema, HoliumJsonSchema};
+use crate::utils::local::helpers::media_type::validate_mimetype_coherence;
+use crate::utils::local::helpers::selector::validate_selector;
 use crate::utils::local::models::shaper::Shaper;
 use crate::utils::local::models::source::Source;
 use crate::utils::local::models::transformation::Transformation;
 
-use crate::utils::repo::models::portation::Portation;
+use crate::utils::repo::context::RepositoryContext;
+use crate::utils::repo::models::portation::{Portation, PortationFileFormat};
+use crate::utils::repo::ports::directory::validate_directory_portation;
+use crate::utils::repo::ports::formats::parquet::validate_parquet_compatibility;
+use crate::utils::repo::ports::partial::get_partial_json_schema;
 use anyhow::{Context, Result};
 use serde_json::Value;
+use std::path::Path;
 
 #[derive(thiserror::Error, Debug)]
 enum Error {
     #[error("failed to parse json schema")]
     JsonSchemaParseFailed,
+    #[error("data entering node {0} can not be both fully and partially imported")]
+    MixedImportPortations(String),
 }
 
-/// Get the json schema related to a portation.
+/// Get the json schema related to a portation, only describing the ported part of the node data for
+/// partial portations.
 pub fn get_portation_json_schema(
     local_context: &LocalContext,
     portation: &Portation,
+) -> Result<HoliumJsonSchema> {
+    let node_json_schema = get_portation_node_json_schema(local_context, portation)?;
+    get_partial_json_schema(node_json_schema, portation.selector.as_deref())
+}
+
+/// Validate a portation, as created or updated, against the node it relates to: its file format and
+/// path should be coherent, its selector should designate part of the node data and this data should
+/// fit the layout of directory portations and the Parquet format.
+pub fn validate_portation(local_context: &LocalContext, portation: &Portation) -> Result<()> {
+    let node_json_schema = get_portation_node_json_schema(local_context, portation)?;
+    validate_portation_for_node(&local_context.root_path, portation, node_json_schema)
+}
+
+/// Same as [validate_portation], the json schema of the node data being given rather than read from
+/// the local area, for nodes that are not stored yet.
+pub fn validate_portation_for_node(
+    root_path: &Path,
+    portation: &Portation,
+    node_json_schema: HoliumJsonSchema,
+) -> Result<()> {
+    let (direction, _) = parse_portation_id(&portation.id)?;
+    // validate coherence of file format and file name, or file pattern for directory portations
+    validate_mimetype_coherence(
+        portation
+            .file_pattern
+            .as_deref()
+            .unwrap_or(&portation.file_path),
+        &portation.file_format,
+    )?;
+    // validate selector of partial portations, if any
+    if let Some(selector) = &portation.selector {
+        validate_selector(selector)?;
+    }
+    // validate that node data, or its selected part, can be ported to the files of a directory
+    let json_schema = get_partial_json_schema(node_json_schema, portation.selector.as_deref())?;
+    let content_json_schema = match &portation.file_pattern {
+        Some(file_pattern) => validate_directory_portation(
+            &json_schema,
+            &direction,
+            file_pattern,
+            portation.file_name_field.as_deref(),
+        )?,
+        None => &json_schema,
+    };
+    // validate that node data can be ported in Parquet format, and that an existing file to import
+    // is compatible with the node
+    if portation.file_format == PortationFileFormat::parquet {
+        let path = root_path.join(&portation.file_path);
+        let parquet_file_path = if direction == PortationDirectionType::toHolium
+            && portation.file_pattern.is_none()
+            && path.exists()
+        {
+            Some(path.as_path())
+        } else {
+            None
+        };
+        validate_parquet_compatibility(content_json_schema, parquet_file_path)?;
+    }
+    Ok(())
+}
+
+/// Validate that the data entering a node is either fully imported by its import portations, or
+/// partially imported, along with connections, by portations with a selector. Full import
+/// portations would otherwise be ignored.
+pub fn validate_import_portations(node_typed_name: &str, portations: &[&Portation]) -> Result<()> {
+    let nb_partial = portations
+        .iter()
+        .filter(|portation| portation.selector.is_some())
+        .count();
+    if 0 < nb_partial && nb_partial < portations.len() {
+        return Err(Error::MixedImportPortations(node_typed_name.to_string()).into());
+    }
+    Ok(())
+}
+
+/// Validate that a portation, as created or updated, can be combined with the stored import
+/// portations of its node.
+pub fn validate_node_import_portations(
+    repo_context: &RepositoryContext,
+    portation: &Portation,
+) -> Result<()> {
+    let (direction, node_typed_name) = parse_portation_id(&portation.id)?;
+    if direction != PortationDirectionType::toHolium {
+        return Ok(());
+    }
+    let mut portations: Vec<&Portation> =
+        get_node_portations(repo_context, &direction, node_typed_name)?
+            .into_iter()
+            .filter(|other| other.id != portation.id)
+            .collect();
+    portations.push(portation);
+    validate_import_portations(node_typed_name, &portations)
+}
+
+/// Get the json schema of the data of the node a portation relates to.
+pub fn get_portation_node_json_schema(
+    local_context: &LocalContext,
+    portation: &Portation,
 ) -> Result<HoliumJsonSchema> {
     // get details of the portation
     let (direction, node_typed_name) = parse_portation_id(&portation.id)?;
+    get_node_json_schema(local_context, node_typed_name, &direction)
+}
+
+/// Get the json schema of the data entering (*to Holium*) or leaving (*from Holium*) a pipeline
+/// node.
+pub fn get_node_json_schema(
+    local_context: &LocalContext,
+    node_typed_name: &str,
+    direction: &PortationDirectionType,
+) -> Result<HoliumJsonSchema> {
     let (node_type, node_name) = parse_node_typed_name(node_typed_name)?;
     let tree = local_context.get_tree_from_node_type(&node_type);
     let encoded = tree
@@ -50,7 +169,7 @@ pub fn get_portation_json_schema(
             let decoded: Transformation = bincode::deserialize(&encoded[..])
                 .ok()
                 .context(BinCodeDeserializeFailed)?;
-            if direction == PortationDirectionType::toHolium {
+            if direction == &PortationDirectionType::toHolium {
                 decoded.json_schema_in
             } else {
                 decoded.json_schema_out
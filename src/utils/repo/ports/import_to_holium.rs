@@ -1,15 +1,20 @@
 use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::jsonschema::HoliumJsonSchema;
 
-use crate::utils::repo::helpers::get_root_path;
 use crate::utils::repo::models::portation::{Portation, PortationFileFormat};
+use crate::utils::repo::ports::directory::import_directory_to_holium;
 use crate::utils::repo::ports::formats::bin::BinPorter;
 use crate::utils::repo::ports::formats::cbor::CborPorter;
 use crate::utils::repo::ports::formats::json::JsonPorter;
+use crate::utils::repo::ports::formats::msgpack::MsgpackPorter;
+use crate::utils::repo::ports::formats::ndjson::NdjsonPorter;
+use crate::utils::repo::ports::formats::parquet::ParquetPorter;
+use crate::utils::repo::ports::formats::yaml::YamlPorter;
 use crate::utils::repo::ports::formats::FormatPorter;
 use crate::utils::repo::ports::helpers::get_portation_json_schema;
 use anyhow::{bail, Context, Result};
 
-use std::io::{BufReader, Write};
+use std::io::{BufReader, Read, Write};
 
 #[derive(thiserror::Error, Debug)]
 enum Error {
@@ -17,7 +22,8 @@ enum Error {
     FailedToOpenFile(String, String),
 }
 
-/// Read data from a Portation and write it as HoliumCBOR data.
+/// Read data from a Portation, either from a file or from the files of a directory, and write it as
+/// HoliumCBOR data.
 pub fn import_to_holium<W: Write>(
     local_context: &LocalContext,
     portation: &Portation,
@@ -25,8 +31,12 @@ pub fn import_to_holium<W: Write>(
 ) -> Result<()> {
     // get json schema from the portation
     let json_schema = get_portation_json_schema(&local_context, &portation)?;
+    let path = local_context.root_path.join(&portation.file_path);
+    // read items of directory portations from their own files
+    if let Some(file_pattern) = &portation.file_pattern {
+        return import_directory_to_holium(&json_schema, portation, &path, file_pattern, writer);
+    }
     // open file from its path
-    let path = get_root_path()?.join(&portation.file_path);
     let file = std::fs::File::open(&path).context(Error::FailedToOpenFile(
         path.file_name()
             .map(|oss| oss.to_string_lossy().to_string())
@@ -35,15 +45,29 @@ pub fn import_to_holium<W: Write>(
     ))?;
     let mut reader = BufReader::new(file);
     // parse data in Holium CBOR format
-    match portation.file_format {
-        PortationFileFormat::bin => BinPorter::import_to_holium(&json_schema, &mut reader, writer),
-        PortationFileFormat::cbor => {
-            CborPorter::import_to_holium(&json_schema, &mut reader, writer)
-        }
+    import_reader_to_holium(&json_schema, &portation.file_format, &mut reader, writer)
+}
+
+/// Read data in a given file format and write it as HoliumCBOR data, as described by a JSON schema.
+pub fn import_reader_to_holium<R: Read, W: Write>(
+    json_schema: &HoliumJsonSchema,
+    file_format: &PortationFileFormat,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<()> {
+    match file_format {
+        PortationFileFormat::bin => BinPorter::import_to_holium(json_schema, reader, writer),
+        PortationFileFormat::cbor => CborPorter::import_to_holium(json_schema, reader, writer),
         PortationFileFormat::csv => bail!("import from CSV format is not supported yet"),
-        PortationFileFormat::json => {
-            JsonPorter::import_to_holium(&json_schema, &mut reader, writer)
+        PortationFileFormat::json => JsonPorter::import_to_holium(json_schema, reader, writer),
+        PortationFileFormat::msgpack => {
+            MsgpackPorter::import_to_holium(json_schema, reader, writer)
+        }
+        PortationFileFormat::ndjson => NdjsonPorter::import_to_holium(json_schema, reader, writer),
+        PortationFileFormat::parquet => {
+            ParquetPorter::import_to_holium(json_schema, reader, writer)
         }
+        PortationFileFormat::yaml => YamlPorter::import_to_holium(json_schema, reader, writer),
     }?;
     Ok(())
 }
@@ -1,5 +1,8 @@
 //! Module handling data imports/exports from/to Holium.
+pub mod ad_hoc;
+pub mod directory;
 pub mod export_from_holium;
 pub mod formats;
 pub mod helpers;
 pub mod import_to_holium;
+pub mod partial;
@@ -0,0 +1,246 @@
+//! Partial portations, only porting part of the data of a node as designated by a selector.
+//!
+//! A partial portation selector is made of paths to elements of the node data, possibly grouped in
+//! a union. The ported file holds a tuple with one element per path, in the order of the selector.
+//! Data exported from a node is selected with such a selector, while imported data is written at
+//! its paths, alongside data coming from connections.
+
+use crate::utils::cbor::as_holium_cbor::AsHoliumCbor;
+use crate::utils::cbor::write_holium_cbor::WriteHoliumCbor;
+use crate::utils::interplanetary::kinds::selector::{
+    ExploreIndex, ExploreUnion, Matcher, Selector,
+};
+use crate::utils::local::context::LocalContext;
+use crate::utils::local::helpers::jsonschema::{
+    HoliumJsonSchema, HoliumJsonSchemaName, HoliumJsonSchemaType,
+};
+use crate::utils::local::helpers::selector::resolve_selector_fields;
+use crate::utils::local::models::data::HoliumCbor;
+use crate::utils::repo::models::portation::Portation;
+use crate::utils::repo::ports::import_to_holium::import_to_holium;
+
+use anyhow::{Context, Result};
+use std::convert::TryFrom;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("a portation selector can only explore indices, fields and unions of them")]
+    UnsupportedPortationSelector,
+    #[error("a portation selector should select elements of the node data rather than its root")]
+    RootSelectedByPortation,
+    #[error("no json schema for the element at index: {0}")]
+    NoSchemaAtIndex(u64),
+    #[error("invalid selector for portation: {0}")]
+    InvalidPortationSelector(String),
+    #[error("failed to select data for portation: {0}")]
+    DataSelectionFailed(String),
+}
+
+/// Parse the selector of a partial portation, and resolve its fields against the JSON schema of the
+/// data of the node.
+pub fn resolve_portation_selector(
+    selector_literal: &str,
+    json_schema: &HoliumJsonSchema,
+) -> Result<Selector> {
+    let selector = Selector::try_from(selector_literal).context(
+        Error::InvalidPortationSelector(selector_literal.to_string()),
+    )?;
+    let selector = resolve_selector_fields(&selector, json_schema).context(
+        Error::InvalidPortationSelector(selector_literal.to_string()),
+    )?;
+    // check that the selector is made of paths to elements of the node data
+    selector_paths(&selector).context(Error::InvalidPortationSelector(
+        selector_literal.to_string(),
+    ))?;
+    Ok(selector)
+}
+
+/// Get the JSON schema of the data ported by a portation, given the JSON schema of the data of the
+/// node. It is the node JSON schema itself, unless the portation is partial.
+pub fn get_partial_json_schema(
+    json_schema: HoliumJsonSchema,
+    selector_literal: Option<&str>,
+) -> Result<HoliumJsonSchema> {
+    let selector_literal = match selector_literal {
+        Some(selector_literal) => selector_literal,
+        None => return Ok(json_schema),
+    };
+    let selector = resolve_portation_selector(selector_literal, &json_schema)?;
+    let element_schemata = selector_paths(&selector)?
+        .iter()
+        .map(|path| {
+            let mut element_schema = &json_schema;
+            for index in path.iter() {
+                element_schema = child_json_schema(element_schema, *index)?;
+            }
+            Ok(element_schema.clone())
+        })
+        .collect::<Result<Vec<HoliumJsonSchema>>>()?;
+    Ok(HoliumJsonSchema(
+        HoliumJsonSchemaName(None),
+        Box::new(HoliumJsonSchemaType::TupleArray(element_schemata)),
+    ))
+}
+
+/// Select the part of the data of a node exported by a partial portation.
+pub fn select_partial_data(
+    portation_id: &str,
+    data: &HoliumCbor,
+    selector: &Selector,
+) -> Result<HoliumCbor> {
+    let mut partial_data: HoliumCbor = Vec::new();
+    partial_data
+        .copy_cbor(&vec![(
+            portation_id.to_string(),
+            data.to_vec(),
+            selector.clone(),
+            tuple_elements_selector(selector_paths(selector)?.len()),
+        )])
+        .context(Error::DataSelectionFailed(portation_id.to_string()))?;
+    Ok(partial_data)
+}
+
+/// Import data with a partial portation and return a quartet, similar to the ones describing
+/// connections, holding the portation id, imported data, the selector for the said data and the
+/// selector to write it in the data of the node.
+pub fn partial_import_details(
+    local_context: &LocalContext,
+    portation: &Portation,
+    json_schema: &HoliumJsonSchema,
+    selector_literal: &str,
+) -> Result<(String, HoliumCbor, Selector, Selector)> {
+    let selector = resolve_portation_selector(selector_literal, json_schema)?;
+    let mut imported_data: HoliumCbor = Vec::new();
+    import_to_holium(local_context, portation, &mut imported_data)?;
+    let elements_selector = tuple_elements_selector(selector_paths(&selector)?.len());
+    // check that the imported data can be selected
+    imported_data
+        .select_cbor(&elements_selector)
+        .context(Error::DataSelectionFailed(portation.id.clone()))?;
+    Ok((
+        portation.id.clone(),
+        imported_data,
+        elements_selector,
+        selector,
+    ))
+}
+
+/// Get the paths, as lists of indices, addressed by a resolved portation selector.
+fn selector_paths(selector: &Selector) -> Result<Vec<Vec<u64>>> {
+    let paths = element_paths(selector)?;
+    if paths.iter().any(Vec::is_empty) {
+        return Err(Error::RootSelectedByPortation.into());
+    }
+    Ok(paths)
+}
+
+fn element_paths(selector: &Selector) -> Result<Vec<Vec<u64>>> {
+    match selector {
+        Selector::Matcher(_) => Ok(vec![vec![]]),
+        Selector::ExploreIndex(explore_index) => Ok(element_paths(&explore_index.next)?
+            .into_iter()
+            .map(|path| {
+                let mut indexed_path = vec![explore_index.index];
+                indexed_path.extend(path);
+                indexed_path
+            })
+            .collect()),
+        Selector::ExploreUnion(explore_union) => Ok(explore_union
+            .0
+            .iter()
+            .map(element_paths)
+            .collect::<Result<Vec<Vec<Vec<u64>>>>>()?
+            .concat()),
+        _ => Err(Error::UnsupportedPortationSelector.into()),
+    }
+}
+
+/// Build a selector exploring, one by one, the elements of a tuple.
+fn tuple_elements_selector(nb_elements: usize) -> Selector {
+    let mut element_selectors: Vec<Selector> = (0..nb_elements as u64)
+        .map(|index| {
+            Selector::ExploreIndex(Box::new(ExploreIndex {
+                index,
+                next: Box::new(Selector::Matcher(Matcher { label: None })),
+            }))
+        })
+        .collect();
+    if element_selectors.len() == 1 {
+        return element_selectors.remove(0);
+    }
+    Selector::ExploreUnion(Box::new(ExploreUnion(element_selectors)))
+}
+
+/// Get the JSON schema of the element at a given index of a recursive type
+fn child_json_schema(json_schema: &HoliumJsonSchema, index: u64) -> Result<&HoliumJsonSchema> {
+    match json_schema.1.as_ref() {
+        HoliumJsonSchemaType::Object(schemata) | HoliumJsonSchemaType::TupleArray(schemata) => {
+            schemata
+                .get(index as usize)
+                .ok_or_else(|| Error::NoSchemaAtIndex(index).into())
+        }
+        HoliumJsonSchemaType::ItemsArray(items_schema) => Ok(items_schema),
+        _ => Err(Error::NoSchemaAtIndex(index).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::local::helpers::jsonschema::parse_root_json_schema;
+    use crate::utils::local::helpers::selector::parse_selector_argument;
+    use crate::utils::repo::ports::formats::json::{holium_cbor_to_json, json_to_holium_cbor};
+    use serde_json::json;
+
+    fn report_json_schema() -> HoliumJsonSchema {
+        parse_root_json_schema(&json!({
+            "type": "array",
+            "prefixItems": [
+                { "type": "string" },
+                {
+                    "type": "object",
+                    "properties": {
+                        "count": { "type": "number" },
+                        "mean": { "type": "number" }
+                    }
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn can_select_partial_data() {
+        let json_schema = report_json_schema();
+        let selector_literal = parse_selector_argument("1/mean").unwrap();
+        // the ported tuple holds the selected element
+        let partial_json_schema =
+            get_partial_json_schema(report_json_schema(), Some(&selector_literal)).unwrap();
+        let selector = resolve_portation_selector(&selector_literal, &json_schema).unwrap();
+        let data =
+            json_to_holium_cbor(&json_schema, &json!(["report", {"count": 3, "mean": 2}])).unwrap();
+        let partial_data = select_partial_data("from:source:report", &data, &selector).unwrap();
+        // numbers are exported to json as floats
+        assert_eq!(
+            holium_cbor_to_json(&partial_json_schema, &partial_data).unwrap(),
+            json!([2.0])
+        );
+    }
+
+    #[test]
+    fn cannot_select_partial_data_from_float_data() {
+        let json_schema = report_json_schema();
+        // Holium CBOR does not handle floats, so such data never reaches the selection
+        let res = json_to_holium_cbor(&json_schema, &json!(["report", {"count": 3, "mean": 1.5}]));
+        assert!(format!("{:?}", res.unwrap_err()).contains("float value not handled"));
+    }
+
+    #[test]
+    fn cannot_use_unsupported_portation_selector() {
+        let json_schema = report_json_schema();
+        for argument in ["*", "."] {
+            let selector_literal = parse_selector_argument(argument).unwrap();
+            assert!(resolve_portation_selector(&selector_literal, &json_schema).is_err());
+        }
+    }
+}
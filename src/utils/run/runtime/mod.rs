@@ -1,5 +1,7 @@
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use wasmer::{imports, wat2wasm, Cranelift, Instance, Memory, Module, Store, Universal};
 
@@ -47,10 +49,26 @@ struct Slice {
     len: u32,
 }
 
+/// [RuntimeMetrics] holds measurements made while instantiating and running the last module of a
+/// [Runtime]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RuntimeMetrics {
+    /// Time spent compiling the bytecode of the module
+    pub compilation_time: Duration,
+    /// Time spent instantiating the compiled module
+    pub instantiation_time: Duration,
+    /// Time spent running a function of the module, including the transfer of data to and from it
+    pub execution_time: Duration,
+    /// Size of the guest linear memory after the run, in bytes. As linear memories can only grow,
+    /// it is their high-water mark.
+    pub memory_size: u64,
+}
+
 /// [Runtime] is a structure that contains our wasm runtime and associated functions to run wasm modules
 #[derive(Clone, Debug)]
 pub struct Runtime {
     pub(crate) instance: Instance,
+    metrics: RuntimeMetrics,
 }
 
 impl Runtime {
@@ -66,7 +84,10 @@ impl Runtime {
 
         let instance = Instance::new(&module, &imports)?;
 
-        Ok(Runtime { instance })
+        Ok(Runtime {
+            instance,
+            metrics: RuntimeMetrics::default(),
+        })
     }
 
     /// [instantiate] will create a new wasm runtime instance that contains a wasm module. The wasm
@@ -82,6 +103,8 @@ impl Runtime {
 
         // Create a store, that holds the engine.
         let store = Store::new(&engine);
+        self.metrics = RuntimeMetrics::default();
+        let compilation_start = Instant::now();
 
         // Let's compile the Wasm module. It is at this step that the Wasm
         // text is transformed into Wasm bytes (if necessary), and then
@@ -89,14 +112,17 @@ impl Runtime {
         // stored in memory by the engine.
         let module = Module::new(&store, wasm_bytecode)
             .context(RuntimeError::ConversionBytesToModuleFailed)?;
+        self.metrics.compilation_time = compilation_start.elapsed();
 
         // Create an import object. Since our Wasm module didn't declare
         // any imports, it's an empty object.
         let import_object = imports! {};
 
         // And here we go again. Let's instantiate the Wasm module.
+        let instantiation_start = Instant::now();
         self.instance =
             Instance::new(&module, &import_object).context(RuntimeError::InstantiationFailed)?;
+        self.metrics.instantiation_time = instantiation_start.elapsed();
 
         Ok(())
     }
@@ -104,6 +130,7 @@ impl Runtime {
     /// [run] will run a given `func` from the wasm instance while also using `data` as an input payload.
     /// Currently data should be a Holium CBOR serialized value
     pub fn run(&mut self, func: &str, data: &[u8]) -> Result<Vec<u8>> {
+        let execution_start = Instant::now();
         // Because of legacy from SDK we need to convert to Data Node before sending off payload.
         // TODO fix when sdk is corrected with custom serde
         let serde_value: serde_cbor::Value = serde_cbor::from_slice(data).unwrap();
@@ -145,9 +172,18 @@ impl Runtime {
         let res_node: crate::utils::run::data::data_tree::Node =
             serde_cbor::from_slice(&node_bytes_payload).unwrap();
 
+        let memory_size = memory.data_size();
+        self.metrics.execution_time = execution_start.elapsed();
+        self.metrics.memory_size = memory_size;
+
         Ok(serde_cbor::to_vec(&serde_cbor::Value::from(res_node)).unwrap())
     }
 
+    /// [metrics] returns measurements made while instantiating and running the last module
+    pub fn metrics(&self) -> &RuntimeMetrics {
+        &self.metrics
+    }
+
     /// [guest_mem_alloc] will allocate some memory space on a wasm linear memory to allow for direct
     /// read and write
     fn guest_mem_alloc(&self, size: usize) -> Result<u32> {
@@ -200,4 +236,16 @@ mod tests {
             module.serialize().unwrap()
         );
     }
+
+    #[test]
+    fn can_measure_instantiation() {
+        let mut runtime = Runtime::new().unwrap();
+
+        runtime.instantiate(&wasm_bytes()).unwrap();
+
+        let metrics = runtime.metrics();
+        assert!(metrics.compilation_time > Duration::ZERO);
+        assert_eq!(metrics.execution_time, Duration::ZERO);
+        assert_eq!(metrics.memory_size, 0);
+    }
 }
@@ -0,0 +1,91 @@
+use assert_fs::TempDir;
+use holium::{Error, Project};
+use serde_json::json;
+
+const SOURCE_NAME: &'static str = "source";
+const JSON_SCHEMA: &'static str =
+    r#"{ "type" : "array", "prefixItems" : [ {"type" : "string"} ] }"#;
+
+/// Initialize a project in a new temporary directory, with a source already created
+fn setup_project_with_source() -> (TempDir, Project) {
+    let dir = TempDir::new().unwrap();
+    let project = Project::init(dir.path()).unwrap();
+    project.create_source(SOURCE_NAME, JSON_SCHEMA).unwrap();
+    (dir, project)
+}
+
+#[test]
+fn cannot_open_non_initialized_project() {
+    let dir = TempDir::new().unwrap();
+
+    let res = Project::open(dir.path());
+
+    assert!(matches!(res, Err(Error::NotAProject(_))));
+}
+
+#[test]
+fn cannot_init_project_twice() {
+    let (dir, project) = setup_project_with_source();
+    drop(project);
+
+    let res = Project::init(dir.path());
+
+    assert!(matches!(res, Err(Error::AlreadyInitialized(_))));
+}
+
+#[test]
+fn can_create_and_read_source() {
+    let (_dir, project) = setup_project_with_source();
+
+    let source = project.source(SOURCE_NAME).unwrap();
+
+    assert_eq!(source.name, SOURCE_NAME);
+    assert_eq!(source.json_schema, JSON_SCHEMA);
+}
+
+#[test]
+fn cannot_create_source_twice() {
+    let (_dir, project) = setup_project_with_source();
+
+    let res = project.create_source(SOURCE_NAME, JSON_SCHEMA);
+
+    assert_eq!(res, Err(Error::AlreadyExists(SOURCE_NAME.to_string())));
+}
+
+#[test]
+fn cannot_create_source_with_invalid_json_schema() {
+    let (_dir, project) = setup_project_with_source();
+
+    let res = project.create_source("other", r#"{"type": "wrong_type"}"#);
+
+    assert!(matches!(res, Err(Error::InvalidObject(_))));
+}
+
+#[test]
+fn cannot_read_non_existent_source() {
+    let (_dir, project) = setup_project_with_source();
+
+    let res = project.source("other");
+
+    assert!(matches!(res, Err(Error::NotFound(_))));
+}
+
+#[test]
+fn can_set_and_read_node_data() {
+    let (_dir, project) = setup_project_with_source();
+    let typed_name = format!("source:{}", SOURCE_NAME);
+    let value = json!(["hello holium"]);
+
+    project.set_data(&typed_name, &value).unwrap();
+
+    assert_eq!(project.data(&typed_name).unwrap(), value);
+}
+
+#[test]
+fn cannot_read_data_of_non_existent_node() {
+    let (_dir, project) = setup_project_with_source();
+
+    let res = project.data("source:other");
+
+    assert_eq!(res, Err(Error::NotFound("source:other".to_string())));
+}
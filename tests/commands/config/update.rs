@@ -0,0 +1,52 @@
+use crate::helpers::repo::setup_repo;
+use crate::helpers::source::setup_repo_with_source;
+use assert_cmd::assert::Assert;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::Path;
+
+/// Create and run a command in a repository, returning an [Assert] used to validate testing
+fn build_cmd(repo_path: &Path, args: &[&str]) -> Assert {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.current_dir(repo_path).args(args).assert();
+    assert
+}
+
+#[test]
+fn can_switch_block_store_of_empty_interplanetary_area() {
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    build_cmd(repo_path, &["config", "update", "--block-store", "sled"]).success();
+    // check that the new block store is used
+    build_cmd(repo_path, &["project", "fsck"])
+        .success()
+        .stdout(predicate::str::contains("0 block(s) checked"));
+    assert!(repo_path.join(".holium").join("interplanetary_db").exists());
+}
+
+#[test]
+fn cannot_switch_block_store_with_blocks_present() {
+    // initialize a repository with a source and export it
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    build_cmd(repo_path, &["project", "export"]).success();
+    // switching the block store would leave the exported blocks behind
+    build_cmd(repo_path, &["config", "update", "--block-store", "sled"])
+        .failure()
+        .stderr(predicate::str::contains(
+            "the interplanetary area is not empty",
+        ));
+    let assert = build_cmd(repo_path, &["config", "read"]);
+    assert.success().stdout(predicate::str::contains("flatfs"));
+    // migrating moves the blocks to the new block store, and switches it
+    build_cmd(repo_path, &["project", "migrate", "--block-store", "sled"]).success();
+    build_cmd(repo_path, &["config", "read"])
+        .success()
+        .stdout(predicate::str::contains("sled"));
+    build_cmd(repo_path, &["project", "fsck"])
+        .success()
+        .stdout(predicate::str::contains("interplanetary area is healthy"))
+        .stdout(predicate::str::contains("0 block(s)").not());
+    // updating with the current block store is accepted
+    build_cmd(repo_path, &["config", "update", "--block-store", "sled"]).success();
+}
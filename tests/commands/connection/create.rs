@@ -1,7 +1,7 @@
 use crate::helpers::connection::{
     build_connection_create_cmd, build_connection_id, node_type_name_alternative_pairs,
     node_type_name_pairs, setup_repo_with_all_node_types, NON_VALID_SELECTOR, NON_VALID_TYPE,
-    SELECTOR, SOURCE_TYPE, TRANSFORMATION_TYPE,
+    SELECTOR, SOURCE_TYPE, TRANSFORMATION_TYPE, UNION_SELECTOR,
 };
 
 use crate::helpers::source::SOURCE_NAME;
@@ -392,6 +392,29 @@ fn cannot_create_connection_with_non_valid_head_selector() {
         .stderr(predicate::str::contains("invalid holium selector"));
 }
 
+#[test]
+fn cannot_create_connection_with_non_paired_selectors() {
+    // initialize a repository
+    let repo = setup_repo_with_all_node_types();
+    let repo_path = repo.path();
+
+    // try to create connection with a tail union not matching the head selector
+    let assert = build_connection_create_cmd(
+        repo_path,
+        SOURCE_TYPE,
+        SOURCE_NAME,
+        UNION_SELECTOR,
+        TRANSFORMATION_TYPE,
+        TRANSFORMATION_NAME,
+        SELECTOR,
+    );
+
+    // check output
+    assert.failure().stderr(predicate::str::contains(
+        "tail selector produces 2 data set(s) while head selector expects 1",
+    ));
+}
+
 #[test]
 fn cannot_create_connection_with_non_parsable_tail_selector() {
     // initialize a repository
@@ -0,0 +1,52 @@
+use crate::helpers::connection::default_connection_id;
+use crate::helpers::repo::setup_repo;
+use assert_cmd::Command;
+use predicates::prelude::predicate;
+
+#[test]
+fn help_available() {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.arg("connection").arg("preview").arg("--help").assert();
+    // Check success
+    assert.success();
+}
+
+#[test]
+fn cannot_preview_connection_without_id() {
+    // initialize a repository
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    // try to preview connection without id
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("connection")
+        .arg("preview")
+        .assert();
+    // check output
+    assert
+        .failure()
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .stderr(predicate::str::contains("<ID>"));
+}
+
+#[test]
+fn cannot_preview_non_existent_connection() {
+    // initialize a repository
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    // try to preview connection
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("connection")
+        .arg("preview")
+        .arg(default_connection_id().as_str())
+        .assert();
+    // check output
+    assert
+        .failure()
+        .stderr(predicate::str::contains("missing object for key"));
+}
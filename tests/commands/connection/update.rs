@@ -1,10 +1,10 @@
 use crate::helpers::connection::{
     build_connection_read_cmd, default_connection_id, setup_repo_with_connection,
-    ALTERNATIVE_SELECTOR, NON_VALID_SELECTOR,
+    ALTERNATIVE_SELECTOR, NON_VALID_SELECTOR, PATH_SELECTOR,
 };
 
 use assert_cmd::Command;
-use predicates::prelude::predicate;
+use predicates::prelude::*;
 
 #[test]
 fn help_available() {
@@ -176,8 +176,16 @@ fn can_update_connection() {
         .stdout(predicate::str::contains("object updated"))
         .stdout(predicate::str::contains(default_connection_id().as_str()));
 
-    // try to read connection
-    let assert = build_connection_read_cmd(repo_path, default_connection_id().as_str());
+    // try to read connection, selectors being kept as json literals in the json output
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("connection")
+        .arg("read")
+        .arg(default_connection_id().as_str())
+        .arg("--output")
+        .arg("json")
+        .assert();
     // check output
     assert
         .success()
@@ -185,3 +193,38 @@ fn can_update_connection() {
         .stdout(predicate::str::contains("\"i\""))
         .stdout(predicate::str::contains("\">\""));
 }
+
+#[test]
+fn can_update_connection_with_path_selector() {
+    // initialize a repository
+    let repo = setup_repo_with_connection();
+    let repo_path = repo.path();
+
+    // try to update connection
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("connection")
+        .arg("update")
+        .arg(default_connection_id().as_str())
+        .arg("--tail-selector")
+        .arg(PATH_SELECTOR)
+        .arg("--head-selector")
+        .arg(PATH_SELECTOR)
+        .assert();
+
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains("object updated"))
+        .stdout(predicate::str::contains(default_connection_id().as_str()));
+
+    // try to read connection, selectors being displayed in the path syntax
+    let assert = build_connection_read_cmd(repo_path, default_connection_id().as_str());
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains(default_connection_id().as_str()))
+        .stdout(predicate::str::contains(PATH_SELECTOR))
+        .stdout(predicate::str::contains("\"i\"").not());
+}
@@ -0,0 +1,41 @@
+use crate::helpers::data::setup_repo_with_source_data;
+use crate::helpers::repo::setup_repo;
+use crate::helpers::source::SOURCE_NAME;
+use assert_cmd::Command;
+use predicates::prelude::predicate;
+
+#[test]
+fn help_available() {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.arg("data").arg("list").arg("--help").assert();
+    // Check success
+    assert.success();
+}
+
+#[test]
+fn can_list_with_no_data() {
+    // initialize a repository
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    // try to list data
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.current_dir(repo_path).arg("data").arg("list").assert();
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains("no object in the list"));
+}
+
+#[test]
+fn can_list_with_data() {
+    // initialize a repository with source data
+    let repo = setup_repo_with_source_data();
+    let repo_path = repo.path();
+    // try to list data
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.current_dir(repo_path).arg("data").arg("list").assert();
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains(format!("source:{}", SOURCE_NAME)));
+}
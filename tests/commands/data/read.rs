@@ -0,0 +1,38 @@
+use crate::helpers::data::{build_data_read_cmd, setup_repo_with_source_data};
+use crate::helpers::source::{setup_repo_with_source, SOURCE_NAME};
+use assert_cmd::Command;
+use predicates::prelude::predicate;
+
+#[test]
+fn help_available() {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.arg("data").arg("read").arg("--help").assert();
+    // Check success
+    assert.success();
+}
+
+#[test]
+fn cannot_read_data_of_node_without_data() {
+    // initialize a repository
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // try to read data
+    let assert = build_data_read_cmd(repo_path, &format!("source:{}", SOURCE_NAME));
+    // check output
+    assert.failure().stderr(predicate::str::contains(
+        "no data available at pipeline node",
+    ));
+}
+
+#[test]
+fn can_read_data() {
+    // initialize a repository with source data
+    let repo = setup_repo_with_source_data();
+    let repo_path = repo.path();
+    // try to read data
+    let assert = build_data_read_cmd(repo_path, &format!("source:{}", SOURCE_NAME));
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains("hello holium"));
+}
@@ -0,0 +1,130 @@
+use crate::helpers::data::{build_data_read_cmd, build_data_set_cmd, DATA_FILE_NAME, JSON_DATA};
+use crate::helpers::repo::setup_repo;
+use crate::helpers::source::{build_source_create_cmd, setup_repo_with_source, SOURCE_NAME};
+use crate::helpers::transformation::{
+    build_transformation_create_cmd, ALTERNATIVE_JSON_SCHEMA, JSON_SCHEMA, SOUND_BYTECODE,
+    TRANSFORMATION_HANDLE, TRANSFORMATION_NAME,
+};
+use assert_cmd::Command;
+use predicates::prelude::predicate;
+
+#[test]
+fn help_available() {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.arg("data").arg("set").arg("--help").assert();
+    // Check success
+    assert.success();
+}
+
+#[test]
+fn cannot_set_data_of_non_existent_node() {
+    // initialize a repository
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    std::fs::write(repo_path.join(DATA_FILE_NAME), JSON_DATA).unwrap();
+    // try to set data
+    let assert = build_data_set_cmd(
+        repo_path,
+        &format!("source:{}", SOURCE_NAME),
+        DATA_FILE_NAME,
+        "json",
+    );
+    // check output
+    assert
+        .failure()
+        .stderr(predicate::str::contains("no source node found with name"));
+}
+
+#[test]
+fn cannot_set_data_from_non_existent_file() {
+    // initialize a repository
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // try to set data
+    let assert = build_data_set_cmd(
+        repo_path,
+        &format!("source:{}", SOURCE_NAME),
+        DATA_FILE_NAME,
+        "json",
+    );
+    // check output
+    assert
+        .failure()
+        .stderr(predicate::str::contains("failed to open file"));
+}
+
+#[test]
+fn can_set_data() {
+    // initialize a repository
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    std::fs::write(repo_path.join(DATA_FILE_NAME), JSON_DATA).unwrap();
+    // try to set data
+    let assert = build_data_set_cmd(
+        repo_path,
+        &format!("source:{}", SOURCE_NAME),
+        DATA_FILE_NAME,
+        "json",
+    );
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains("object updated"));
+}
+
+#[test]
+fn can_set_data_from_ndjson_lines() {
+    // initialize a repository with a source holding an array of items
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    build_source_create_cmd(
+        repo_path,
+        SOURCE_NAME,
+        r#"{ "type": "array", "prefixItems": [{ "type": "array", "items": { "type": "string" } }] }"#,
+    )
+    .success();
+    std::fs::write(repo_path.join("data.ndjson"), "\"hello\"\n\"holium\"\n").unwrap();
+    // try to set data
+    let assert = build_data_set_cmd(
+        repo_path,
+        &format!("source:{}", SOURCE_NAME),
+        "data.ndjson",
+        "ndjson",
+    );
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains("object updated"));
+    // check that each line has been imported as an item
+    build_data_read_cmd(repo_path, &format!("source:{}", SOURCE_NAME))
+        .success()
+        .stdout(predicate::str::contains("hello"))
+        .stdout(predicate::str::contains("holium"));
+}
+
+#[test]
+fn can_set_data_of_transformation_as_its_output() {
+    // initialize a repository with a transformation taking strings and returning numbers
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    build_transformation_create_cmd(
+        repo_path,
+        TRANSFORMATION_NAME,
+        TRANSFORMATION_HANDLE,
+        SOUND_BYTECODE,
+        JSON_SCHEMA,
+        ALTERNATIVE_JSON_SCHEMA,
+    )
+    .success();
+    let node_typed_name = format!("transformation:{}", TRANSFORMATION_NAME);
+    // data matching the input schema only is rejected
+    std::fs::write(repo_path.join("input.json"), r#"["hello"]"#).unwrap();
+    let assert = build_data_set_cmd(repo_path, &node_typed_name, "input.json", "json");
+    assert.failure();
+    // data matching the output schema is stored
+    std::fs::write(repo_path.join("output.json"), "[42]").unwrap();
+    let assert = build_data_set_cmd(repo_path, &node_typed_name, "output.json", "json");
+    assert.success();
+    let assert = build_data_read_cmd(repo_path, &node_typed_name);
+    assert.success().stdout(predicate::str::contains("42"));
+}
@@ -122,4 +122,5 @@ fn init_cmd_creates_project_structure() {
     assert!(local_holium_path.join("interplanetary").exists());
     assert!(local_holium_path.join("local").exists());
     assert!(local_holium_path.join("portations").exists());
+    assert!(local_holium_path.join("config").exists());
 }
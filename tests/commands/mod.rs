@@ -1,5 +1,9 @@
+mod config;
 mod connection;
+mod data;
 mod init;
+mod project;
+mod selector;
 mod shaper;
 mod source;
 mod transformation;
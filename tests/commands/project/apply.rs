@@ -0,0 +1,127 @@
+use crate::helpers::repo::setup_repo;
+use crate::helpers::source::{build_source_read_cmd, setup_repo_with_source, SOURCE_NAME};
+use assert_cmd::Command;
+use predicates::prelude::predicate;
+
+const MANIFEST: &str = r#"
+sources:
+  - name: source
+    json_schema:
+      type: array
+      prefixItems: [{ type: string }]
+"#;
+
+#[test]
+fn help_available() {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.arg("project").arg("apply").arg("--help").assert();
+    // Check success
+    assert.success();
+}
+
+#[test]
+fn cannot_apply_without_manifest() {
+    // initialize a repository
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    // try to apply the manifest
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("apply")
+        .assert();
+    // check output
+    assert
+        .failure()
+        .stderr(predicate::str::contains("failed to open manifest file"));
+}
+
+#[test]
+fn can_apply_manifest() {
+    // initialize a repository with a manifest
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    std::fs::write(repo_path.join("holium.yaml"), MANIFEST).unwrap();
+    // apply the manifest
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("apply")
+        .assert();
+    // check output
+    assert.success().stdout(predicate::str::contains(format!(
+        "+ source {}",
+        SOURCE_NAME
+    )));
+    // check that the source has been created
+    build_source_read_cmd(repo_path, SOURCE_NAME)
+        .success()
+        .stdout(predicate::str::contains("prefixItems"));
+}
+
+#[test]
+fn can_plan_deletions_without_applying_them() {
+    // initialize a repository with a source and an empty manifest
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    std::fs::write(repo_path.join("holium.yaml"), "{}").unwrap();
+    // plan the manifest
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("apply")
+        .arg("--dry-run")
+        .assert();
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "- source {}",
+            SOURCE_NAME
+        )))
+        .stdout(predicate::str::contains("1 change(s) to apply"));
+    // check that the source still exists
+    build_source_read_cmd(repo_path, SOURCE_NAME).success();
+}
+
+#[test]
+fn can_apply_manifest_with_directory_portation() {
+    // initialize a repository with a manifest porting a directory of images
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    let manifest = r#"
+sources:
+  - name: images
+    json_schema:
+      type: array
+      prefixItems:
+        - type: array
+          items: { type: string, contentEncoding: base64 }
+portations:
+  - direction: toHolium
+    node: "source:images"
+    file_path: images
+    file_format: bin
+    file_pattern: "*.png"
+"#;
+    std::fs::write(repo_path.join("holium.yaml"), manifest).unwrap();
+    // apply the manifest
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("apply")
+        .assert();
+    assert.success();
+    // check that the portation holds the file pattern
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("portation")
+        .arg("list")
+        .assert();
+    assert.success().stdout(predicate::str::contains("*.png"));
+}
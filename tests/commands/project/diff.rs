@@ -0,0 +1,95 @@
+use crate::helpers::repo::setup_repo;
+use crate::helpers::source::{
+    build_source_create_cmd, setup_repo_with_source, JSON_SCHEMA, SOURCE_ALTERNATIVE_NAME,
+};
+use assert_cmd::assert::Assert;
+use assert_cmd::Command;
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use predicates::prelude::*;
+use std::path::Path;
+
+/// Export the project, returning the cid of the exported pipeline.
+fn export_project(repo_path: &Path) -> String {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("export")
+        .arg("--output")
+        .arg("json")
+        .assert();
+    let output: serde_json::Value =
+        serde_json::from_slice(&assert.success().get_output().stdout).unwrap();
+    output["pipeline_cid"].as_str().unwrap().to_string()
+}
+
+/// Create and run a project diff command, returning an [Assert] used to validate testing
+fn build_project_diff_cmd(repo_path: &Path, cids: &[&str]) -> Assert {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("diff")
+        .args(cids)
+        .arg("--output")
+        .arg("json")
+        .assert();
+    assert
+}
+
+fn diff_output(assert: Assert) -> serde_json::Value {
+    serde_json::from_slice(&assert.success().get_output().stdout).unwrap()
+}
+
+#[test]
+fn can_diff_local_project_and_exported_pipeline() {
+    // initialize a repository with a source and export it
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    export_project(repo_path);
+    // the local project is equivalent to the exported pipeline
+    let diff = diff_output(build_project_diff_cmd(repo_path, &[]));
+    assert!(diff["added_nodes"].as_array().unwrap().is_empty());
+    // add a source, then compare again
+    build_source_create_cmd(repo_path, SOURCE_ALTERNATIVE_NAME, JSON_SCHEMA).success();
+    let diff = diff_output(build_project_diff_cmd(repo_path, &[]));
+    assert_eq!(
+        diff["added_nodes"],
+        serde_json::json!([format!("source:{}", SOURCE_ALTERNATIVE_NAME)])
+    );
+}
+
+#[test]
+fn cannot_diff_without_exported_pipeline() {
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    build_project_diff_cmd(repo_path, &[])
+        .failure()
+        .stderr(predicate::str::contains("failed to find a Pipeline block"));
+}
+
+#[test]
+fn cannot_diff_without_cid_when_several_pipelines_are_exported() {
+    // export a first version of the pipeline, and save the interplanetary area
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    let old_cid = export_project(repo_path);
+    let ip_area = repo.child(".holium").child("interplanetary");
+    let saved_ip_area = TempDir::new().unwrap();
+    saved_ip_area.copy_from(ip_area.path(), &["**"]).unwrap();
+    // export a second version, and restore blocks of the first one next to it
+    build_source_create_cmd(repo_path, SOURCE_ALTERNATIVE_NAME, JSON_SCHEMA).success();
+    export_project(repo_path);
+    ip_area.copy_from(saved_ip_area.path(), &["**"]).unwrap();
+    // picking one of both pipelines would be arbitrary
+    build_project_diff_cmd(repo_path, &[])
+        .failure()
+        .stderr(predicate::str::contains("several Pipeline blocks"));
+    // an explicit cid is accepted
+    let diff = diff_output(build_project_diff_cmd(repo_path, &[&old_cid]));
+    assert_eq!(
+        diff["added_nodes"],
+        serde_json::json!([format!("source:{}", SOURCE_ALTERNATIVE_NAME)])
+    );
+}
@@ -0,0 +1,50 @@
+use crate::helpers::source::{setup_repo_with_source, SOURCE_NAME};
+use assert_cmd::Command;
+use predicates::prelude::predicate;
+
+#[test]
+fn help_available() {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.arg("project").arg("dump").arg("--help").assert();
+    // Check success
+    assert.success();
+}
+
+#[test]
+fn can_dump_manifest() {
+    // initialize a repository with a source
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // dump the manifest
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("dump")
+        .assert();
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains("manifest written"));
+    let manifest = std::fs::read_to_string(repo_path.join("holium.yaml")).unwrap();
+    assert!(manifest.contains(&format!("name: {}", SOURCE_NAME)));
+}
+
+#[test]
+fn cannot_overwrite_manifest_without_force() {
+    // initialize a repository with a manifest
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    std::fs::write(repo_path.join("holium.yaml"), "{}").unwrap();
+    // try to dump the manifest
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("dump")
+        .assert();
+    // check output
+    assert
+        .failure()
+        .stderr(predicate::str::contains("object already exists with key"));
+}
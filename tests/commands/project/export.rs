@@ -0,0 +1,45 @@
+use crate::helpers::data::{build_data_read_cmd, build_data_set_cmd};
+use crate::helpers::source::{setup_repo_with_source, SOURCE_NAME};
+use assert_cmd::Command;
+
+#[test]
+fn can_export_and_import_data_larger_than_chunk_size() {
+    // initialize a repository with a source holding a string of several mebibytes
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    let mut state = 1u64;
+    let large_string: String = (0..3 * 1_048_576)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (b'a' + ((state >> 33) % 26) as u8) as char
+        })
+        .collect();
+    std::fs::write(
+        repo_path.join("large.json"),
+        format!(r#"["{}"]"#, large_string),
+    )
+    .unwrap();
+    let node_typed_name = format!("source:{}", SOURCE_NAME);
+    build_data_set_cmd(repo_path, &node_typed_name, "large.json", "json").success();
+    let assert = build_data_read_cmd(repo_path, &node_typed_name);
+    let data_before = assert.success().get_output().stdout.clone();
+    // export the project, its data being split into chunks, and import it back
+    for subcommand in ["export", "import"].iter() {
+        let mut cmd = Command::cargo_bin("holium").unwrap();
+        let assert = cmd
+            .current_dir(repo_path)
+            .arg("project")
+            .arg(subcommand)
+            .assert();
+        assert.success();
+    }
+    // check that the data has been reassembled from its chunks
+    let assert = build_data_read_cmd(repo_path, &node_typed_name);
+    let data_after = assert.success().get_output().stdout.clone();
+    assert_eq!(data_before, data_after);
+    assert!(String::from_utf8(data_after)
+        .unwrap()
+        .contains(&large_string[..1000]));
+}
@@ -0,0 +1,56 @@
+use crate::helpers::source::setup_repo_with_source;
+use assert_cmd::assert::Assert;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Create and run a project fsck command, returning an [Assert] used to validate testing
+fn build_project_fsck_cmd(repo_path: &Path, quarantine: bool) -> Assert {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    cmd.current_dir(repo_path).arg("project").arg("fsck");
+    if quarantine {
+        cmd.arg("--quarantine");
+    }
+    cmd.assert()
+}
+
+#[test]
+fn can_detect_and_quarantine_corrupted_block() {
+    // initialize a repository with a source and export it
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    cmd.current_dir(repo_path)
+        .arg("project")
+        .arg("export")
+        .assert()
+        .success();
+    build_project_fsck_cmd(repo_path, false)
+        .success()
+        .stdout(predicate::str::contains("interplanetary area is healthy"));
+    // corrupt a block of the interplanetary area
+    let block_path = WalkDir::new(repo_path.join(".holium").join("interplanetary"))
+        .min_depth(2)
+        .into_iter()
+        .map(|entry| entry.unwrap())
+        .find(|entry| entry.file_type().is_file())
+        .unwrap()
+        .into_path();
+    std::fs::write(&block_path, b"corrupted").unwrap();
+    // check that the corruption is reported
+    build_project_fsck_cmd(repo_path, false)
+        .failure()
+        .stdout(predicate::str::contains("content does not match the cid"))
+        .stderr(predicate::str::contains(
+            "interplanetary area failed the integrity check",
+        ));
+    assert!(block_path.exists());
+    // check that the corrupted block can be moved out of the area
+    build_project_fsck_cmd(repo_path, true)
+        .failure()
+        .stdout(predicate::str::contains("quarantined to"));
+    assert!(!block_path.exists());
+    let quarantine_dir_path = repo_path.join(".holium").join("quarantine");
+    assert_eq!(std::fs::read_dir(quarantine_dir_path).unwrap().count(), 1);
+}
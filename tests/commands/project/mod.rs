@@ -0,0 +1,7 @@
+mod apply;
+mod diff;
+mod dump;
+mod export;
+mod fsck;
+mod run;
+mod runs;
@@ -0,0 +1,445 @@
+use crate::helpers::connection::{
+    build_connection_create_cmd, SELECTOR, SHAPER_TYPE, SOURCE_TYPE, TRANSFORMATION_TYPE,
+};
+use crate::helpers::repo::setup_repo;
+use crate::helpers::shaper::{build_shaper_create_cmd, SHAPER_NAME};
+use crate::helpers::source::{build_source_create_cmd, setup_repo_with_source, SOURCE_NAME};
+use crate::helpers::transformation::{
+    build_transformation_create_cmd, JSON_SCHEMA as TRANSFORMATION_JSON_SCHEMA, SOUND_BYTECODE,
+    TRANSFORMATION_HANDLE, TRANSFORMATION_NAME,
+};
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn help_available() {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.arg("project").arg("run").arg("--help").assert();
+    // Check success
+    assert.success();
+}
+
+#[test]
+fn can_pipe_data_through_standard_streams() {
+    // initialize a repository with a source
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // run the pipeline with data from the standard input
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}=-", SOURCE_NAME))
+        .arg("--export")
+        .arg(format!("source:{}=-", SOURCE_NAME))
+        .arg("--format")
+        .arg("json")
+        .write_stdin(r#"["hello"]"#)
+        .assert();
+    // check that only exported data is printed
+    assert.success().stdout(r#"["hello"]"#);
+}
+
+#[test]
+fn can_export_to_ad_hoc_file() {
+    // initialize a repository with a source
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    std::fs::write(repo_path.join("in.json"), r#"["hello"]"#).unwrap();
+    // run the pipeline
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}=in.json", SOURCE_NAME))
+        .arg("--export")
+        .arg(format!("source:{}=out.json", SOURCE_NAME))
+        .assert();
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains("successfully ran"));
+    let exported = std::fs::read_to_string(repo_path.join("out.json")).unwrap();
+    assert_eq!(exported, r#"["hello"]"#);
+    // check that no portation has been stored
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("portation")
+        .arg("list")
+        .assert();
+    assert
+        .success()
+        .stdout(predicate::str::contains("out.json").not());
+}
+
+#[test]
+fn can_export_with_several_named_portations() {
+    // initialize a repository with a source
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // create two portations exporting data of the source
+    for (name, file_path) in [("first", "first.json"), ("second", "second.cbor")] {
+        let file_format = file_path.rsplit('.').next().unwrap();
+        let mut cmd = Command::cargo_bin("holium").unwrap();
+        let assert = cmd
+            .current_dir(repo_path)
+            .arg("portation")
+            .arg("create")
+            .arg("--direction")
+            .arg("fromHolium")
+            .arg("--node-type")
+            .arg("source")
+            .arg("--node-name")
+            .arg(SOURCE_NAME)
+            .arg("--file-path")
+            .arg(file_path)
+            .arg("--file-format")
+            .arg(file_format)
+            .arg("--name")
+            .arg(name)
+            .assert();
+        assert.success();
+    }
+    // run the pipeline with twice the same data from two ad-hoc imports
+    std::fs::write(repo_path.join("in.json"), r#"["hello"]"#).unwrap();
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!(
+            "source:{0}=in.json,source:{0}=in.json",
+            SOURCE_NAME
+        ))
+        .assert();
+    assert.success();
+    // check that data has been exported by both portations
+    let exported = std::fs::read_to_string(repo_path.join("first.json")).unwrap();
+    assert_eq!(exported, r#"["hello"]"#);
+    assert!(repo_path.join("second.cbor").exists());
+}
+
+#[test]
+fn can_export_part_of_node_data_with_selector() {
+    // initialize a repository with a source holding two elements
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    build_source_create_cmd(
+        repo_path,
+        SOURCE_NAME,
+        r#"{ "type": "array", "prefixItems": [ { "type": "string" }, { "type": "number" } ] }"#,
+    )
+    .success();
+    // create a portation only exporting the second element
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("portation")
+        .arg("create")
+        .arg("--direction")
+        .arg("fromHolium")
+        .arg("--node-type")
+        .arg("source")
+        .arg("--node-name")
+        .arg(SOURCE_NAME)
+        .arg("--file-path")
+        .arg("out.json")
+        .arg("--file-format")
+        .arg("json")
+        .arg("--selector")
+        .arg("1")
+        .assert();
+    assert.success();
+    // run the pipeline with data from the standard input
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}=-", SOURCE_NAME))
+        .write_stdin(r#"["hello", 2]"#)
+        .assert();
+    assert.success();
+    // check that only the selected element has been exported
+    let exported = std::fs::read_to_string(repo_path.join("out.json")).unwrap();
+    assert_eq!(exported, "[2.0]");
+}
+
+#[test]
+fn can_report_on_nodes() {
+    // initialize a repository with a source connected to a transformation
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    build_transformation_create_cmd(
+        repo_path,
+        TRANSFORMATION_NAME,
+        TRANSFORMATION_HANDLE,
+        SOUND_BYTECODE,
+        TRANSFORMATION_JSON_SCHEMA,
+        TRANSFORMATION_JSON_SCHEMA,
+    )
+    .success();
+    build_connection_create_cmd(
+        repo_path,
+        SOURCE_TYPE,
+        SOURCE_NAME,
+        SELECTOR,
+        TRANSFORMATION_TYPE,
+        TRANSFORMATION_NAME,
+        SELECTOR,
+    )
+    .success();
+    std::fs::write(repo_path.join("in.json"), r#"["hello"]"#).unwrap();
+    // run the pipeline with a report
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}=in.json", SOURCE_NAME))
+        .arg("--report")
+        .arg("--output")
+        .arg("json")
+        .assert();
+    let output = assert.success().get_output().stdout.clone();
+    let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let node_report = |node_typed_name: String| {
+        value["report"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|node_report| node_report["node"] == node_typed_name)
+            .unwrap()
+            .clone()
+    };
+    // check the report, sources only passing their data through
+    let source_report = node_report(format!("source:{}", SOURCE_NAME));
+    assert!(source_report["input_size"].as_u64().unwrap() > 0);
+    assert_eq!(source_report["input_size"], source_report["output_size"]);
+    assert!(source_report["execution_time_ms"].is_null());
+    assert!(source_report["memory_size"].is_null());
+    // transformations are measured by the runtime
+    let transformation_report = node_report(format!("transformation:{}", TRANSFORMATION_NAME));
+    assert!(transformation_report["input_size"].as_u64().unwrap() > 0);
+    assert!(transformation_report["output_size"].as_u64().unwrap() > 0);
+    assert!(!transformation_report["execution_time_ms"].is_null());
+    assert!(transformation_report["memory_size"].as_u64().unwrap() > 0);
+    // the report is printed as a table in the human format
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}=in.json", SOURCE_NAME))
+        .arg("--report")
+        .assert();
+    assert
+        .success()
+        .stdout(predicate::str::contains("EXECUTION"));
+}
+
+#[test]
+fn cannot_run_with_invalid_ad_hoc_portation() {
+    // initialize a repository with a source
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // try to run the pipeline
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}", SOURCE_NAME))
+        .assert();
+    // check output
+    assert
+        .failure()
+        .stderr(predicate::str::contains("invalid ad-hoc portation"));
+}
+
+#[test]
+fn can_connect_fields_in_selected_order() {
+    // initialize a repository with a source of objects and a shaper of two strings
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    build_source_create_cmd(
+        repo_path,
+        SOURCE_NAME,
+        r#"{ "type": "array", "prefixItems": [ { "type": "object", "properties": { "id": { "type": "string" }, "name": { "type": "string" } } } ] }"#,
+    )
+    .success();
+    build_shaper_create_cmd(
+        repo_path,
+        SHAPER_NAME,
+        r#"{ "type": "array", "prefixItems": [ { "type": "string" }, { "type": "string" } ] }"#,
+    )
+    .success();
+    // connect the fields, in an order different from the one of the schema properties
+    build_connection_create_cmd(
+        repo_path,
+        SOURCE_TYPE,
+        SOURCE_NAME,
+        r#"{ "i": { "i": 0, ">": { "f": { "f>": { "name": { ".": {} }, "id": { ".": {} } } } } } }"#,
+        SHAPER_TYPE,
+        SHAPER_NAME,
+        r#"{ "|": [ { "i": { "i": 0, ">": { ".": {} } } }, { "i": { "i": 1, ">": { ".": {} } } } ] }"#,
+    )
+    .success();
+    std::fs::write(
+        repo_path.join("in.json"),
+        r#"[{ "id": "42", "name": "holium" }]"#,
+    )
+    .unwrap();
+    // run the pipeline
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}=in.json", SOURCE_NAME))
+        .arg("--export")
+        .arg(format!("shaper:{}=out.json", SHAPER_NAME))
+        .assert();
+    assert.success();
+    // check that fields have been copied in the order they were selected
+    let exported = std::fs::read_to_string(repo_path.join("out.json")).unwrap();
+    assert_eq!(exported, r#"["holium","42"]"#);
+}
+
+#[test]
+fn can_update_portation_selector_from_file_once_validated() {
+    // initialize a repository with a source holding two elements, exported with a selector
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    build_source_create_cmd(
+        repo_path,
+        SOURCE_NAME,
+        r#"{ "type": "array", "prefixItems": [ { "type": "string" }, { "type": "number" } ] }"#,
+    )
+    .success();
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("portation")
+        .arg("create")
+        .arg("--direction")
+        .arg("fromHolium")
+        .arg("--node-type")
+        .arg("source")
+        .arg("--node-name")
+        .arg(SOURCE_NAME)
+        .arg("--file-path")
+        .arg("out.json")
+        .arg("--file-format")
+        .arg("json")
+        .arg("--selector")
+        .arg("1")
+        .assert();
+    assert.success();
+    let portation_id = format!("from:source:{}", SOURCE_NAME);
+    // try to update the selector with one that does not match the node schema
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("portation")
+        .arg("update")
+        .arg(&portation_id)
+        .arg("--selector")
+        .arg("0/0")
+        .assert();
+    assert.failure().stderr(predicate::str::contains(
+        "no json schema for the element at index: 0",
+    ));
+    // update the selector with one read from a file
+    std::fs::write(repo_path.join("selector.txt"), "0").unwrap();
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("portation")
+        .arg("update")
+        .arg(&portation_id)
+        .arg("--selector")
+        .arg("@selector.txt")
+        .assert();
+    assert.success();
+    // run the pipeline and check that the newly selected element has been exported
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}=-", SOURCE_NAME))
+        .write_stdin(r#"["hello", 2]"#)
+        .assert();
+    assert.success();
+    let exported = std::fs::read_to_string(repo_path.join("out.json")).unwrap();
+    assert_eq!(exported, r#"["hello"]"#);
+}
+
+#[test]
+fn cannot_combine_full_and_partial_import_portations() {
+    // initialize a repository with a source holding two elements, fully imported
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    build_source_create_cmd(
+        repo_path,
+        SOURCE_NAME,
+        r#"{ "type": "array", "prefixItems": [ { "type": "string" }, { "type": "number" } ] }"#,
+    )
+    .success();
+    let build_portation_create_cmd = |extra_args: &[&str]| {
+        let mut cmd = Command::cargo_bin("holium").unwrap();
+        cmd.current_dir(repo_path)
+            .arg("portation")
+            .arg("create")
+            .arg("--direction")
+            .arg("toHolium")
+            .arg("--node-type")
+            .arg("source")
+            .arg("--node-name")
+            .arg(SOURCE_NAME)
+            .arg("--file-format")
+            .arg("json")
+            .args(extra_args)
+            .assert()
+    };
+    build_portation_create_cmd(&["--file-path", "in.json"]).success();
+    // a partial import portation would not be combined with the full one
+    build_portation_create_cmd(&[
+        "--file-path",
+        "part.json",
+        "--name",
+        "part",
+        "--selector",
+        "1",
+    ])
+    .failure()
+    .stderr(predicate::str::contains(
+        "can not be both fully and partially imported",
+    ));
+    // check that the full import portation still feeds the node
+    std::fs::write(repo_path.join("in.json"), r#"["hello", 2]"#).unwrap();
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--export")
+        .arg(format!("source:{}=out.json", SOURCE_NAME))
+        .assert();
+    assert.success();
+    let exported = std::fs::read_to_string(repo_path.join("out.json")).unwrap();
+    assert_eq!(exported, r#"["hello",2]"#);
+}
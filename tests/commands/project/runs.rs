@@ -0,0 +1,159 @@
+use crate::helpers::source::{setup_repo_with_source, SOURCE_NAME};
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::Path;
+
+#[test]
+fn help_available() {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.arg("project").arg("runs").arg("--help").assert();
+    // Check success
+    assert.success();
+}
+
+/// Run the pipeline of a repository with a source, porting data from and to files, and return the
+/// json output of the command.
+fn run_with_files(repo_path: &Path, extra_args: &[&str]) -> serde_json::Value {
+    std::fs::write(repo_path.join("in.json"), r#"["hello"]"#).unwrap();
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}=in.json", SOURCE_NAME))
+        .arg("--export")
+        .arg(format!("source:{}=out.json", SOURCE_NAME))
+        .arg("--output")
+        .arg("json")
+        .args(extra_args)
+        .assert();
+    let output = assert.success().get_output().stdout.clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+#[test]
+fn can_show_provenance_of_run() {
+    // initialize a repository with a source, and run it
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    let run_output = run_with_files(repo_path, &[]);
+    let id = run_output["run"].as_str().unwrap().to_string();
+    assert!(run_output["provenance_cid"].is_null());
+    // list runs
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("runs")
+        .arg("list")
+        .arg("--output")
+        .arg("json")
+        .assert();
+    let output = assert.success().get_output().stdout.clone();
+    let runs: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(runs[0]["id"], id.as_str());
+    // show the run
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("runs")
+        .arg("show")
+        .arg(&id)
+        .arg("--output")
+        .arg("json")
+        .assert();
+    let output = assert.success().get_output().stdout.clone();
+    let run: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let node = &run["nodes"][0];
+    assert_eq!(node["node"], format!("source:{}", SOURCE_NAME));
+    // data of a source leaves it unchanged, as do the ported json files
+    assert!(node["input_cid"].is_string());
+    assert_eq!(node["input_cid"], node["output_cid"]);
+    assert!(node["bytecode_cid"].is_null());
+    assert!(node["exports"][0]["file_path"]
+        .as_str()
+        .unwrap()
+        .ends_with("out.json"));
+    assert!(node["exports"][0]["file_cid"].is_string());
+    assert_eq!(
+        node["imports"][0]["file_cid"],
+        node["exports"][0]["file_cid"]
+    );
+}
+
+#[test]
+fn can_export_provenance_of_run() {
+    // initialize a repository with a source, and run it
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    let run_output = run_with_files(repo_path, &["--export-provenance"]);
+    let provenance_cid = run_output["provenance_cid"].as_str().unwrap().to_string();
+    // check that the record holds the cids of the exported blocks
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("runs")
+        .arg("show")
+        .arg(run_output["run"].as_str().unwrap())
+        .arg("--output")
+        .arg("json")
+        .assert();
+    let output = assert.success().get_output().stdout.clone();
+    let run: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(run["block_cid"], provenance_cid.as_str());
+    assert!(run["pipeline_cid"].is_string());
+    // check that the interplanetary area is consistent
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("fsck")
+        .assert();
+    assert.success();
+}
+
+#[test]
+fn can_export_provenance_of_successive_runs() {
+    // initialize a repository with a source, and run it twice
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    run_with_files(repo_path, &["--export-provenance"]);
+    run_with_files(repo_path, &["--export-provenance"]);
+    // check that the pipeline, linking to both records, is the only one to be imported
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("import")
+        .arg("--no-write")
+        .assert();
+    assert.success();
+    // check that no link is broken, the record of the first run being kept
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("fsck")
+        .assert();
+    assert.success();
+}
+
+#[test]
+fn cannot_show_unknown_run() {
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("runs")
+        .arg("show")
+        .arg("42")
+        .assert();
+    assert
+        .failure()
+        .stderr(predicate::str::contains("missing object for key: 42"));
+}
@@ -0,0 +1,98 @@
+use crate::helpers::connection::SELECTOR;
+use crate::helpers::data::build_data_set_cmd;
+use crate::helpers::repo::setup_repo;
+use crate::helpers::source::{build_source_create_cmd, setup_repo_with_source, SOURCE_NAME};
+use assert_cmd::Command;
+use predicates::prelude::predicate;
+
+#[test]
+fn help_available() {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd.arg("selector").arg("eval").arg("--help").assert();
+    // Check success
+    assert.success();
+}
+
+#[test]
+fn cannot_eval_selector_on_non_existent_node() {
+    // initialize a repository
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    // try to evaluate a selector
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("selector")
+        .arg("eval")
+        .arg("--node")
+        .arg(format!("source:{}", SOURCE_NAME))
+        .arg("--selector")
+        .arg(SELECTOR)
+        .assert();
+    // check output
+    assert
+        .failure()
+        .stderr(predicate::str::contains("no source node found with name"));
+}
+
+#[test]
+fn cannot_eval_selector_on_node_without_data() {
+    // initialize a repository
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // try to evaluate a selector
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("selector")
+        .arg("eval")
+        .arg("--node")
+        .arg(format!("source:{}", SOURCE_NAME))
+        .arg("--selector")
+        .arg("0/1")
+        .assert();
+    // check output
+    assert.failure().stderr(predicate::str::contains(
+        "no data available at pipeline node",
+    ));
+}
+
+#[test]
+fn can_eval_selector_rendering_data_with_node_schema() {
+    // initialize a repository with a source holding objects
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    build_source_create_cmd(
+        repo_path,
+        SOURCE_NAME,
+        r#"{ "type": "array", "prefixItems": [ { "type": "object", "properties": { "id": { "type": "string" }, "name": { "type": "string" } } } ] }"#,
+    )
+    .success();
+    std::fs::write(
+        repo_path.join("data.json"),
+        r#"[{ "id": "42", "name": "holium" }]"#,
+    )
+    .unwrap();
+    build_data_set_cmd(
+        repo_path,
+        &format!("source:{}", SOURCE_NAME),
+        "data.json",
+        "json",
+    )
+    .success();
+    // evaluate a selector
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("selector")
+        .arg("eval")
+        .arg("--node")
+        .arg(format!("source:{}", SOURCE_NAME))
+        .arg("--selector")
+        .arg("0")
+        .assert();
+    // check that the selected object is rendered with its property names
+    assert
+        .success()
+        .stdout(predicate::str::contains(r#"[{"id":"42","name":"holium"}]"#));
+}
@@ -140,3 +140,154 @@ fn cannot_create_source() {
         .stdout(predicate::str::contains(SOURCE_NAME))
         .stdout(predicate::str::contains("\"type\": \"string\""));
 }
+
+#[test]
+fn can_create_source_with_json_schema_from_file() {
+    // initialize a repository with a JSON schema file
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    std::fs::write(repo_path.join("schema.json"), JSON_SCHEMA).unwrap();
+    // create source
+    let assert = build_source_create_cmd(repo_path, SOURCE_NAME, "@schema.json");
+    // check output
+    assert.success();
+    // check that the JSON schema has been read from the file
+    build_source_read_cmd(repo_path, SOURCE_NAME)
+        .success()
+        .stdout(predicate::str::contains("string"));
+}
+
+#[test]
+fn can_create_source_with_json_schema_from_stdin() {
+    // initialize a repository
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    // create source
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("source")
+        .arg("create")
+        .arg(SOURCE_NAME)
+        .arg("--json-schema")
+        .arg("-")
+        .write_stdin(JSON_SCHEMA)
+        .assert();
+    // check output
+    assert.success();
+    // check that the JSON schema has been read from the standard input
+    build_source_read_cmd(repo_path, SOURCE_NAME)
+        .success()
+        .stdout(predicate::str::contains("string"));
+}
+
+#[test]
+fn cannot_create_source_with_json_schema_file_outside_project() {
+    // initialize a repository
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    // try to create source
+    let assert = build_source_create_cmd(repo_path, SOURCE_NAME, "@../schema.json");
+    // check output
+    assert
+        .failure()
+        .stderr(predicate::str::contains("outside the project directory"));
+}
+
+#[test]
+fn can_print_json_schema_inferred_from_sample() {
+    // initialize a repository with a sample file
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    std::fs::write(
+        repo_path.join("sample.json"),
+        r#"[[{"name": "foo", "size": 1}, {"name": "bar", "size": 2}]]"#,
+    )
+    .unwrap();
+    // infer the JSON schema without creating the source
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("source")
+        .arg("create")
+        .arg(SOURCE_NAME)
+        .arg("--infer-from")
+        .arg("sample.json")
+        .arg("--dry-run")
+        .assert();
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains("prefixItems"))
+        .stdout(predicate::str::contains("size"));
+    // check that the source has not been created
+    build_source_read_cmd(repo_path, SOURCE_NAME).failure();
+}
+
+#[test]
+fn can_create_source_with_json_schema_inferred_from_sample() {
+    // initialize a repository with a sample file
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    std::fs::write(repo_path.join("sample"), r#"["hello", [1, 2]]"#).unwrap();
+    // create source
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("source")
+        .arg("create")
+        .arg(SOURCE_NAME)
+        .arg("--infer-from")
+        .arg("sample")
+        .arg("--format")
+        .arg("json")
+        .assert();
+    // check output
+    assert.success();
+    // check that the inferred JSON schema has been stored
+    build_source_read_cmd(repo_path, SOURCE_NAME)
+        .success()
+        .stdout(predicate::str::contains("number"));
+}
+
+#[test]
+fn can_import_sample_with_inferred_json_schema() {
+    // initialize a repository with a sample file
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    std::fs::write(
+        repo_path.join("sample.json"),
+        r#"["report", [{"id": 1, "tags": ["a"]}, {"id": 2, "tags": []}]]"#,
+    )
+    .unwrap();
+    // create source with a JSON schema inferred from the sample
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("source")
+        .arg("create")
+        .arg(SOURCE_NAME)
+        .arg("--infer-from")
+        .arg("sample.json")
+        .assert();
+    assert.success();
+    // run the pipeline, importing and exporting back the sample
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("project")
+        .arg("run")
+        .arg("--import")
+        .arg(format!("source:{}=sample.json", SOURCE_NAME))
+        .arg("--export")
+        .arg(format!("source:{}=out.json", SOURCE_NAME))
+        .assert();
+    assert.success();
+    // check that the sample went through the source
+    let exported: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(repo_path.join("out.json")).unwrap()).unwrap();
+    assert_eq!(
+        exported,
+        serde_json::json!(["report", [{"id": 1.0, "tags": ["a"]}, {"id": 2.0, "tags": []}]])
+    );
+}
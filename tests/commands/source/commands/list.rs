@@ -56,3 +56,24 @@ fn can_list_with_source() {
         .stdout(predicate::str::contains(SOURCE_NAME))
         .stdout(predicate::str::contains("\"type\": \"string\""));
 }
+
+#[test]
+fn can_list_sources_as_json() {
+    // initialize a repository
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // try to list sources
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("source")
+        .arg("list")
+        .arg("--output")
+        .arg("json")
+        .assert();
+    // check output
+    let output = assert.success().get_output().stdout.clone();
+    let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(value[0]["name"], SOURCE_NAME);
+    assert_eq!(value[0]["json_schema"]["type"], "array");
+}
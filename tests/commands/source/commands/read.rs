@@ -1,5 +1,5 @@
 use crate::helpers::repo::setup_repo;
-use crate::helpers::source::{build_source_read_cmd, SOURCE_NAME};
+use crate::helpers::source::{build_source_read_cmd, setup_repo_with_source, SOURCE_NAME};
 use assert_cmd::Command;
 use predicates::prelude::predicate;
 
@@ -39,3 +39,25 @@ fn cannot_read_non_existent_source() {
         .failure()
         .stderr(predicate::str::contains("missing object for key"));
 }
+
+#[test]
+fn can_read_source_as_yaml() {
+    // initialize a repository
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // try to read source
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("source")
+        .arg("read")
+        .arg(SOURCE_NAME)
+        .arg("--output")
+        .arg("yaml")
+        .assert();
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains(format!("name: {}", SOURCE_NAME)))
+        .stdout(predicate::str::contains("prefixItems:"));
+}
@@ -205,6 +205,26 @@ fn cannot_create_transformation_which_bytecode_lacks_wasm_magic_number() {
         .stderr(predicate::str::contains("invalid WebAssembly bytecode"));
 }
 
+#[test]
+fn cannot_create_transformation_with_handle_not_exported_by_bytecode() {
+    // initialize a repository
+    let repo = setup_repo();
+    let repo_path = repo.path();
+    // try to create transformation with a handle the module does not export
+    let assert = build_transformation_create_cmd(
+        repo_path,
+        TRANSFORMATION_NAME,
+        "non_existent_handle",
+        SOUND_BYTECODE,
+        JSON_SCHEMA,
+        JSON_SCHEMA,
+    );
+    // check output
+    assert.failure().stderr(predicate::str::contains(
+        "no function exported by the Wasm module with handle",
+    ));
+}
+
 #[test]
 fn cannot_create_transformation_with_incorrect_json_schema_in() {
     // initialize a repository
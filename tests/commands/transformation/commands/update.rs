@@ -1,8 +1,13 @@
+use crate::helpers::connection::{
+    build_connection_create_cmd, default_connection_id, setup_repo_with_all_node_types,
+    setup_repo_with_connection, SOURCE_TYPE, TRANSFORMATION_TYPE,
+};
 use crate::helpers::repo::setup_repo;
+use crate::helpers::source::SOURCE_NAME;
 use crate::helpers::transformation::*;
 
 use assert_cmd::Command;
-use predicates::prelude::predicate;
+use predicates::prelude::*;
 
 #[test]
 fn help_available() {
@@ -199,3 +204,92 @@ fn can_update_transformation() {
         .stdout(predicate::str::contains("476 B"))
         .stdout(predicate::str::contains("\"type\": \"number\""));
 }
+
+#[test]
+fn cannot_update_transformation_with_handle_not_exported_by_bytecode() {
+    // initialize a repository
+    let repo = setup_repo_with_transformation();
+    let repo_path = repo.path();
+
+    // try to update transformation
+    let alternative_bytecode_path = bytecode_path("alternative_import.wasm");
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("transformation")
+        .arg("update")
+        .arg(TRANSFORMATION_NAME)
+        .arg("--bytecode")
+        .arg(alternative_bytecode_path)
+        .assert();
+    // check output
+    assert.failure().stderr(predicate::str::contains(
+        "no function exported by the Wasm module with handle",
+    ));
+}
+
+#[test]
+fn can_report_impact_of_transformation_update_without_applying_it() {
+    // initialize a repository
+    let repo = setup_repo_with_connection();
+    let repo_path = repo.path();
+
+    // try to update transformation
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("transformation")
+        .arg("update")
+        .arg(TRANSFORMATION_NAME)
+        .arg("--json-schema-in")
+        .arg(ALTERNATIVE_JSON_SCHEMA)
+        .arg("--dry-run")
+        .assert();
+    // check output
+    assert
+        .success()
+        .stdout(predicate::str::contains("json_schema_in"))
+        .stdout(predicate::str::contains(default_connection_id()))
+        .stdout(predicate::str::contains("object updated").not());
+
+    //Read to verify elements
+    let assert = build_transformation_read_cmd(repo_path, TRANSFORMATION_NAME);
+
+    assert
+        .success()
+        .stdout(predicate::str::contains("\"type\": \"number\"").not());
+}
+
+#[test]
+fn can_report_connection_selector_not_matching_tail_node_schema() {
+    // initialize a repository with a connection selecting a field the source does not have
+    let repo = setup_repo_with_all_node_types();
+    let repo_path = repo.path();
+    let assert = build_connection_create_cmd(
+        repo_path,
+        SOURCE_TYPE,
+        SOURCE_NAME,
+        "0/label",
+        TRANSFORMATION_TYPE,
+        TRANSFORMATION_NAME,
+        "0",
+    );
+    assert.success();
+
+    // try to update the input of the transformation
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("transformation")
+        .arg("update")
+        .arg(TRANSFORMATION_NAME)
+        .arg("--json-schema-in")
+        .arg(ALTERNATIVE_JSON_SCHEMA)
+        .arg("--dry-run")
+        .assert();
+    // check that the tail selector is checked against the schema of the source
+    assert
+        .success()
+        .stdout(predicate::str::contains(default_connection_id()))
+        .stdout(predicate::str::contains("tail selector"));
+}
@@ -31,6 +31,9 @@ pub(crate) const NON_VALID_TYPE: &'static str = "non_valid_type";
 pub(crate) const SELECTOR: &'static str = "{ \".\": {} }";
 pub(crate) const ALTERNATIVE_SELECTOR: &'static str =
     "{ \"i\": { \"i\": 1, \">\": { \".\": {} } } }";
+pub(crate) const PATH_SELECTOR: &'static str = "0/1..3";
+pub(crate) const UNION_SELECTOR: &'static str =
+    "{ \"|\": [{ \"i\": { \"i\": 0, \">\": { \".\": {} } } }, { \"i\": { \"i\": 1, \">\": { \".\": {} } } }] }";
 pub(crate) const NON_VALID_SELECTOR: &'static str = "{ \"non\": \"valid\"}";
 
 pub(crate) const NON_VALID_CONNECTION_ID: &'static str = "non_valid_connection_id";
@@ -0,0 +1,65 @@
+use crate::helpers::source::{setup_repo_with_source, SOURCE_NAME};
+use assert_cmd::assert::Assert;
+use assert_cmd::Command;
+use assert_fs::TempDir;
+use std::path::Path;
+
+/***********************************************************
+ * Constants useful to play around node data testing
+ ***********************************************************/
+
+pub(crate) const DATA_FILE_NAME: &'static str = "data.json";
+pub(crate) const JSON_DATA: &'static str = r#"["hello holium"]"#;
+
+/// Same as [setup_repo_with_source] but with data already set for the source
+pub(crate) fn setup_repo_with_source_data() -> TempDir {
+    // initialize a repository with a source
+    let repo = setup_repo_with_source();
+    let repo_path = repo.path();
+    // write a data file
+    std::fs::write(repo_path.join(DATA_FILE_NAME), JSON_DATA).unwrap();
+    // set data of the source
+    let assert = build_data_set_cmd(
+        repo_path,
+        &format!("source:{}", SOURCE_NAME),
+        DATA_FILE_NAME,
+        "json",
+    );
+    // check output
+    assert.success();
+
+    repo
+}
+
+/// Create and run a set data command, returning an [Assert] used to validate testing
+pub(crate) fn build_data_set_cmd(
+    repo_path: &Path,
+    node_typed_name: &str,
+    file_path: &str,
+    file_format: &str,
+) -> Assert {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("data")
+        .arg("set")
+        .arg(node_typed_name)
+        .arg("--file")
+        .arg(file_path)
+        .arg("--format")
+        .arg(file_format)
+        .assert();
+    assert
+}
+
+/// Create and run a read data command, returning an [Assert] used to validate testing
+pub(crate) fn build_data_read_cmd(repo_path: &Path, node_typed_name: &str) -> Assert {
+    let mut cmd = Command::cargo_bin("holium").unwrap();
+    let assert = cmd
+        .current_dir(repo_path)
+        .arg("data")
+        .arg("read")
+        .arg(node_typed_name)
+        .assert();
+    assert
+}
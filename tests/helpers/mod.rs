@@ -1,4 +1,5 @@
 pub(crate) mod connection;
+pub(crate) mod data;
 pub(crate) mod repo;
 pub(crate) mod shaper;
 pub(crate) mod source;
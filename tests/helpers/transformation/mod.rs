@@ -12,7 +12,7 @@ pub(crate) const TRANSFORMATION_NAME: &'static str = "import_transformation";
 pub(crate) const TRANSFORMATION_ALTERNATIVE_NAME: &'static str = "alternative_transformation";
 
 pub(crate) const TRANSFORMATION_HANDLE: &'static str = "helloWorld";
-pub(crate) const TRANSFORMATION_ALTERNATIVE_HANDLE: &'static str = "alternative_transformation";
+pub(crate) const TRANSFORMATION_ALTERNATIVE_HANDLE: &'static str = "add_one";
 
 pub(crate) const SOUND_BYTECODE: &'static str = "import.wasm";
 pub(crate) const CORRUPTED_BYTECODE: &'static str = "import_corrupted.wasm";